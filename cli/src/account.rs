@@ -102,12 +102,14 @@ pub async fn account_prompt_internal(
                     maximum_supply,
                     foundry_metadata_hex,
                     foundry_metadata_file,
+                    irc30_file,
                 } => {
                     mint_native_token_command(
                         &account_handle,
                         circulating_supply,
                         maximum_supply,
                         bytes_from_hex_or_file(foundry_metadata_hex, foundry_metadata_file).await?,
+                        bytes_from_hex_or_file(None, irc30_file).await?,
                     )
                     .await
                 }
@@ -115,6 +117,7 @@ pub async fn account_prompt_internal(
                     address,
                     immutable_metadata_hex,
                     immutable_metadata_file,
+                    irc27_file,
                     metadata_hex,
                     metadata_file,
                     tag,
@@ -125,6 +128,7 @@ pub async fn account_prompt_internal(
                         &account_handle,
                         address,
                         bytes_from_hex_or_file(immutable_metadata_hex, immutable_metadata_file).await?,
+                        bytes_from_hex_or_file(None, irc27_file).await?,
                         bytes_from_hex_or_file(metadata_hex, metadata_file).await?,
                         tag,
                         sender,
@@ -18,7 +18,7 @@ use iota_sdk::{
     },
     wallet::{
         account::{
-            types::{AccountAddress, TransactionDto},
+            types::{AccountAddress, Irc27Metadata, Irc30Metadata, TransactionDto},
             AccountHandle, OutputsToClaim,
         },
         AddressAndNftId, AddressNativeTokens, AddressWithAmount, AddressWithMicroAmount, NativeTokenOptions,
@@ -108,6 +108,9 @@ pub enum AccountCommand {
         /// Metadata to attach to the associated foundry, e.g. --foundry-metadata-file ./foundry-metadata.json.
         #[arg(long, group = "foundry_metadata")]
         foundry_metadata_file: Option<String>,
+        /// IRC30 metadata to validate and attach to the associated foundry, e.g. --irc30-file ./irc30.json.
+        #[arg(long, group = "foundry_metadata")]
+        irc30_file: Option<String>,
     },
     /// Mint an NFT.
     /// IOTA NFT Standard - TIP27: https://github.com/iotaledger/tips/blob/main/tips/TIP-0027/tip-0027.md.
@@ -120,6 +123,9 @@ pub enum AccountCommand {
         /// Immutable metadata to attach to the NFT, e.g. --immutable-metadata-file ./nft-immutable-metadata.json.
         #[arg(long, group = "immutable_metadata")]
         immutable_metadata_file: Option<String>,
+        /// IRC27 metadata to validate and attach as immutable metadata, e.g. --irc27-file ./irc27.json.
+        #[arg(long, group = "immutable_metadata")]
+        irc27_file: Option<String>,
         /// Metadata to attach to the NFT, e.g. --metadata-hex 0xdeadbeef.
         #[arg(long, group = "metadata")]
         metadata_hex: Option<String>,
@@ -463,6 +469,7 @@ pub async fn mint_native_token_command(
     circulating_supply: String,
     maximum_supply: String,
     foundry_metadata: Option<Vec<u8>>,
+    irc_30_metadata: Option<Vec<u8>>,
 ) -> Result<(), Error> {
     // If no alias output exists, create one first
     if account_handle.balance().await?.aliases.is_empty() {
@@ -479,12 +486,16 @@ pub async fn mint_native_token_command(
         account_handle.sync(None).await?;
     }
 
-    let native_token_options = NativeTokenOptions {
+    let mut native_token_options = NativeTokenOptions {
         alias_id: None,
         circulating_supply: U256::from_dec_str(&circulating_supply).map_err(|e| Error::Miscellaneous(e.to_string()))?,
         maximum_supply: U256::from_dec_str(&maximum_supply).map_err(|e| Error::Miscellaneous(e.to_string()))?,
         foundry_metadata,
     };
+    if let Some(irc_30_metadata) = irc_30_metadata {
+        native_token_options =
+            native_token_options.with_irc_30_metadata(&Irc30Metadata::from_bytes(&irc_30_metadata)?)?;
+    }
 
     let mint_transaction = account_handle.mint_native_token(native_token_options, None).await?;
 
@@ -502,6 +513,7 @@ pub async fn mint_nft_command(
     account_handle: &AccountHandle,
     address: Option<String>,
     immutable_metadata: Option<Vec<u8>>,
+    irc_27_metadata: Option<Vec<u8>>,
     metadata: Option<Vec<u8>>,
     tag: Option<String>,
     sender: Option<String>,
@@ -512,15 +524,18 @@ pub async fn mint_nft_command(
     } else {
         None
     };
-    let nft_options = vec![NftOptions {
+    let mut nft_options = NftOptions {
         issuer,
         sender,
         tag,
         address,
         immutable_metadata,
         metadata,
-    }];
-    let transaction = account_handle.mint_nfts(nft_options, None).await?;
+    };
+    if let Some(irc_27_metadata) = irc_27_metadata {
+        nft_options = nft_options.with_irc_27_metadata(&Irc27Metadata::from_bytes(&irc_27_metadata)?)?;
+    }
+    let transaction = account_handle.mint_nfts(vec![nft_options], None).await?;
 
     println_log_info!(
         "NFT minting transaction sent:\n{:?}\n{:?}",
@@ -1,11 +1,16 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{fs::File, io::prelude::*};
+use std::{fs::File, io::prelude::*, path::Path};
 
 use clap::{Args, Parser, Subcommand};
 use iota_sdk::{
-    client::{constants::SHIMMER_COIN_TYPE, secret::SecretManager, utils::generate_mnemonic},
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::SecretManager,
+        stronghold::{SnapshotStatus, StrongholdAdapter},
+        utils::generate_mnemonic,
+    },
     wallet::{ClientOptions, Wallet},
 };
 use log::LevelFilter;
@@ -40,6 +45,11 @@ pub enum WalletCommand {
         /// Account alias, next available account index if not provided.
         alias: Option<String>,
     },
+    /// Extract the mnemonic and the readable data of the stronghold file into a new stronghold file.
+    RepairStronghold {
+        /// Path of the new stronghold file.
+        repaired_snapshot_path: String,
+    },
     /// Restore a stronghold backup file.
     Restore {
         /// Path of the to be restored stronghold backup file.
@@ -52,6 +62,8 @@ pub enum WalletCommand {
     },
     /// Synchronize all accounts.
     Sync,
+    /// Verify the stronghold file and list its content.
+    VerifyStronghold,
 }
 
 #[derive(Debug, Default, Clone, Args)]
@@ -59,6 +71,9 @@ pub struct InitParameters {
     /// Mnemonic, randomly generated if not provided.
     #[arg(short, long)]
     pub mnemonic: Option<String>,
+    /// Prompt for a BIP-39 passphrase, also known as the 25th word, to derive the seed from the mnemonic with.
+    #[arg(long)]
+    pub passphrase: bool,
     /// Node URL, "https://api.testnet.shimmer.network" if not provided.
     #[arg(short, long)]
     pub node: Option<String>,
@@ -117,8 +132,25 @@ pub async fn init_command(
         "It is the only way to recover your account if you ever forget your password and/or lose the stronghold file."
     );
 
+    let passphrase = if parameters.passphrase {
+        let passphrase = get_password("BIP-39 passphrase", true)?;
+        println_log_info!(
+            "The passphrase isn't written to \"mnemonic.txt\", it's required with the mnemonic to recover your account."
+        );
+        Some(passphrase)
+    } else {
+        None
+    };
+
     if let SecretManager::Stronghold(secret_manager) = &mut *wallet.get_secret_manager().write().await {
-        secret_manager.store_mnemonic(mnemonic).await?;
+        match passphrase {
+            Some(passphrase) => {
+                secret_manager
+                    .store_mnemonic_with_passphrase(mnemonic, passphrase)
+                    .await?
+            }
+            None => secret_manager.store_mnemonic(mnemonic).await?,
+        }
     } else {
         panic!("cli-wallet only supports Stronghold-backed secret managers at the moment.");
     }
@@ -157,6 +189,21 @@ pub async fn new_command(wallet: &Wallet, alias: Option<String>) -> Result<Strin
     Ok(alias)
 }
 
+pub fn repair_stronghold_command(
+    snapshot_path: &Path,
+    password: &str,
+    repaired_snapshot_path: String,
+) -> Result<(), Error> {
+    let content = StrongholdAdapter::repair_snapshot(snapshot_path, password, &repaired_snapshot_path)?;
+
+    println_log_info!(
+        "The mnemonic and the readable data {:?} have been extracted to \"{repaired_snapshot_path}\".",
+        content.store_keys
+    );
+
+    Ok(())
+}
+
 pub async fn restore_command(
     secret_manager: SecretManager,
     storage_path: String,
@@ -184,6 +231,23 @@ pub async fn set_node_command(wallet: &Wallet, url: String) -> Result<(), Error>
     Ok(())
 }
 
+pub fn verify_stronghold_command(snapshot_path: &Path, password: &str) -> Result<(), Error> {
+    let status = StrongholdAdapter::verify_snapshot(snapshot_path, password)?;
+
+    println_log_info!("Stronghold file \"{}\": {status}", snapshot_path.display());
+    if let SnapshotStatus::Valid(content) = status {
+        println_log_info!("Readable data: {:?}", content.store_keys);
+        if !content.corrupted_store_keys.is_empty() {
+            println_log_info!(
+                "Unreadable data: {:?}, use `repair-stronghold` to extract the mnemonic and the readable data.",
+                content.corrupted_store_keys
+            );
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn sync_command(wallet: &Wallet) -> Result<(), Error> {
     let total_balance = wallet.sync(None).await?;
 
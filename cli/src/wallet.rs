@@ -10,8 +10,9 @@ use iota_sdk::{
 
 use crate::{
     command::wallet::{
-        backup_command, change_password_command, init_command, mnemonic_command, new_command, restore_command,
-        set_node_command, sync_command, InitParameters, WalletCli, WalletCommand,
+        backup_command, change_password_command, init_command, mnemonic_command, new_command,
+        repair_stronghold_command, restore_command, set_node_command, sync_command, verify_stronghold_command,
+        InitParameters, WalletCli, WalletCommand,
     },
     error::Error,
     helper::get_password,
@@ -30,6 +31,24 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<String
     );
     let snapshot_path = std::path::Path::new("./stardust-cli-wallet.stronghold");
     let snapshot_exists = snapshot_path.exists();
+
+    // The stronghold file is inspected before being loaded, as loading fails for a broken one.
+    match &cli.command {
+        Some(WalletCommand::VerifyStronghold) => {
+            verify_stronghold_command(snapshot_path, &get_password("Stronghold password", false)?)?;
+            return Ok((None, None));
+        }
+        Some(WalletCommand::RepairStronghold { repaired_snapshot_path }) => {
+            repair_stronghold_command(
+                snapshot_path,
+                &get_password("Stronghold password", false)?,
+                repaired_snapshot_path.clone(),
+            )?;
+            return Ok((None, None));
+        }
+        _ => {}
+    }
+
     let password = if let Some(WalletCommand::Restore { .. }) = &cli.command {
         get_password("Stronghold password", false)?
     } else {
@@ -67,7 +86,11 @@ pub async fn new_wallet(cli: WalletCli) -> Result<(Option<Wallet>, Option<String
                 WalletCommand::SetNode { url } => set_node_command(&wallet, url).await?,
                 WalletCommand::Sync => sync_command(&wallet).await?,
                 // PANIC: this will never happen because these variants have already been checked.
-                WalletCommand::Init(_) | WalletCommand::Mnemonic | WalletCommand::Restore { .. } => unreachable!(),
+                WalletCommand::Init(_)
+                | WalletCommand::Mnemonic
+                | WalletCommand::RepairStronghold { .. }
+                | WalletCommand::Restore { .. }
+                | WalletCommand::VerifyStronghold => unreachable!(),
             };
 
             (wallet, account)
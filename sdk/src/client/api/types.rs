@@ -79,6 +79,28 @@ impl PreparedTransactionData {
         })
     }
 
+    /// Verifies that the prepared transaction is still valid with the given protocol parameters, which can change with
+    /// a network upgrade between the preparation and the signing of the transaction.
+    pub fn verify_protocol_parameters(&self, protocol_parameters: &ProtocolParameters) -> crate::client::Result<()> {
+        let TransactionEssence::Regular(essence) = &self.essence;
+
+        if essence.network_id() != protocol_parameters.network_id() {
+            return Err(crate::types::block::Error::NetworkIdMismatch {
+                expected: protocol_parameters.network_id(),
+                actual: essence.network_id(),
+            })?;
+        }
+
+        for output in essence.outputs() {
+            output.verify_storage_deposit(
+                protocol_parameters.rent_structure().clone(),
+                protocol_parameters.token_supply(),
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Unverified conversion from [`PreparedTransactionDataDto`] to [`PreparedTransactionData`].
     pub fn try_from_dto_unverified(value: &PreparedTransactionDataDto) -> Result<Self, DtoError> {
         Ok(Self {
@@ -10,6 +10,7 @@ import type {
     BlockId,
     INetworkInfo,
     SecretManager,
+    Slip39Group,
     INode,
     IAuth,
     IBasicOutputBuilderOptions,
@@ -130,13 +131,55 @@ export class Client {
     }
 
     /**
-     * Returns a hex encoded seed for a mnemonic.
+     * Returns a hex encoded seed for a mnemonic and an optional BIP-39 passphrase.
      */
-    async mnemonicToHexSeed(mnemonic: string): Promise<string> {
+    async mnemonicToHexSeed(
+        mnemonic: string,
+        passphrase?: string,
+    ): Promise<string> {
         const response = await this.messageHandler.sendMessage({
             name: 'mnemonicToHexSeed',
             data: {
                 mnemonic,
+                passphrase,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Generates a new master secret and splits it into SLIP-39 mnemonic shares, returned by group.
+     */
+    async generateSlip39Shares(
+        groupThreshold: number,
+        groups: Slip39Group[],
+        passphrase?: string,
+    ): Promise<string[][]> {
+        const response = await this.messageHandler.sendMessage({
+            name: 'generateSlip39Shares',
+            data: {
+                groupThreshold,
+                groups,
+                passphrase,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Returns a hex encoded seed for a threshold of SLIP-39 mnemonic shares.
+     */
+    async slip39SharesToHexSeed(
+        shares: string[],
+        passphrase?: string,
+    ): Promise<string> {
+        const response = await this.messageHandler.sendMessage({
+            name: 'slip39SharesToHexSeed',
+            data: {
+                shares,
+                passphrase,
             },
         });
 
@@ -347,17 +390,39 @@ export class Client {
     }
 
     /**
-     * Store a mnemonic in the Stronghold vault
+     * Store a mnemonic, with an optional BIP-39 passphrase, in the Stronghold vault
      */
     async storeMnemonic(
         secretManager: SecretManager,
         mnemonic: string,
+        passphrase?: string,
     ): Promise<void> {
         const response = await this.messageHandler.sendMessage({
             name: 'storeMnemonic',
             data: {
                 secretManager,
                 mnemonic,
+                passphrase,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Store the master secret recovered from a threshold of SLIP-39 mnemonic shares in the Stronghold vault
+     */
+    async storeSlip39Shares(
+        secretManager: SecretManager,
+        shares: string[],
+        passphrase?: string,
+    ): Promise<void> {
+        const response = await this.messageHandler.sendMessage({
+            name: 'storeSlip39Shares',
+            data: {
+                secretManager,
+                shares,
+                passphrase,
             },
         });
 
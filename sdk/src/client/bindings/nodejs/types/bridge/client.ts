@@ -6,7 +6,7 @@ import type {
     IEd25519Signature,
     IEd25519Address,
 } from '@iota/types';
-import type { SecretManager } from '../secretManager';
+import type { SecretManager, Slip39Group } from '../secretManager';
 import type { IGenerateAddressesOptions } from '../generateAddressesOptions';
 import type { IBuildBlockOptions } from '../buildBlockOptions';
 import type { BlockId } from '../blockId';
@@ -59,6 +59,24 @@ export interface __MnemonicToHexSeedMessage__ {
     name: 'mnemonicToHexSeed';
     data: {
         mnemonic: string;
+        passphrase?: string;
+    };
+}
+
+export interface __GenerateSlip39SharesMessage__ {
+    name: 'generateSlip39Shares';
+    data: {
+        groupThreshold: number;
+        groups: Slip39Group[];
+        passphrase?: string;
+    };
+}
+
+export interface __Slip39SharesToHexSeedMessage__ {
+    name: 'slip39SharesToHexSeed';
+    data: {
+        shares: string[];
+        passphrase?: string;
     };
 }
 
@@ -183,6 +201,16 @@ export interface __StoreMnemonicMessage__ {
     data: {
         secretManager: SecretManager;
         mnemonic: string;
+        passphrase?: string;
+    };
+}
+
+export interface __StoreSlip39SharesMessage__ {
+    name: 'storeSlip39Shares';
+    data: {
+        secretManager: SecretManager;
+        shares: string[];
+        passphrase?: string;
     };
 }
 
@@ -5,6 +5,8 @@ import type {
     __GetOutputsMessage__,
     __GenerateMnemonicMessage__,
     __MnemonicToHexSeedMessage__,
+    __GenerateSlip39SharesMessage__,
+    __Slip39SharesToHexSeedMessage__,
     __ComputeAliasIdMessage__,
     __ComputeNftIdMessage__,
     __ComputeFoundryIdMessage__,
@@ -68,6 +70,7 @@ import type {
     __GetMilestoneByIndexMessage__,
     __GetUtxoChangesByIndexMessage__,
     __StoreMnemonicMessage__,
+    __StoreSlip39SharesMessage__,
     __BuildBasicOutputMessage__,
     __BuildAliasOutputMessage__,
     __BuildFoundryOutputMessage__,
@@ -86,6 +89,8 @@ export type __ClientMessages__ =
     | __GetOutputsMessage__
     | __GenerateMnemonicMessage__
     | __MnemonicToHexSeedMessage__
+    | __GenerateSlip39SharesMessage__
+    | __Slip39SharesToHexSeedMessage__
     | __ComputeAliasIdMessage__
     | __ComputeNftIdMessage__
     | __ComputeFoundryIdMessage__
@@ -103,6 +108,7 @@ export type __ClientMessages__ =
     | __SignTransactionMessage__
     | __SignatureUnlockMessage__
     | __StoreMnemonicMessage__
+    | __StoreSlip39SharesMessage__
     | __PostBlockPayloadMessage__
     | __ParseBech32AddressMessage__
     | __BlockIdMessage__
@@ -29,6 +29,7 @@ export interface IMqttBrokerOptions {
     useWs?: boolean;
     port?: number;
     maxReconnectionAttempts?: number;
+    replayMissedMilestones?: boolean;
 }
 
 /**
@@ -12,6 +12,14 @@ export interface MnemonicSecretManager {
     mnemonic: string;
 }
 
+/** Secret manager that uses a mnemonic and a BIP-39 passphrase in plain memory. */
+export interface MnemonicWithPassphraseSecretManager {
+    mnemonicWithPassphrase: {
+        mnemonic: string;
+        passphrase: string;
+    };
+}
+
 /** Secret manager that uses Stronghold. */
 export interface StrongholdSecretManager {
     stronghold: {
@@ -24,4 +32,13 @@ export interface StrongholdSecretManager {
 export type SecretManager =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
+    | MnemonicWithPassphraseSecretManager
     | StrongholdSecretManager;
+
+/** The member shares of a group of a SLIP-39 backup. */
+export interface Slip39Group {
+    /** The number of member shares required to recover the group. */
+    memberThreshold: number;
+    /** The number of member shares of the group. */
+    memberCount: number;
+}
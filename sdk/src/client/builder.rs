@@ -10,12 +10,16 @@ use std::{
 
 #[cfg(not(target_family = "wasm"))]
 use tokio::runtime::Runtime;
+use tokio::sync::broadcast;
 
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
-        constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
+        constants::{
+            DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL,
+            PROTOCOL_PARAMETERS_CHANGE_CHANNEL_CAPACITY,
+        },
         error::Result,
         node_manager::{
             builder::validate_url,
@@ -48,6 +52,10 @@ pub struct NetworkInfo {
     /// The latest cached milestone timestamp.
     #[serde(rename = "latestMilestoneTimestamp")]
     pub latest_milestone_timestamp: Option<u32>,
+    /// Whether the protocol parameters were synced from a node, until then they're only the default or configured
+    /// ones.
+    #[serde(skip)]
+    pub(crate) protocol_parameters_synced: bool,
 }
 
 /// Dto for the NetworkInfo
@@ -143,6 +151,36 @@ fn default_remote_pow_timeout() -> Duration {
     DEFAULT_REMOTE_POW_API_TIMEOUT
 }
 
+impl NetworkInfo {
+    /// Updates the protocol parameters synced from a node and returns the change if they differ from the previous ones.
+    /// The first sync replaces the default or configured protocol parameters and isn't considered a change.
+    pub(crate) fn update_protocol_parameters(
+        &mut self,
+        protocol_parameters: ProtocolParameters,
+    ) -> Option<ProtocolParametersChange> {
+        let previously_synced = std::mem::replace(&mut self.protocol_parameters_synced, true);
+        if self.protocol_parameters == protocol_parameters {
+            return None;
+        }
+        let previous = std::mem::replace(&mut self.protocol_parameters, protocol_parameters);
+
+        previously_synced.then(|| ProtocolParametersChange {
+            previous,
+            current: self.protocol_parameters.clone(),
+        })
+    }
+}
+
+/// A change of the protocol parameters of the network, like the rent structure, the token supply or the protocol
+/// version, detected when the node info is refreshed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProtocolParametersChange {
+    /// The protocol parameters before the change.
+    pub previous: ProtocolParameters,
+    /// The protocol parameters after the change.
+    pub current: ProtocolParameters,
+}
+
 impl Default for NetworkInfo {
     fn default() -> Self {
         Self {
@@ -152,6 +190,7 @@ impl Default for NetworkInfo {
             fallback_to_local_pow: true,
             tips_interval: DEFAULT_TIPS_INTERVAL,
             latest_milestone_timestamp: None,
+            protocol_parameters_synced: false,
         }
     }
 }
@@ -330,6 +369,7 @@ impl ClientBuilder {
     /// Build the Client instance.
     pub fn finish(self) -> Result<Client> {
         let network_info = Arc::new(RwLock::new(self.network_info));
+        let (protocol_parameters_change_sender, _) = broadcast::channel(PROTOCOL_PARAMETERS_CHANGE_CHANNEL_CAPACITY);
         let healthy_nodes = Arc::new(RwLock::new(HashMap::new()));
 
         #[cfg(not(target_family = "wasm"))]
@@ -344,6 +384,7 @@ impl ClientBuilder {
 
             let healthy_nodes_ = healthy_nodes.clone();
             let network_info_ = network_info.clone();
+            let protocol_parameters_change_sender_ = protocol_parameters_change_sender.clone();
 
             let (runtime, sync_handle) = std::thread::spawn(move || {
                 let runtime = Runtime::new().expect("failed to create Tokio runtime");
@@ -351,6 +392,7 @@ impl ClientBuilder {
                     &healthy_nodes_,
                     &nodes,
                     &network_info_,
+                    &protocol_parameters_change_sender_,
                     self.node_manager_builder.ignore_node_health,
                 )) {
                     panic!("failed to sync nodes: {e:?}");
@@ -361,6 +403,7 @@ impl ClientBuilder {
                     nodes,
                     self.node_manager_builder.node_sync_interval,
                     network_info_,
+                    protocol_parameters_change_sender_,
                     self.node_manager_builder.ignore_node_health,
                 );
                 (runtime, sync_handle)
@@ -386,7 +429,10 @@ impl ClientBuilder {
             broker_options: self.broker_options,
             #[cfg(feature = "mqtt")]
             mqtt_event_channel: (Arc::new(mqtt_event_tx), mqtt_event_rx),
+            #[cfg(feature = "mqtt")]
+            mqtt_last_milestone_index: Default::default(),
             network_info,
+            protocol_parameters_change_sender,
             api_timeout: self.api_timeout,
             remote_pow_timeout: self.remote_pow_timeout,
             pow_worker_count: self.pow_worker_count,
@@ -394,3 +440,40 @@ impl ClientBuilder {
         Ok(client)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::output::RentStructureBuilder;
+
+    #[test]
+    fn syncing_the_default_protocol_parameters() {
+        let mut network_info = NetworkInfo::default();
+        assert!(!network_info.protocol_parameters_synced);
+
+        // The default protocol parameters are the ones of a real network, syncing them must still mark them as synced
+        assert_eq!(
+            network_info.update_protocol_parameters(ProtocolParameters::default()),
+            None
+        );
+        assert!(network_info.protocol_parameters_synced);
+
+        let protocol_parameters = ProtocolParameters::new(
+            2,
+            String::from("shimmer"),
+            String::from("smr"),
+            1500,
+            15,
+            RentStructureBuilder::new().byte_cost(200).finish(),
+            1_813_620_509_061_365,
+        )
+        .unwrap();
+        assert_eq!(
+            network_info.update_protocol_parameters(protocol_parameters.clone()),
+            Some(ProtocolParametersChange {
+                previous: ProtocolParameters::default(),
+                current: protocol_parameters,
+            })
+        );
+    }
+}
@@ -21,7 +21,7 @@ use {
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
-        builder::{ClientBuilder, NetworkInfo},
+        builder::{ClientBuilder, NetworkInfo, ProtocolParametersChange},
         constants::DEFAULT_TIPS_INTERVAL,
         error::Result,
         Error,
@@ -49,7 +49,12 @@ pub struct Client {
     pub(crate) broker_options: BrokerOptions,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt_event_channel: (Arc<WatchSender<MqttEvent>>, WatchReceiver<MqttEvent>),
+    /// The index of the last milestone seen through MQTT, used to replay missed milestones after a reconnection.
+    #[cfg(feature = "mqtt")]
+    pub(crate) mqtt_last_milestone_index: Arc<RwLock<Option<u32>>>,
     pub(crate) network_info: Arc<RwLock<NetworkInfo>>,
+    /// Sender of the protocol parameters changes detected when the network info is refreshed.
+    pub(crate) protocol_parameters_change_sender: tokio::sync::broadcast::Sender<ProtocolParametersChange>,
     /// HTTP request timeout.
     pub(crate) api_timeout: Duration,
     /// HTTP request timeout for remote PoW API call.
@@ -88,18 +93,21 @@ impl Drop for Client {
             }
         }
 
+        // Only disconnect the MQTT client when the last clone of the client is dropped, otherwise dropping any clone
+        // would kill the subscriptions of all clients.
         #[cfg(feature = "mqtt")]
-        let mqtt_client = self.mqtt_client.clone();
-        #[cfg(feature = "mqtt")]
-        std::thread::spawn(move || {
-            crate::client::async_runtime::block_on(async move {
-                if let Some(mqtt_client) = mqtt_client.write().await.take() {
-                    mqtt_client.disconnect().await.unwrap();
-                }
-            });
-        })
-        .join()
-        .unwrap();
+        if Arc::strong_count(&self.mqtt_client) == 1 {
+            let mqtt_client = self.mqtt_client.clone();
+            std::thread::spawn(move || {
+                crate::client::async_runtime::block_on(async move {
+                    if let Some(mqtt_client) = mqtt_client.write().await.take() {
+                        mqtt_client.disconnect().await.unwrap();
+                    }
+                });
+            })
+            .join()
+            .unwrap();
+        }
     }
 }
 
@@ -131,11 +139,17 @@ impl Client {
                 }
             }
             let info = self.get_info().await?.node_info;
-            let mut client_network_info = self
+            let protocol_parameters_change = self
                 .network_info
                 .write()
-                .map_err(|_| crate::client::Error::PoisonError)?;
-            client_network_info.protocol_parameters = info.protocol.try_into()?;
+                .map_err(|_| crate::client::Error::PoisonError)?
+                .update_protocol_parameters(info.protocol.try_into()?);
+            if let Some(protocol_parameters_change) = protocol_parameters_change {
+                // Sending only fails if there are no receivers.
+                self.protocol_parameters_change_sender
+                    .send(protocol_parameters_change)
+                    .ok();
+            }
 
             *LAST_SYNC.lock().unwrap() = Some(current_time + CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS);
         }
@@ -147,6 +161,27 @@ impl Client {
             .clone())
     }
 
+    /// Returns a receiver for the changes of the protocol parameters, like the rent structure, the token supply or the
+    /// protocol version. Changes are detected when the network info is refreshed by the node syncing process.
+    /// Transactions prepared before a change should be verified again with
+    /// [`PreparedTransactionData::verify_protocol_parameters`](crate::client::api::PreparedTransactionData::verify_protocol_parameters).
+    pub fn subscribe_protocol_parameters_changes(&self) -> tokio::sync::broadcast::Receiver<ProtocolParametersChange> {
+        self.protocol_parameters_change_sender.subscribe()
+    }
+
+    /// Returns the cached protocol parameters if they were synced from a node, without requesting them. Returns `None`
+    /// for an offline client, which only knows the default protocol parameters.
+    pub fn synced_protocol_parameters(&self) -> Result<Option<ProtocolParameters>> {
+        let network_info = self
+            .network_info
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?;
+
+        Ok(network_info
+            .protocol_parameters_synced
+            .then(|| network_info.protocol_parameters.clone()))
+    }
+
     /// Gets the protocol parameters of the node we're connecting to.
     pub async fn get_protocol_parameters(&self) -> Result<ProtocolParameters> {
         Ok(self.get_network_info().await?.protocol_parameters)
@@ -22,6 +22,11 @@ pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/",
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
+/// Number of protocol parameters changes buffered for each receiver before the oldest ones are dropped
+pub(crate) const PROTOCOL_PARAMETERS_CHANGE_CHANNEL_CAPACITY: usize = 16;
+/// Number of MQTT events buffered by a topic event stream before waiting for it to be polled
+#[cfg(feature = "mqtt")]
+pub(crate) const DEFAULT_MQTT_STREAM_CAPACITY: usize = 100;
 /// Delay for caching a node info response in WASM runtime
 #[cfg(target_family = "wasm")]
 pub(crate) const CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS: u32 = 60;
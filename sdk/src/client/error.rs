@@ -144,6 +144,9 @@ pub enum Error {
     /// Specifically used for `TryInfo` implementations for `SecretManager`.
     #[error("cannot unwrap a SecretManager: type mismatch!")]
     SecretManagerMismatch,
+    /// Invalid SLIP-39 shares or parameters
+    #[error("SLIP-39 error: {0}")]
+    Slip39(String),
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
@@ -274,6 +277,11 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     #[error("Stronghold reported a procedure error: {0}")]
     StrongholdProcedure(#[from] iota_stronghold::procedures::ProcedureError),
+    /// The Stronghold snapshot can't be read
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[error("the Stronghold snapshot can't be read: {0}")]
+    StrongholdSnapshotUnreadable(Box<crate::client::stronghold::SnapshotStatus>),
 }
 
 // map most errors to a single error but there are some errors that
@@ -15,6 +15,7 @@ use crate::{
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
         secret::SecretManagerDto,
+        slip39::Slip39Group,
     },
     types::block::{
         address::{dto::Ed25519AddressDto, AliasAddress},
@@ -210,7 +211,7 @@ pub enum Message {
         /// The hex encoded Ed25519 address
         address: Ed25519AddressDto,
     },
-    /// Store a mnemonic in the Stronghold vault
+    /// Store a mnemonic, with an optional BIP-39 passphrase, in the Stronghold vault
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
     StoreMnemonic {
@@ -219,6 +220,20 @@ pub enum Message {
         secret_manager: SecretManagerDto,
         /// Mnemonic
         mnemonic: String,
+        /// BIP-39 passphrase
+        passphrase: Option<String>,
+    },
+    /// Store the master secret recovered from SLIP-39 mnemonic shares in the Stronghold vault
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    StoreSlip39Shares {
+        /// Stronghold secret manager
+        #[serde(rename = "secretManager")]
+        secret_manager: SecretManagerDto,
+        /// A threshold of SLIP-39 mnemonic shares
+        shares: Vec<String>,
+        /// The passphrase of the shares
+        passphrase: Option<String>,
     },
     /// Build a block containing the specified payload and post it to the network.
     PostBlockPayload {
@@ -539,10 +554,29 @@ pub enum Message {
     },
     /// Generates a new mnemonic.
     GenerateMnemonic,
-    /// Returns a hex encoded seed for a mnemonic.
+    /// Returns a hex encoded seed for a mnemonic and an optional BIP-39 passphrase.
     MnemonicToHexSeed {
         /// Mnemonic
         mnemonic: String,
+        /// BIP-39 passphrase
+        passphrase: Option<String>,
+    },
+    /// Generates a new master secret and splits it into SLIP-39 mnemonic shares.
+    GenerateSlip39Shares {
+        /// The number of groups required to recover the master secret
+        #[serde(rename = "groupThreshold")]
+        group_threshold: u8,
+        /// The member threshold and count of each group
+        groups: Vec<Slip39Group>,
+        /// The passphrase required to recover the master secret
+        passphrase: Option<String>,
+    },
+    /// Returns a hex encoded seed for SLIP-39 mnemonic shares.
+    Slip39SharesToHexSeed {
+        /// A threshold of SLIP-39 mnemonic shares
+        shares: Vec<String>,
+        /// The passphrase of the shares
+        passphrase: Option<String>,
     },
     /// Returns a block ID (Blake2b256 hash of block bytes) from a block
     BlockId {
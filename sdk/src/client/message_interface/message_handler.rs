@@ -181,6 +181,16 @@ impl ClientMessageHandler {
             Message::MnemonicToHexSeed { .. } => {
                 log::debug!("Response: MnemonicToHexSeed{{ <omitted> }}")
             }
+            #[cfg(feature = "stronghold")]
+            Message::StoreSlip39Shares { .. } => {
+                log::debug!("Response: StoreSlip39Shares{{ <omitted> }}")
+            }
+            Message::GenerateSlip39Shares { .. } => {
+                log::debug!("Response: GenerateSlip39Shares{{ <omitted> }}")
+            }
+            Message::Slip39SharesToHexSeed { .. } => {
+                log::debug!("Response: Slip39SharesToHexSeed{{ <omitted> }}")
+            }
             _ => log::debug!("Message: {:?}", message),
         }
 
@@ -199,6 +209,9 @@ impl ClientMessageHandler {
             Response::MnemonicHexSeed { .. } => {
                 log::debug!("Response: MnemonicHexSeed(<omitted>)")
             }
+            Response::GeneratedSlip39Shares { .. } => {
+                log::debug!("Response: GeneratedSlip39Shares(<omitted>)")
+            }
             _ => log::debug!("Response: {:?}", response),
         }
 
@@ -465,10 +478,35 @@ impl ClientMessageHandler {
             Message::StoreMnemonic {
                 secret_manager,
                 mnemonic,
+                passphrase,
+            } => {
+                let mut secret_manager = (&secret_manager).try_into()?;
+                if let SecretManager::Stronghold(secret_manager) = &mut secret_manager {
+                    match passphrase {
+                        Some(passphrase) => {
+                            secret_manager
+                                .store_mnemonic_with_passphrase(mnemonic, passphrase)
+                                .await?
+                        }
+                        None => secret_manager.store_mnemonic(mnemonic).await?,
+                    }
+                } else {
+                    return Err(crate::client::Error::SecretManagerMismatch);
+                }
+
+                Ok(Response::Ok)
+            }
+            #[cfg(feature = "stronghold")]
+            Message::StoreSlip39Shares {
+                secret_manager,
+                shares,
+                passphrase,
             } => {
                 let mut secret_manager = (&secret_manager).try_into()?;
                 if let SecretManager::Stronghold(secret_manager) = &mut secret_manager {
-                    secret_manager.store_mnemonic(mnemonic).await?;
+                    secret_manager
+                        .store_slip39_shares(shares, passphrase.unwrap_or_default())
+                        .await?;
                 } else {
                     return Err(crate::client::Error::SecretManagerMismatch);
                 }
@@ -660,12 +698,43 @@ impl ClientMessageHandler {
             ))),
             Message::IsAddressValid { address } => Ok(Response::Bool(Address::is_valid_bech32(&address))),
             Message::GenerateMnemonic => Ok(Response::GeneratedMnemonic(Client::generate_mnemonic()?)),
-            Message::MnemonicToHexSeed { mut mnemonic } => {
-                let response = Response::MnemonicHexSeed(Client::mnemonic_to_hex_seed(&mnemonic)?);
+            Message::MnemonicToHexSeed {
+                mut mnemonic,
+                mut passphrase,
+            } => {
+                let response =
+                    Client::mnemonic_to_hex_seed_with_passphrase(&mnemonic, passphrase.as_deref().unwrap_or_default())
+                        .map(Response::MnemonicHexSeed);
 
                 mnemonic.zeroize();
+                passphrase.zeroize();
+
+                response
+            }
+            Message::GenerateSlip39Shares {
+                group_threshold,
+                groups,
+                mut passphrase,
+            } => {
+                let response =
+                    Client::generate_slip39_shares(group_threshold, &groups, passphrase.as_deref().unwrap_or_default())
+                        .map(Response::GeneratedSlip39Shares);
+
+                passphrase.zeroize();
+
+                response
+            }
+            Message::Slip39SharesToHexSeed {
+                mut shares,
+                mut passphrase,
+            } => {
+                let response = Client::slip39_shares_to_hex_seed(&shares, passphrase.as_deref().unwrap_or_default())
+                    .map(Response::MnemonicHexSeed);
+
+                shares.zeroize();
+                passphrase.zeroize();
 
-                Ok(response)
+                response
             }
             Message::BlockId { block } => {
                 let block = Block::try_from_dto_unverified(&block)?;
@@ -206,7 +206,11 @@ pub enum Response {
     /// - [`GenerateMnemonic`](crate::message_interface::Message::GenerateMnemonic)
     GeneratedMnemonic(String),
     /// Response for:
+    /// - [`GenerateSlip39Shares`](crate::message_interface::Message::GenerateSlip39Shares)
+    GeneratedSlip39Shares(Vec<Vec<String>>),
+    /// Response for:
     /// - [`MnemonicToHexSeed`](crate::message_interface::Message::MnemonicToHexSeed)
+    /// - [`Slip39SharesToHexSeed`](crate::message_interface::Message::Slip39SharesToHexSeed)
     MnemonicHexSeed(String),
     /// Response for:
     /// - [`BlockId`](crate::message_interface::Message::BlockId)
@@ -234,6 +238,7 @@ pub enum Response {
     /// Response for:
     /// - [`ClearListeners`](crate::message_interface::Message::ClearListeners)
     /// - [`StoreMnemonic`](crate::message_interface::Message::StoreMnemonic)
+    /// - [`StoreSlip39Shares`](crate::message_interface::Message::StoreSlip39Shares)
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
@@ -45,6 +45,7 @@ pub mod message_interface;
 pub mod node_api;
 pub mod node_manager;
 pub mod secret;
+pub mod slip39;
 pub mod storage;
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -58,7 +59,7 @@ pub use url::Url;
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
 pub use self::{
-    builder::{ClientBuilder, NetworkInfo, NetworkInfoDto},
+    builder::{ClientBuilder, NetworkInfo, NetworkInfoDto, ProtocolParametersChange},
     client::*,
     error::*,
     node_api::core::routes::NodeInfoWrapper,
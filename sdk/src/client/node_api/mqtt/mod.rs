@@ -4,31 +4,45 @@
 //! IOTA node MQTT API
 
 mod error;
+mod stream;
 pub mod types;
 
 use std::{
-    sync::{Arc, RwLock as StdRwLock},
-    time::Instant,
+    str::FromStr,
+    sync::{atomic::AtomicU64, Arc, RwLock as StdRwLock},
+    time::{Duration, Instant},
 };
 
 use crypto::utils;
 use log::warn;
 use packable::PackableExt;
-use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
+use rumqttc::{
+    AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, Publish, QoS, SubscribeFilter, Transport,
+};
 use tokio::sync::{
     watch::{Receiver as WatchReceiver, Sender},
     RwLock,
 };
 
-pub use self::{error::Error, types::*};
+pub use self::{error::Error, stream::TopicEventStream, types::*};
 use crate::{
-    client::{Client, NetworkInfo},
-    types::block::{
-        payload::{milestone::ReceiptMilestoneOption, MilestonePayload},
-        Block,
+    client::{
+        constants::DEFAULT_MQTT_STREAM_CAPACITY, node_api::core::routes::NodeInfoWrapper, node_manager::NodeManager,
+        Client, NetworkInfo,
+    },
+    types::{
+        api::core::response::{MilestoneResponse, OutputWithMetadataResponse, UtxoChangesResponse},
+        block::{
+            output::{Output, OutputId, UnlockCondition},
+            payload::{milestone::ReceiptMilestoneOption, MilestonePayload},
+            Block,
+        },
     },
 };
 
+/// The topic used internally to track the last confirmed milestone index.
+const MILESTONE_INFO_CONFIRMED_TOPIC: &str = "milestone-info/confirmed";
+
 impl Client {
     /// Returns a handle to the MQTT topics manager.
     pub fn subscriber(&self) -> MqttManager<'_> {
@@ -44,6 +58,15 @@ impl Client {
         MqttManager::new(self).with_topics(topics).subscribe(callback).await
     }
 
+    /// Subscribe to MQTT events with a stream.
+    /// The topics are unsubscribed when the stream is dropped.
+    pub async fn subscribe_stream(&self, topics: Vec<Topic>) -> Result<TopicEventStream, Error> {
+        MqttManager::new(self)
+            .with_topics(topics)
+            .subscribe_stream(DEFAULT_MQTT_STREAM_CAPACITY)
+            .await
+    }
+
     /// Unsubscribe from MQTT events.
     pub async fn unsubscribe(&self, topics: Vec<Topic>) -> Result<(), Error> {
         MqttManager::new(self).with_topics(topics).unsubscribe().await
@@ -53,6 +76,181 @@ impl Client {
     pub fn mqtt_event_receiver(&self) -> WatchReceiver<MqttEvent> {
         self.mqtt_event_channel.1.clone()
     }
+
+    /// Returns the index of the last confirmed milestone seen through MQTT, if any.
+    pub fn mqtt_last_milestone_index(&self) -> Option<u32> {
+        *self.mqtt_last_milestone_index.read().unwrap()
+    }
+}
+
+/// The state needed to replay the milestones that were missed while the MQTT connection was down.
+struct MilestoneReplay {
+    node_manager: NodeManager,
+    timeout: Duration,
+    last_milestone_index: Arc<StdRwLock<Option<u32>>>,
+}
+
+impl MilestoneReplay {
+    fn update_last_milestone_index(&self, index: u32) {
+        let mut last_milestone_index = self.last_milestone_index.write().unwrap();
+        if *last_milestone_index < Some(index) {
+            last_milestone_index.replace(index);
+        }
+    }
+
+    /// Fetches the confirmed milestones after the last seen one and dispatches them to the `milestones` handlers, and
+    /// the outputs they created and consumed to the handlers of the matching `outputs/...` topics, like the node would
+    /// have published them. The whole gap is replayed one milestone after another, other topics like the block topics
+    /// aren't replayed.
+    async fn replay(
+        &self,
+        mqtt_topic_handlers_guard: &RwLock<TopicHandlerMap>,
+        network_info: &StdRwLock<NetworkInfo>,
+    ) -> crate::client::Result<()> {
+        let Some(last_milestone_index) = *self.last_milestone_index.read().unwrap() else {
+            return Ok(());
+        };
+        let info = self
+            .node_manager
+            .get_request::<NodeInfoWrapper>("api/core/v2/info", None, self.timeout, false, false)
+            .await?;
+        let confirmed_milestone_index = info.node_info.status.confirmed_milestone.index;
+
+        for index in last_milestone_index + 1..=confirmed_milestone_index {
+            // Clone the handlers so the lock isn't held while waiting for slow streams.
+            let mqtt_topic_handlers = mqtt_topic_handlers_guard.read().await.clone();
+            let protocol_parameters = network_info.read().unwrap().protocol_parameters.clone();
+
+            if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked("milestones".to_string())) {
+                let milestone = match self
+                    .node_manager
+                    .get_request::<MilestoneResponse>(
+                        &format!("api/core/v2/milestones/by-index/{index}"),
+                        None,
+                        self.timeout,
+                        false,
+                        true,
+                    )
+                    .await?
+                {
+                    MilestoneResponse::Json(dto) => MilestonePayload::try_from_dto(&dto, &protocol_parameters)?,
+                    MilestoneResponse::Raw(_) => return Err(crate::client::Error::UnexpectedApiResponse),
+                };
+                let event = TopicEvent {
+                    topic: "milestones".to_string(),
+                    payload: MqttPayload::MilestonePayload(milestone),
+                };
+                for handler in handlers {
+                    handler.handle(&event);
+                }
+            }
+
+            if mqtt_topic_handlers
+                .keys()
+                .any(|topic| topic.topic().starts_with("outputs/"))
+            {
+                let utxo_changes = self
+                    .node_manager
+                    .get_request::<UtxoChangesResponse>(
+                        &format!("api/core/v2/milestones/by-index/{index}/utxo-changes"),
+                        None,
+                        self.timeout,
+                        false,
+                        false,
+                    )
+                    .await?;
+                let created_outputs = utxo_changes.created_outputs.iter().map(|output_id| (output_id, false));
+                let consumed_outputs = utxo_changes.consumed_outputs.iter().map(|output_id| (output_id, true));
+
+                for (output_id, spent) in created_outputs.chain(consumed_outputs) {
+                    let output_id = OutputId::from_str(output_id)?;
+                    let output_with_metadata = self
+                        .node_manager
+                        .get_request::<OutputWithMetadataResponse>(
+                            &format!("api/core/v2/outputs/{output_id}"),
+                            None,
+                            self.timeout,
+                            false,
+                            true,
+                        )
+                        .await?;
+                    let output =
+                        Output::try_from_dto(&output_with_metadata.output, protocol_parameters.token_supply())?;
+                    let payload = MqttPayload::Json(serde_json::to_value(&output_with_metadata)?);
+
+                    for topic in output_topics(&output, &output_id, spent, protocol_parameters.bech32_hrp()) {
+                        if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(topic.clone())) {
+                            let event = TopicEvent {
+                                topic,
+                                payload: payload.clone(),
+                            };
+                            for handler in handlers {
+                                handler.handle(&event);
+                            }
+                        }
+                    }
+                }
+            }
+
+            self.update_last_milestone_index(index);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the `outputs/...` topics the node publishes an output on when it gets created or spent.
+fn output_topics(output: &Output, output_id: &OutputId, spent: bool, bech32_hrp: &str) -> Vec<String> {
+    let mut topics = vec![format!("outputs/{output_id}")];
+
+    // The chain topics are only published for created outputs.
+    if !spent {
+        match output {
+            Output::Alias(alias_output) => {
+                topics.push(format!("outputs/alias/{}", alias_output.alias_id_non_null(output_id)));
+            }
+            Output::Nft(nft_output) => topics.push(format!("outputs/nft/{}", nft_output.nft_id_non_null(output_id))),
+            Output::Foundry(foundry_output) => topics.push(format!("outputs/foundry/{}", foundry_output.id())),
+            Output::Basic(_) | Output::Treasury(_) => {}
+        }
+    }
+
+    let suffix = if spent { "/spent" } else { "" };
+    for unlock_condition in output.unlock_conditions().iter().flat_map(|u| u.iter()) {
+        let (condition, address) = match unlock_condition {
+            UnlockCondition::Address(u) => ("address", u.address()),
+            UnlockCondition::StorageDepositReturn(u) => ("storage-return", u.return_address()),
+            UnlockCondition::Expiration(u) => ("expiration", u.return_address()),
+            UnlockCondition::StateControllerAddress(u) => ("state-controller", u.address()),
+            UnlockCondition::GovernorAddress(u) => ("governor", u.address()),
+            UnlockCondition::ImmutableAliasAddress(u) => ("immutable-alias", u.address()),
+            UnlockCondition::Timelock(_) => continue,
+        };
+        let bech32_address = address.to_bech32(bech32_hrp);
+        topics.push(format!("outputs/unlock/{condition}/{bech32_address}{suffix}"));
+        // Subscriptions with the `+` wildcard match every unlock condition.
+        let any_condition_topic = format!("outputs/unlock/+/{bech32_address}{suffix}");
+        if !topics.contains(&any_condition_topic) {
+            topics.push(any_condition_topic);
+        }
+    }
+
+    topics
+}
+
+/// Returns the subscribe filters for the given topics, including the one used to track the last milestone index if
+/// missed milestones should be replayed.
+fn subscribe_filters<'a>(topics: impl Iterator<Item = &'a Topic>, options: &BrokerOptions) -> Vec<SubscribeFilter> {
+    let mut filters = topics
+        .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce))
+        .collect::<Vec<SubscribeFilter>>();
+    if options.replay_missed_milestones && !filters.iter().any(|f| f.path == MILESTONE_INFO_CONFIRMED_TOPIC) {
+        filters.push(SubscribeFilter::new(
+            MILESTONE_INFO_CONFIRMED_TOPIC.to_string(),
+            QoS::AtLeastOnce,
+        ));
+    }
+    filters
 }
 
 async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
@@ -127,6 +325,11 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                     client.mqtt_event_channel.0.clone(),
                     connection,
                     client.network_info.clone(),
+                    MilestoneReplay {
+                        node_manager: client.node_manager.clone(),
+                        timeout: client.get_timeout(),
+                        last_milestone_index: client.mqtt_last_milestone_index.clone(),
+                    },
                 );
             }
         }
@@ -141,6 +344,7 @@ fn poll_mqtt(
     event_sender: Arc<Sender<MqttEvent>>,
     mut event_loop: EventLoop,
     network_info: Arc<StdRwLock<NetworkInfo>>,
+    milestone_replay: MilestoneReplay,
 ) {
     std::thread::spawn(move || {
         let runtime = tokio::runtime::Builder::new_current_thread()
@@ -148,6 +352,14 @@ fn poll_mqtt(
             .build()
             .expect("failed to create Tokio runtime");
         runtime.block_on(async move {
+            let (dispatch_sender, dispatch_receiver) = tokio::sync::mpsc::unbounded_channel();
+            tokio::spawn(dispatch_events(
+                dispatch_receiver,
+                mqtt_topic_handlers_guard.clone(),
+                network_info,
+                milestone_replay,
+            ));
+
             // rumqttc performs automatic reconnection since we keep running the event loop
             // but the subscriptions are lost on reconnection, so we need to resubscribe
             // the `is_subscribed` flag is set to false on event error, so the ConnAck event
@@ -159,7 +371,6 @@ fn poll_mqtt(
 
             loop {
                 let event = event_loop.poll().await;
-                let mqtt_topic_handlers_guard = mqtt_topic_handlers_guard.clone();
 
                 match event {
                     Ok(Event::Incoming(Incoming::ConnAck(_))) => {
@@ -167,88 +378,22 @@ fn poll_mqtt(
                         if !is_subscribed {
                             is_subscribed = true;
                             // resubscribe topics
-                            let topics = mqtt_topic_handlers_guard
-                                .read()
-                                .await
-                                .keys()
-                                .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce))
-                                .collect::<Vec<SubscribeFilter>>();
-                            if !topics.is_empty() {
-                                let _ = mqtt_client.subscribe_many(topics).await;
+                            let mqtt_topic_handlers = mqtt_topic_handlers_guard.read().await;
+                            if !mqtt_topic_handlers.is_empty() {
+                                let _ = mqtt_client
+                                    .subscribe_many(subscribe_filters(mqtt_topic_handlers.keys(), &options))
+                                    .await;
+                            }
+                            drop(mqtt_topic_handlers);
+                            // backfill the milestones that were missed while the connection was down, before the
+                            // live events received from now on
+                            if options.replay_missed_milestones {
+                                let _ = dispatch_sender.send(Dispatch::Replay);
                             }
                         }
                     }
                     Ok(Event::Incoming(Incoming::Publish(p))) => {
-                        let topic = p.topic.clone();
-                        let network_info = network_info.clone();
-
-                        crate::client::async_runtime::spawn(async move {
-                            let mqtt_topic_handlers = mqtt_topic_handlers_guard.read().await;
-
-                            if let Some(handlers) = mqtt_topic_handlers.get(&Topic::new_unchecked(topic.clone())) {
-                                let event = {
-                                    if topic.contains("blocks") || topic.contains("included-block") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
-
-                                        match Block::unpack_verified(payload, protocol_parameters) {
-                                            Ok(block) => Ok(TopicEvent {
-                                                topic,
-                                                payload: MqttPayload::Block(block),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Block unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if topic.contains("milestones") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
-
-                                        match MilestonePayload::unpack_verified(payload, protocol_parameters) {
-                                            Ok(milestone_payload) => Ok(TopicEvent {
-                                                topic,
-                                                payload: MqttPayload::MilestonePayload(milestone_payload),
-                                            }),
-                                            Err(e) => {
-                                                warn!("MilestonePayload unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else if topic.contains("receipts") {
-                                        let payload = &*p.payload;
-                                        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
-
-                                        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
-                                            Ok(receipt) => Ok(TopicEvent {
-                                                topic,
-                                                payload: MqttPayload::Receipt(receipt),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Receipt unpacking failed: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    } else {
-                                        match serde_json::from_slice(&p.payload) {
-                                            Ok(value) => Ok(TopicEvent {
-                                                topic,
-                                                payload: MqttPayload::Json(value),
-                                            }),
-                                            Err(e) => {
-                                                warn!("Cannot parse JSON: {:?}", e);
-                                                Err(())
-                                            }
-                                        }
-                                    }
-                                };
-                                if let Ok(event) = event {
-                                    for handler in handlers {
-                                        handler(&event);
-                                    }
-                                };
-                            }
-                        });
+                        let _ = dispatch_sender.send(Dispatch::Publish(p));
                     }
                     Err(_) => {
                         if error_instant.elapsed().as_secs() < 5 {
@@ -270,6 +415,124 @@ fn poll_mqtt(
     });
 }
 
+/// Work for the task dispatching the MQTT events.
+#[derive(Debug)]
+enum Dispatch {
+    /// Replay the milestones missed while the connection was down.
+    Replay,
+    /// Pass a received publish to the handlers of its topic.
+    Publish(Publish),
+}
+
+/// Replays missed milestones and dispatches received publishes one after another, so the handlers get the events in
+/// order. Runs next to the event loop, which keeps polling and sending keep-alive pings while milestones are
+/// replayed.
+async fn dispatch_events(
+    mut dispatch_receiver: tokio::sync::mpsc::UnboundedReceiver<Dispatch>,
+    mqtt_topic_handlers_guard: Arc<RwLock<TopicHandlerMap>>,
+    network_info: Arc<StdRwLock<NetworkInfo>>,
+    milestone_replay: MilestoneReplay,
+) {
+    while let Some(dispatch) = dispatch_receiver.recv().await {
+        match dispatch {
+            Dispatch::Replay => {
+                if let Err(e) = milestone_replay.replay(&mqtt_topic_handlers_guard, &network_info).await {
+                    warn!("Replaying missed milestones failed: {:?}", e);
+                }
+            }
+            Dispatch::Publish(p) => {
+                if p.topic == MILESTONE_INFO_CONFIRMED_TOPIC {
+                    if let Some(index) = serde_json::from_slice::<serde_json::Value>(&p.payload)
+                        .ok()
+                        .and_then(|value| value.get("index")?.as_u64())
+                        .and_then(|index| u32::try_from(index).ok())
+                    {
+                        milestone_replay.update_last_milestone_index(index);
+                    }
+                }
+
+                dispatch_publish(&p, &mqtt_topic_handlers_guard, &network_info).await;
+            }
+        }
+    }
+}
+
+/// Decodes the payload of a received publish according to its topic and passes it to the handlers of the topic, one
+/// after another.
+async fn dispatch_publish(
+    publish: &Publish,
+    mqtt_topic_handlers_guard: &RwLock<TopicHandlerMap>,
+    network_info: &StdRwLock<NetworkInfo>,
+) {
+    // Clone the handlers so the lock isn't held while waiting for slow streams.
+    let Some(handlers) = mqtt_topic_handlers_guard
+        .read()
+        .await
+        .get(&Topic::new_unchecked(publish.topic.clone()))
+        .cloned()
+    else {
+        return;
+    };
+
+    let topic = publish.topic.clone();
+    let payload = &*publish.payload;
+    let event = if topic.contains("blocks") || topic.contains("included-block") {
+        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
+
+        match Block::unpack_verified(payload, protocol_parameters) {
+            Ok(block) => TopicEvent {
+                topic,
+                payload: MqttPayload::Block(block),
+            },
+            Err(e) => {
+                warn!("Block unpacking failed: {:?}", e);
+                return;
+            }
+        }
+    } else if topic.contains("milestones") {
+        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
+
+        match MilestonePayload::unpack_verified(payload, protocol_parameters) {
+            Ok(milestone_payload) => TopicEvent {
+                topic,
+                payload: MqttPayload::MilestonePayload(milestone_payload),
+            },
+            Err(e) => {
+                warn!("MilestonePayload unpacking failed: {:?}", e);
+                return;
+            }
+        }
+    } else if topic.contains("receipts") {
+        let protocol_parameters = &network_info.read().unwrap().protocol_parameters;
+
+        match ReceiptMilestoneOption::unpack_verified(payload, protocol_parameters) {
+            Ok(receipt) => TopicEvent {
+                topic,
+                payload: MqttPayload::Receipt(receipt),
+            },
+            Err(e) => {
+                warn!("Receipt unpacking failed: {:?}", e);
+                return;
+            }
+        }
+    } else {
+        match serde_json::from_slice(payload) {
+            Ok(value) => TopicEvent {
+                topic,
+                payload: MqttPayload::Json(value),
+            },
+            Err(e) => {
+                warn!("Cannot parse JSON: {:?}", e);
+                return;
+            }
+        }
+    };
+
+    for handler in &handlers {
+        handler.handle(&event);
+    }
+}
+
 /// MQTT subscriber.
 pub struct MqttManager<'a> {
     client: &'a Client,
@@ -307,6 +570,7 @@ impl<'a> MqttManager<'a> {
         }
 
         *self.client.mqtt_client.write().await = None;
+        *self.client.mqtt_last_milestone_index.write().unwrap() = None;
 
         Ok(())
     }
@@ -342,11 +606,27 @@ impl<'a> MqttTopicManager<'a> {
         self,
         callback: C,
     ) -> Result<(), Error> {
-        let cb =
-            Arc::new(Box::new(callback)
-                as Box<
-                    dyn Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static,
-                >);
+        self.subscribe_handler(TopicHandler::Callback(Arc::new(Box::new(callback))))
+            .await
+    }
+
+    /// Subscribe to the given topics with a stream buffering at most `capacity` events. The events are received in the
+    /// order the node published them. Once the stream is full, new events for it are dropped until it's polled again
+    /// and counted in [`TopicEventStream::lagged_events`], so a stream that isn't polled doesn't stall the other
+    /// subscribers and the MQTT connection.
+    /// The topics are unsubscribed when the stream is dropped.
+    pub async fn subscribe_stream(self, capacity: usize) -> Result<TopicEventStream, Error> {
+        let (sender, receiver) = tokio::sync::mpsc::channel(capacity);
+        let lagged = Arc::new(AtomicU64::new(0));
+        let topics = self.topics.clone();
+        let client = self.client;
+        self.subscribe_handler(TopicHandler::Channel(sender.clone(), lagged.clone()))
+            .await?;
+
+        Ok(TopicEventStream::new(client, topics, sender, receiver, lagged))
+    }
+
+    async fn subscribe_handler(self, handler: TopicHandler) -> Result<(), Error> {
         set_mqtt_client(self.client).await?;
         self.client
             .mqtt_client
@@ -354,24 +634,13 @@ impl<'a> MqttTopicManager<'a> {
             .await
             .as_ref()
             .ok_or(Error::ConnectionNotFound)?
-            .subscribe_many(
-                self.topics
-                    .iter()
-                    .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce))
-                    .collect::<Vec<SubscribeFilter>>(),
-            )
+            .subscribe_many(subscribe_filters(self.topics.iter(), &self.client.broker_options))
             .await?;
         {
             let mqtt_topic_handlers = &self.client.mqtt_topic_handlers;
             let mut mqtt_topic_handlers = mqtt_topic_handlers.write().await;
             for topic in self.topics {
-                #[allow(clippy::option_if_let_else)]
-                match mqtt_topic_handlers.get_mut(&topic) {
-                    Some(handlers) => handlers.push(cb.clone()),
-                    None => {
-                        mqtt_topic_handlers.insert(topic, vec![cb.clone()]);
-                    }
-                }
+                mqtt_topic_handlers.entry(topic).or_default().push(handler.clone());
             }
         }
         Ok(())
@@ -412,3 +681,186 @@ impl<'a> MqttTopicManager<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+            BasicOutputBuilder, NftId, NftOutputBuilder,
+        },
+        protocol::protocol_parameters,
+    };
+
+    const OUTPUT_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c6490000";
+
+    #[test]
+    fn output_topics_of_created_and_spent_outputs() {
+        let protocol_parameters = protocol_parameters();
+        let token_supply = protocol_parameters.token_supply();
+        let hrp = protocol_parameters.bech32_hrp();
+        let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+        let address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let return_address = Address::Ed25519(Ed25519Address::new([2; 32]));
+
+        let basic_output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(return_address, 100_000, token_supply).unwrap(),
+            )
+            .finish_output(token_supply)
+            .unwrap();
+        assert_eq!(
+            output_topics(&basic_output, &output_id, false, hrp),
+            vec![
+                format!("outputs/{OUTPUT_ID}"),
+                format!("outputs/unlock/address/{}", address.to_bech32(hrp)),
+                format!("outputs/unlock/+/{}", address.to_bech32(hrp)),
+                format!("outputs/unlock/storage-return/{}", return_address.to_bech32(hrp)),
+                format!("outputs/unlock/+/{}", return_address.to_bech32(hrp)),
+            ]
+        );
+        assert_eq!(
+            output_topics(&basic_output, &output_id, true, hrp),
+            vec![
+                format!("outputs/{OUTPUT_ID}"),
+                format!("outputs/unlock/address/{}/spent", address.to_bech32(hrp)),
+                format!("outputs/unlock/+/{}/spent", address.to_bech32(hrp)),
+                format!("outputs/unlock/storage-return/{}/spent", return_address.to_bech32(hrp)),
+                format!("outputs/unlock/+/{}/spent", return_address.to_bech32(hrp)),
+            ]
+        );
+
+        // A newly minted NFT is published on the id derived from its output id, but not when it's spent.
+        let nft_output = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)
+            .unwrap();
+        let nft_topic = format!("outputs/nft/{}", NftId::from(&output_id));
+        assert!(output_topics(&nft_output, &output_id, false, hrp).contains(&nft_topic));
+        assert!(!output_topics(&nft_output, &output_id, true, hrp).contains(&nft_topic));
+    }
+
+    #[tokio::test]
+    async fn dispatch_in_order_and_drop_events_of_full_streams() {
+        let topic = Topic::try_new("milestone-info/latest").unwrap();
+        let (full_sender, mut full_receiver) = tokio::sync::mpsc::channel(1);
+        let full_lagged = Arc::new(AtomicU64::new(0));
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+        let lagged = Arc::new(AtomicU64::new(0));
+        let handlers = RwLock::new(TopicHandlerMap::from([(
+            topic.clone(),
+            vec![
+                TopicHandler::Channel(full_sender, full_lagged.clone()),
+                TopicHandler::Channel(sender, lagged.clone()),
+            ],
+        )]));
+        let network_info = StdRwLock::new(NetworkInfo::default());
+        let publish = |index: u64| {
+            Publish::new(
+                topic.topic(),
+                QoS::AtLeastOnce,
+                serde_json::json!({ "index": index }).to_string(),
+            )
+        };
+        let index = |event: TopicEvent| match event.payload {
+            MqttPayload::Json(value) => value["index"].as_u64().unwrap(),
+            payload => panic!("unexpected payload {payload:?}"),
+        };
+
+        // Dispatching doesn't wait for the stream that isn't polled
+        for i in 0..20 {
+            tokio::time::timeout(
+                Duration::from_millis(100),
+                dispatch_publish(&publish(i), &handlers, &network_info),
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut indexes = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            indexes.push(index(event));
+        }
+        assert_eq!(indexes, (0..20).collect::<Vec<_>>());
+        assert_eq!(lagged.load(std::sync::atomic::Ordering::Relaxed), 0);
+
+        // The full stream only got the first event, the others were dropped and counted
+        assert_eq!(index(full_receiver.try_recv().unwrap()), 0);
+        assert!(full_receiver.try_recv().is_err());
+        assert_eq!(full_lagged.load(std::sync::atomic::Ordering::Relaxed), 19);
+    }
+
+    #[tokio::test]
+    async fn dispatch_events_in_order_and_track_the_confirmed_milestone() {
+        let client = Client::builder().finish().unwrap();
+        let topic = Topic::try_new(MILESTONE_INFO_CONFIRMED_TOPIC).unwrap();
+        let (sender, mut receiver) = tokio::sync::mpsc::channel(100);
+        client
+            .mqtt_topic_handlers
+            .write()
+            .await
+            .insert(topic.clone(), vec![TopicHandler::Channel(sender, Arc::default())]);
+        let milestone_replay = MilestoneReplay {
+            node_manager: client.node_manager.clone(),
+            timeout: client.get_timeout(),
+            last_milestone_index: client.mqtt_last_milestone_index.clone(),
+        };
+
+        let (dispatch_sender, dispatch_receiver) = tokio::sync::mpsc::unbounded_channel();
+        // Nothing to replay before the first milestone was seen
+        dispatch_sender.send(Dispatch::Replay).unwrap();
+        for index in [5u32, 7, 6] {
+            let payload = serde_json::json!({ "index": index }).to_string();
+            dispatch_sender
+                .send(Dispatch::Publish(Publish::new(
+                    topic.topic(),
+                    QoS::AtLeastOnce,
+                    payload,
+                )))
+                .unwrap();
+        }
+        drop(dispatch_sender);
+        dispatch_events(
+            dispatch_receiver,
+            client.mqtt_topic_handlers.clone(),
+            client.network_info.clone(),
+            milestone_replay,
+        )
+        .await;
+
+        let mut indexes = Vec::new();
+        while let Ok(event) = receiver.try_recv() {
+            match event.payload {
+                MqttPayload::Json(value) => indexes.push(value["index"].as_u64().unwrap()),
+                payload => panic!("unexpected payload {payload:?}"),
+            }
+        }
+        assert_eq!(indexes, [5, 7, 6]);
+        assert_eq!(client.mqtt_last_milestone_index(), Some(7));
+    }
+
+    #[tokio::test]
+    async fn dropping_a_clone_keeps_the_connection() {
+        let (mqtt_client, _event_loop) = AsyncClient::new(MqttOptions::new("test", "localhost", 1883), 10);
+        let client = Client::builder().finish().unwrap();
+        client.mqtt_client.write().await.replace(mqtt_client);
+        let topic = Topic::try_new("milestones").unwrap();
+        client
+            .mqtt_topic_handlers
+            .write()
+            .await
+            .insert(topic.clone(), vec![TopicHandler::Callback(Arc::new(Box::new(|_| {})))]);
+
+        // Like the clones used by the tasks of the wallet syncing
+        let clone = client.clone();
+        tokio::spawn(async move { drop(clone) }).await.unwrap();
+
+        assert!(client.mqtt_client.read().await.is_some());
+        assert!(client.mqtt_topic_handlers.read().await.contains_key(&topic));
+    }
+}
@@ -0,0 +1,182 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! MQTT event stream
+
+use std::{
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, RwLock as StdRwLock,
+    },
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use rumqttc::AsyncClient;
+use tokio::sync::{
+    mpsc::{Receiver, Sender, WeakSender},
+    RwLock,
+};
+
+use super::{Topic, TopicEvent, TopicHandler, TopicHandlerMap};
+use crate::client::Client;
+
+/// A stream of the events received on the subscribed MQTT topics.
+/// The topics are unsubscribed when the stream is dropped.
+pub struct TopicEventStream {
+    receiver: Receiver<TopicEvent>,
+    sender: WeakSender<TopicEvent>,
+    topics: Vec<Topic>,
+    lagged: Arc<AtomicU64>,
+    mqtt_client: Arc<RwLock<Option<AsyncClient>>>,
+    mqtt_topic_handlers: Arc<RwLock<TopicHandlerMap>>,
+    mqtt_last_milestone_index: Arc<StdRwLock<Option<u32>>>,
+    automatic_disconnect: bool,
+}
+
+impl TopicEventStream {
+    pub(crate) fn new(
+        client: &Client,
+        topics: Vec<Topic>,
+        sender: Sender<TopicEvent>,
+        receiver: Receiver<TopicEvent>,
+        lagged: Arc<AtomicU64>,
+    ) -> Self {
+        Self {
+            receiver,
+            sender: sender.downgrade(),
+            topics,
+            lagged,
+            mqtt_client: client.mqtt_client.clone(),
+            mqtt_topic_handlers: client.mqtt_topic_handlers.clone(),
+            mqtt_last_milestone_index: client.mqtt_last_milestone_index.clone(),
+            automatic_disconnect: client.broker_options.automatic_disconnect,
+        }
+    }
+
+    /// Returns the topics of the stream.
+    pub fn topics(&self) -> &[Topic] {
+        &self.topics
+    }
+
+    /// Returns the number of events that were dropped because the stream was full.
+    pub fn lagged_events(&self) -> u64 {
+        self.lagged.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for TopicEventStream {
+    type Item = TopicEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for TopicEventStream {
+    fn drop(&mut self) {
+        // The handlers were already removed, by an unsubscription or a disconnection.
+        let Some(sender) = self.sender.upgrade() else {
+            return;
+        };
+        let topics = std::mem::take(&mut self.topics);
+        let mqtt_client = self.mqtt_client.clone();
+        let mqtt_topic_handlers = self.mqtt_topic_handlers.clone();
+        let mqtt_last_milestone_index = self.mqtt_last_milestone_index.clone();
+        let automatic_disconnect = self.automatic_disconnect;
+
+        crate::client::async_runtime::spawn(async move {
+            let (unused_topics, empty_topic_handlers) = {
+                let mut mqtt_topic_handlers = mqtt_topic_handlers.write().await;
+                let mut unused_topics = Vec::new();
+                for topic in topics {
+                    if let Some(handlers) = mqtt_topic_handlers.get_mut(&topic) {
+                        handlers.retain(
+                            |handler| !matches!(handler, TopicHandler::Channel(other, _) if other.same_channel(&sender)),
+                        );
+                        if handlers.is_empty() {
+                            mqtt_topic_handlers.remove(&topic);
+                            unused_topics.push(topic);
+                        }
+                    }
+                }
+                (unused_topics, mqtt_topic_handlers.is_empty())
+            };
+
+            if let Some(client) = &*mqtt_client.read().await {
+                for topic in &unused_topics {
+                    let _ = client.unsubscribe(topic.topic()).await;
+                }
+            }
+
+            if automatic_disconnect && empty_topic_handlers {
+                if let Some(client) = mqtt_client.write().await.take() {
+                    let _ = client.disconnect().await;
+                }
+                *mqtt_last_milestone_index.write().unwrap() = None;
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    async fn subscribe_stream(client: &Client, topics: Vec<Topic>) -> TopicEventStream {
+        let (sender, receiver) = tokio::sync::mpsc::channel(1);
+        let mut mqtt_topic_handlers = client.mqtt_topic_handlers.write().await;
+        for topic in &topics {
+            mqtt_topic_handlers
+                .entry(topic.clone())
+                .or_default()
+                .push(TopicHandler::Channel(sender.clone(), Arc::default()));
+        }
+        drop(mqtt_topic_handlers);
+
+        TopicEventStream::new(client, topics, sender, receiver, Arc::default())
+    }
+
+    /// Waits for the handlers to be removed in the background and returns the number of handlers per topic.
+    async fn handler_counts(client: &Client, expected_topics: usize) -> Vec<(String, usize)> {
+        for _ in 0..100 {
+            if client.mqtt_topic_handlers.read().await.len() == expected_topics {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let mut counts = client
+            .mqtt_topic_handlers
+            .read()
+            .await
+            .iter()
+            .map(|(topic, handlers)| (topic.topic().to_string(), handlers.len()))
+            .collect::<Vec<_>>();
+        counts.sort();
+        counts
+    }
+
+    #[tokio::test]
+    async fn handlers_removed_on_drop() {
+        let client = Client::builder().finish().unwrap();
+        let milestones = Topic::try_new("milestones").unwrap();
+        let receipts = Topic::try_new("receipts").unwrap();
+
+        let first_stream = subscribe_stream(&client, vec![milestones.clone(), receipts]).await;
+        let second_stream = subscribe_stream(&client, vec![milestones]).await;
+        assert_eq!(
+            handler_counts(&client, 2).await,
+            vec![("milestones".to_string(), 2), ("receipts".to_string(), 1)]
+        );
+
+        // Only the handlers of the dropped stream are removed, topics still used by other streams are kept.
+        drop(first_stream);
+        assert_eq!(handler_counts(&client, 1).await, vec![("milestones".to_string(), 1)]);
+
+        drop(second_stream);
+        assert_eq!(handler_counts(&client, 0).await, Vec::new());
+    }
+}
@@ -3,11 +3,19 @@
 
 //! MQTT types
 
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use regex::RegexSet;
 use serde::{de::Error as _, Deserialize, Deserializer};
 use serde_json::Value;
+use tokio::sync::mpsc::{error::TrySendError, Sender};
 
 use super::Error;
 use crate::types::block::{
@@ -15,9 +23,37 @@ use crate::types::block::{
     Block,
 };
 
-type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
+type TopicCallback = Box<dyn Fn(&TopicEvent) + Send + Sync>;
+
+/// A handler of the events received on a MQTT topic.
+#[derive(Clone)]
+pub(crate) enum TopicHandler {
+    /// A callback called for every event.
+    Callback(Arc<TopicCallback>),
+    /// A bounded channel feeding a [`TopicEventStream`](super::TopicEventStream), with the count of the events dropped
+    /// because it was full.
+    Channel(Sender<TopicEvent>, Arc<AtomicU64>),
+}
+
+impl TopicHandler {
+    /// Passes the event to the handler without waiting, an event for a full channel is dropped and counted so a stream
+    /// that isn't polled doesn't stall the other subscribers and the MQTT connection.
+    pub(crate) fn handle(&self, event: &TopicEvent) {
+        match self {
+            Self::Callback(callback) => callback(event),
+            Self::Channel(sender, lagged) => match sender.try_send(event.clone()) {
+                Ok(()) => {}
+                Err(TrySendError::Full(_)) => {
+                    lagged.fetch_add(1, Ordering::Relaxed);
+                }
+                // The receiver may have been dropped before the handler got removed, the event can then be ignored.
+                Err(TrySendError::Closed(_)) => {}
+            },
+        }
+    }
+}
 
-pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<Arc<TopicHandler>>>;
+pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<TopicHandler>>;
 
 /// An event from a MQTT topic.
 
@@ -68,6 +104,8 @@ pub struct BrokerOptions {
     pub(crate) port: u16,
     #[serde(default = "default_max_reconnection_attempts", rename = "maxReconnectionAttempts")]
     pub(crate) max_reconnection_attempts: usize,
+    #[serde(default = "default_replay_missed_milestones", rename = "replayMissedMilestones")]
+    pub(crate) replay_missed_milestones: bool,
 }
 
 fn default_broker_automatic_disconnect() -> bool {
@@ -90,6 +128,10 @@ fn default_max_reconnection_attempts() -> usize {
     0
 }
 
+fn default_replay_missed_milestones() -> bool {
+    true
+}
+
 impl Default for BrokerOptions {
     fn default() -> Self {
         Self {
@@ -98,6 +140,7 @@ impl Default for BrokerOptions {
             use_ws: default_broker_use_ws(),
             port: default_broker_port(),
             max_reconnection_attempts: default_max_reconnection_attempts(),
+            replay_missed_milestones: default_replay_missed_milestones(),
         }
     }
 }
@@ -137,6 +180,14 @@ impl BrokerOptions {
         self.max_reconnection_attempts = max_reconnection_attempts;
         self
     }
+
+    /// Whether milestones that were missed while the connection was down should be fetched from the node and
+    /// dispatched to the subscribed handlers after a reconnection, together with the outputs they created and consumed.
+    /// Only the `milestones` and `outputs/...` topics are replayed.
+    pub fn replay_missed_milestones(mut self, replay_missed_milestones: bool) -> Self {
+        self.replay_missed_milestones = replay_missed_milestones;
+        self
+    }
 }
 
 /// A MQTT topic.
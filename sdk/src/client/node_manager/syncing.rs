@@ -3,7 +3,7 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
-    crate::client::NetworkInfo,
+    crate::client::{NetworkInfo, ProtocolParametersChange},
     crate::types::{api::core::response::InfoResponse, block::protocol::ProtocolParameters},
     std::collections::HashMap,
     std::{
@@ -11,7 +11,7 @@ use {
         sync::{Arc, RwLock},
         time::Duration,
     },
-    tokio::{runtime::Runtime, time::sleep},
+    tokio::{runtime::Runtime, sync::broadcast::Sender, time::sleep},
 };
 
 use super::Node;
@@ -52,6 +52,7 @@ impl Client {
         nodes: HashSet<Node>,
         node_sync_interval: Duration,
         network_info: Arc<RwLock<NetworkInfo>>,
+        protocol_parameters_change_sender: Sender<ProtocolParametersChange>,
         ignore_node_health: bool,
     ) -> tokio::task::JoinHandle<()> {
         runtime.spawn(async move {
@@ -59,7 +60,15 @@ impl Client {
                 // Delay first since the first `sync_nodes` call is made by the builder to ensure the node list is
                 // filled before the client is used.
                 sleep(node_sync_interval).await;
-                if let Err(e) = Self::sync_nodes(&sync, &nodes, &network_info, ignore_node_health).await {
+                if let Err(e) = Self::sync_nodes(
+                    &sync,
+                    &nodes,
+                    &network_info,
+                    &protocol_parameters_change_sender,
+                    ignore_node_health,
+                )
+                .await
+                {
                     log::warn!("Syncing nodes failed: {e}");
                 }
             }
@@ -71,6 +80,7 @@ impl Client {
         sync: &Arc<RwLock<HashMap<Node, InfoResponse>>>,
         nodes: &HashSet<Node>,
         network_info: &Arc<RwLock<NetworkInfo>>,
+        protocol_parameters_change_sender: &Sender<ProtocolParametersChange>,
         ignore_node_health: bool,
     ) -> Result<()> {
         log::debug!("sync_nodes");
@@ -111,10 +121,18 @@ impl Client {
 
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
             if let Some((info, _node_url)) = nodes.first() {
-                let mut network_info = network_info.write().map_err(|_| crate::client::Error::PoisonError)?;
+                let protocol_parameters_change = {
+                    let mut network_info = network_info.write().map_err(|_| crate::client::Error::PoisonError)?;
 
-                network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
-                network_info.protocol_parameters = ProtocolParameters::try_from(info.protocol.clone())?;
+                    network_info.latest_milestone_timestamp = info.status.latest_milestone.timestamp;
+                    network_info.update_protocol_parameters(ProtocolParameters::try_from(info.protocol.clone())?)
+                };
+
+                if let Some(protocol_parameters_change) = protocol_parameters_change {
+                    log::info!("Protocol parameters changed: {protocol_parameters_change:?}");
+                    // Sending only fails if there are no receivers.
+                    protocol_parameters_change_sender.send(protocol_parameters_change).ok();
+                }
             }
 
             for (info, node_url) in nodes {
@@ -82,6 +82,20 @@ impl MnemonicSecretManager {
         Ok(Self(Client::mnemonic_to_seed(mnemonic)?))
     }
 
+    /// Create a new [`MnemonicSecretManager`] from a BIP-39 mnemonic in English and a BIP-39 passphrase, also known
+    /// as the 25th word.
+    pub fn try_from_mnemonic_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Self> {
+        Ok(Self(Client::mnemonic_to_seed_with_passphrase(mnemonic, passphrase)?))
+    }
+
+    /// Create a new [`MnemonicSecretManager`] from a threshold of SLIP-39 mnemonic shares and their passphrase, the
+    /// recovered master secret is used as seed.
+    ///
+    /// For more information, see <https://github.com/satoshilabs/slips/blob/master/slip-0039.md>.
+    pub fn try_from_slip39_shares<S: AsRef<str>>(shares: &[S], passphrase: &str) -> Result<Self> {
+        Ok(Self(Client::slip39_shares_to_seed(shares, passphrase)?))
+    }
+
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
     pub fn try_from_hex_seed(hex: &str) -> Result<Self> {
         let bytes: Vec<u8> = prefix_hex::decode(hex)?;
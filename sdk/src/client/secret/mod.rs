@@ -157,6 +157,14 @@ pub enum SecretManagerDto {
     /// Mnemonic
     #[serde(alias = "mnemonic")]
     Mnemonic(String),
+    /// Mnemonic with a BIP-39 passphrase
+    #[serde(alias = "mnemonicWithPassphrase")]
+    MnemonicWithPassphrase {
+        /// Mnemonic
+        mnemonic: String,
+        /// BIP-39 passphrase
+        passphrase: String,
+    },
     /// Hex seed
     #[serde(alias = "hexSeed")]
     HexSeed(String),
@@ -189,6 +197,10 @@ impl TryFrom<&SecretManagerDto> for SecretManager {
 
             SecretManagerDto::Mnemonic(mnemonic) => Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?),
 
+            SecretManagerDto::MnemonicWithPassphrase { mnemonic, passphrase } => Self::Mnemonic(
+                MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase)?,
+            ),
+
             SecretManagerDto::HexSeed(hex_seed) => Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?),
 
             SecretManagerDto::Placeholder => Self::Placeholder(PlaceholderSecretManager),
@@ -305,6 +317,13 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_mnemonic(mnemonic)?))
     }
 
+    /// Tries to create a [`SecretManager`] from a mnemonic string and a BIP-39 passphrase.
+    pub fn try_from_mnemonic_with_passphrase(mnemonic: &str, passphrase: &str) -> crate::client::Result<Self> {
+        Ok(Self::Mnemonic(
+            MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, passphrase)?,
+        ))
+    }
+
     /// Tries to create a [`SecretManager`] from a seed hex string.
     pub fn try_from_hex_seed(seed: &str) -> crate::client::Result<Self> {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
@@ -0,0 +1,87 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Encryption of the master secret with a passphrase, a four round Feistel network with PBKDF2-HMAC-SHA256 as round
+//! function.
+
+use crypto::keys::pbkdf::PBKDF2_HMAC_SHA256;
+use zeroize::Zeroizing;
+
+use crate::client::Result;
+
+/// The total number of PBKDF2 iterations of the four rounds, for an iteration exponent of 0.
+const BASE_ITERATION_COUNT: usize = 10000;
+const ROUND_COUNT: u8 = 4;
+/// The prefix of the salt of shares which can't be extended with new shares of the same master secret.
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+
+pub(super) fn encrypt(
+    master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let (left, right) = master_secret.split_at(master_secret.len() / 2);
+    feistel(
+        left,
+        right,
+        0..ROUND_COUNT,
+        passphrase,
+        iteration_exponent,
+        &salt(identifier, extendable),
+    )
+}
+
+pub(super) fn decrypt(
+    encrypted_master_secret: &[u8],
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+) -> Result<Zeroizing<Vec<u8>>> {
+    let (left, right) = encrypted_master_secret.split_at(encrypted_master_secret.len() / 2);
+    feistel(
+        left,
+        right,
+        (0..ROUND_COUNT).rev(),
+        passphrase,
+        iteration_exponent,
+        &salt(identifier, extendable),
+    )
+}
+
+fn salt(identifier: u16, extendable: bool) -> Vec<u8> {
+    if extendable {
+        Vec::new()
+    } else {
+        [CUSTOMIZATION_STRING, &identifier.to_be_bytes()].concat()
+    }
+}
+
+fn feistel(
+    left: &[u8],
+    right: &[u8],
+    rounds: impl Iterator<Item = u8>,
+    passphrase: &[u8],
+    iteration_exponent: u8,
+    salt: &[u8],
+) -> Result<Zeroizing<Vec<u8>>> {
+    let mut left = Zeroizing::new(left.to_vec());
+    let mut right = Zeroizing::new(right.to_vec());
+    let iterations = (BASE_ITERATION_COUNT << iteration_exponent) / ROUND_COUNT as usize;
+
+    for round in rounds {
+        let password = Zeroizing::new([&[round], passphrase].concat());
+        let mut round_output = Zeroizing::new(vec![0u8; right.len()]);
+        PBKDF2_HMAC_SHA256(&password, &[salt, &right].concat(), iterations, &mut round_output)?;
+
+        for (byte, round_byte) in left.iter_mut().zip(round_output.iter()) {
+            *byte ^= round_byte;
+        }
+        std::mem::swap(&mut left, &mut right);
+    }
+
+    right.extend_from_slice(&left);
+    Ok(right)
+}
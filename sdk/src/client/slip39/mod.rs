@@ -0,0 +1,386 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shamir secret sharing of a master secret with mnemonic shares, as specified by
+//! [SLIP-39](https://github.com/satoshilabs/slips/blob/master/slip-0039.md).
+//!
+//! The master secret is encrypted with a passphrase and split into groups, any `group_threshold` of which are required
+//! to recover it. Each group is split again into member shares, any `member_threshold` of which recover the group.
+
+mod cipher;
+mod shamir;
+mod wordlist;
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use self::shamir::{recover_secret, split_secret, MAX_SHARE_COUNT};
+use crate::client::{Error, Result};
+
+/// The number of bits encoded by a word.
+const RADIX_BITS: usize = 10;
+/// The number of words of the identifier, the parameters and the indexes of a share.
+const HEADER_WORDS: usize = 4;
+/// The number of words of the checksum of a share.
+const CHECKSUM_WORDS: usize = 3;
+/// The minimum length of the master secret in bytes.
+const MIN_MASTER_SECRET_LENGTH: usize = 16;
+/// The iteration exponent of new shares, the encryption uses 10000 * 2^exponent PBKDF2 iterations.
+const DEFAULT_ITERATION_EXPONENT: u8 = 1;
+/// The checksum customization string of shares which can't be extended with new shares of the same master secret.
+const CUSTOMIZATION_STRING: &[u8] = b"shamir";
+/// The checksum customization string of extendable shares.
+const CUSTOMIZATION_STRING_EXTENDABLE: &[u8] = b"shamir_extendable";
+
+/// The member shares of a group of a SLIP-39 backup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Slip39Group {
+    /// The number of member shares required to recover the group.
+    pub member_threshold: u8,
+    /// The number of member shares of the group.
+    pub member_count: u8,
+}
+
+impl Slip39Group {
+    /// Creates a group of `member_count` shares, any `member_threshold` of which recover the group.
+    pub fn new(member_threshold: u8, member_count: u8) -> Self {
+        Self {
+            member_threshold,
+            member_count,
+        }
+    }
+}
+
+/// Splits a master secret into SLIP-39 mnemonic shares, returned by group.
+///
+/// The master secret is encrypted with the `passphrase`, which must consist of printable ASCII characters and is
+/// required to recover the same master secret.
+pub fn split_master_secret(
+    master_secret: &[u8],
+    passphrase: &str,
+    group_threshold: u8,
+    groups: &[Slip39Group],
+) -> Result<Vec<Vec<String>>> {
+    if master_secret.len() < MIN_MASTER_SECRET_LENGTH || !master_secret.len().is_multiple_of(2) {
+        return Err(Error::Slip39(format!(
+            "the master secret must be at least {MIN_MASTER_SECRET_LENGTH} bytes long and have an even length"
+        )));
+    }
+    verify_passphrase(passphrase)?;
+    if groups.len() > MAX_SHARE_COUNT as usize || group_threshold == 0 || group_threshold as usize > groups.len() {
+        return Err(Error::Slip39(format!(
+            "invalid group threshold {group_threshold} for {} groups",
+            groups.len()
+        )));
+    }
+    for group in groups {
+        if group.member_threshold == 1 && group.member_count > 1 {
+            return Err(Error::Slip39(
+                "a group with a member threshold of 1 can only have one member share".to_string(),
+            ));
+        }
+    }
+
+    let mut identifier = [0u8; 2];
+    crypto::utils::rand::fill(&mut identifier)?;
+    let identifier = u16::from_be_bytes(identifier) & 0x7FFF;
+
+    let encrypted_master_secret = cipher::encrypt(
+        master_secret,
+        passphrase.as_bytes(),
+        DEFAULT_ITERATION_EXPONENT,
+        identifier,
+        false,
+    )?;
+    let group_shares = split_secret(group_threshold, groups.len() as u8, &encrypted_master_secret)?;
+
+    groups
+        .iter()
+        .zip(group_shares)
+        .map(|(group, group_share)| {
+            Ok(
+                split_secret(group.member_threshold, group.member_count, &group_share.data)?
+                    .into_iter()
+                    .map(|member_share| {
+                        Share {
+                            identifier,
+                            extendable: false,
+                            iteration_exponent: DEFAULT_ITERATION_EXPONENT,
+                            group_index: group_share.x,
+                            group_threshold,
+                            group_count: groups.len() as u8,
+                            member_index: member_share.x,
+                            member_threshold: group.member_threshold,
+                            value: member_share.data,
+                        }
+                        .to_mnemonic()
+                    })
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Recovers the master secret from SLIP-39 mnemonic shares, which must contain the member threshold of shares of at
+/// least the group threshold of groups.
+pub fn combine_shares<S: AsRef<str>>(mnemonics: &[S], passphrase: &str) -> Result<Zeroizing<Vec<u8>>> {
+    verify_passphrase(passphrase)?;
+
+    let shares = mnemonics
+        .iter()
+        .map(|mnemonic| Share::from_mnemonic(mnemonic.as_ref()))
+        .collect::<Result<Vec<_>>>()?;
+    let first_share = shares
+        .first()
+        .ok_or_else(|| Error::Slip39("no shares provided".to_string()))?;
+
+    let mut groups: BTreeMap<u8, BTreeMap<u8, &Share>> = BTreeMap::new();
+    for share in &shares {
+        if share.identifier != first_share.identifier
+            || share.extendable != first_share.extendable
+            || share.iteration_exponent != first_share.iteration_exponent
+            || share.group_threshold != first_share.group_threshold
+            || share.group_count != first_share.group_count
+            || share.value.len() != first_share.value.len()
+        {
+            return Err(Error::Slip39(
+                "the shares don't belong to the same master secret".to_string(),
+            ));
+        }
+
+        let group = groups.entry(share.group_index).or_default();
+        if group
+            .values()
+            .any(|member| member.member_threshold != share.member_threshold)
+        {
+            return Err(Error::Slip39(format!(
+                "the shares of group {} have different member thresholds",
+                share.group_index
+            )));
+        }
+        if let Some(member) = group.insert(share.member_index, share) {
+            if member.value != share.value {
+                return Err(Error::Slip39(format!(
+                    "different shares with the same index {} in group {}",
+                    share.member_index, share.group_index
+                )));
+            }
+        }
+    }
+
+    let group_shares = groups
+        .iter()
+        .filter(|(_, members)| members.len() >= members.values().next().map_or(0, |m| m.member_threshold as usize))
+        .map(|(group_index, members)| {
+            let member_threshold = members.values().next().map_or(0, |m| m.member_threshold);
+            let member_shares = members
+                .values()
+                .map(|member| (member.member_index, member.value.as_slice()))
+                .collect::<Vec<_>>();
+            Ok((*group_index, recover_secret(member_threshold, &member_shares)?))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if group_shares.len() < first_share.group_threshold as usize {
+        return Err(Error::Slip39(format!(
+            "{} complete groups are required, but only {} were provided",
+            first_share.group_threshold,
+            group_shares.len()
+        )));
+    }
+
+    let group_shares = group_shares
+        .iter()
+        .map(|(group_index, group_share)| (*group_index, group_share.as_slice()))
+        .collect::<Vec<_>>();
+    let encrypted_master_secret = recover_secret(first_share.group_threshold, &group_shares)?;
+
+    cipher::decrypt(
+        &encrypted_master_secret,
+        passphrase.as_bytes(),
+        first_share.iteration_exponent,
+        first_share.identifier,
+        first_share.extendable,
+    )
+}
+
+fn verify_passphrase(passphrase: &str) -> Result<()> {
+    if passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        Ok(())
+    } else {
+        Err(Error::Slip39(
+            "the passphrase must only contain printable ASCII characters".to_string(),
+        ))
+    }
+}
+
+/// A decoded SLIP-39 mnemonic share.
+struct Share {
+    /// Random identifier of the master secret, 15 bits.
+    identifier: u16,
+    extendable: bool,
+    /// 4 bits.
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    fn to_mnemonic(&self) -> String {
+        let header = (self.identifier as u64) << 25
+            | (self.extendable as u64) << 24
+            | (self.iteration_exponent as u64) << 20
+            | (self.group_index as u64) << 16
+            | ((self.group_threshold - 1) as u64) << 12
+            | ((self.group_count - 1) as u64) << 8
+            | (self.member_index as u64) << 4
+            | (self.member_threshold - 1) as u64;
+
+        let mut words = (0..HEADER_WORDS)
+            .rev()
+            .map(|i| ((header >> (i * RADIX_BITS)) & 0x3FF) as u16)
+            .collect::<Vec<_>>();
+
+        // The value is left padded with zero bits to a multiple of the word size.
+        let value_words = (self.value.len() * 8).div_ceil(RADIX_BITS);
+        let mut accumulator = 0u32;
+        let mut bits = value_words * RADIX_BITS - self.value.len() * 8;
+        for byte in self.value.iter() {
+            accumulator = accumulator << 8 | *byte as u32;
+            bits += 8;
+            while bits >= RADIX_BITS {
+                bits -= RADIX_BITS;
+                words.push((accumulator >> bits) as u16 & 0x3FF);
+                accumulator &= (1 << bits) - 1;
+            }
+        }
+
+        let checksum = rs1024_checksum(customization_string(self.extendable), &words);
+        words.extend(checksum);
+
+        words
+            .into_iter()
+            .map(|word| wordlist::WORDLIST[word as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn from_mnemonic(mnemonic: &str) -> Result<Self> {
+        let words = mnemonic
+            .split_whitespace()
+            .map(|word| {
+                wordlist::word_index(&word.to_lowercase()).ok_or_else(|| Error::Slip39(format!("invalid word {word}")))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let value_words = words.len().saturating_sub(HEADER_WORDS + CHECKSUM_WORDS);
+        let padding_bits = value_words * RADIX_BITS % 16;
+        if value_words * RADIX_BITS < MIN_MASTER_SECRET_LENGTH * 8 || padding_bits > 8 {
+            return Err(Error::Slip39(format!("invalid share length of {} words", words.len())));
+        }
+
+        let header = words[..HEADER_WORDS]
+            .iter()
+            .fold(0u64, |header, word| header << RADIX_BITS | *word as u64);
+        let extendable = (header >> 24) & 1 == 1;
+        if !rs1024_verify(customization_string(extendable), &words) {
+            return Err(Error::Slip39("invalid share checksum".to_string()));
+        }
+
+        let mut value = Zeroizing::new(Vec::with_capacity((value_words * RADIX_BITS - padding_bits) / 8));
+        let mut accumulator = 0u32;
+        let mut bits = 0;
+        for (i, word) in words[HEADER_WORDS..words.len() - CHECKSUM_WORDS].iter().enumerate() {
+            accumulator = accumulator << RADIX_BITS | *word as u32;
+            bits += RADIX_BITS;
+            if i == 0 {
+                if word >> (RADIX_BITS - padding_bits) != 0 {
+                    return Err(Error::Slip39("invalid share padding".to_string()));
+                }
+                bits -= padding_bits;
+                accumulator &= (1 << bits) - 1;
+            }
+            while bits >= 8 {
+                bits -= 8;
+                value.push((accumulator >> bits) as u8);
+                accumulator &= (1 << bits) - 1;
+            }
+        }
+
+        let share = Self {
+            identifier: (header >> 25) as u16,
+            extendable,
+            iteration_exponent: ((header >> 20) & 0xF) as u8,
+            group_index: ((header >> 16) & 0xF) as u8,
+            group_threshold: ((header >> 12) & 0xF) as u8 + 1,
+            group_count: ((header >> 8) & 0xF) as u8 + 1,
+            member_index: ((header >> 4) & 0xF) as u8,
+            member_threshold: (header & 0xF) as u8 + 1,
+            value,
+        };
+        if share.group_threshold > share.group_count {
+            return Err(Error::Slip39("the group threshold exceeds the group count".to_string()));
+        }
+
+        Ok(share)
+    }
+}
+
+fn customization_string(extendable: bool) -> &'static [u8] {
+    if extendable {
+        CUSTOMIZATION_STRING_EXTENDABLE
+    } else {
+        CUSTOMIZATION_STRING
+    }
+}
+
+fn rs1024_polymod(customization_string: &[u8], words: impl IntoIterator<Item = u16>) -> u32 {
+    const GENERATOR: [u32; 10] = [
+        0x00E0_E040,
+        0x01C1_C080,
+        0x0383_8100,
+        0x0707_0200,
+        0x0E0E_0009,
+        0x1C0C_2412,
+        0x3808_6C24,
+        0x3090_FC48,
+        0x21B1_F890,
+        0x03F3_F120,
+    ];
+
+    let mut checksum = 1u32;
+    for value in customization_string
+        .iter()
+        .map(|byte| *byte as u32)
+        .chain(words.into_iter().map(u32::from))
+    {
+        let top = checksum >> 20;
+        checksum = (checksum & 0xF_FFFF) << 10 ^ value;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                checksum ^= generator;
+            }
+        }
+    }
+    checksum
+}
+
+fn rs1024_checksum(customization_string: &[u8], words: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let checksum = rs1024_polymod(customization_string, words.iter().copied().chain([0; CHECKSUM_WORDS])) ^ 1;
+    [
+        (checksum >> 20) as u16 & 0x3FF,
+        (checksum >> 10) as u16 & 0x3FF,
+        checksum as u16 & 0x3FF,
+    ]
+}
+
+fn rs1024_verify(customization_string: &[u8], words: &[u16]) -> bool {
+    rs1024_polymod(customization_string, words.iter().copied()) == 1
+}
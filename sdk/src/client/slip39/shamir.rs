@@ -0,0 +1,157 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Shamir secret sharing over GF(256) with a digest share to detect invalid recoveries.
+
+use crypto::macs::hmac::HMAC_SHA256;
+use zeroize::Zeroizing;
+
+use crate::client::{Error, Result};
+
+/// The maximum number of shares a secret can be split into.
+pub(super) const MAX_SHARE_COUNT: u8 = 16;
+/// The x coordinate of the shared secret.
+const SECRET_INDEX: u8 = 255;
+/// The x coordinate of the digest share.
+const DIGEST_INDEX: u8 = 254;
+/// The length of the digest of the shared secret.
+const DIGEST_LENGTH: usize = 4;
+
+/// Exponentiation and logarithm tables of GF(256) with the Rijndael polynomial and the generator 3.
+const TABLES: ([u8; 255], [u8; 256]) = gf256_tables();
+
+const fn gf256_tables() -> ([u8; 255], [u8; 256]) {
+    let mut exp = [0u8; 255];
+    let mut log = [0u8; 256];
+    let mut poly: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = poly as u8;
+        log[poly as usize] = i as u8;
+        // Multiply by the generator 3.
+        poly = (poly << 1) ^ poly;
+        if poly & 0x100 != 0 {
+            poly ^= 0x11B;
+        }
+        i += 1;
+    }
+    (exp, log)
+}
+
+/// A share of a secret, the value of the polynomial at `x`.
+pub(super) struct RawShare {
+    pub(super) x: u8,
+    pub(super) data: Zeroizing<Vec<u8>>,
+}
+
+/// Splits `secret` into `share_count` shares with x coordinates from 0, any `threshold` of which recover the secret.
+pub(super) fn split_secret(threshold: u8, share_count: u8, secret: &[u8]) -> Result<Vec<RawShare>> {
+    if threshold == 0 || threshold > share_count || share_count > MAX_SHARE_COUNT {
+        return Err(Error::Slip39(format!(
+            "invalid threshold {threshold} for {share_count} shares, at most {MAX_SHARE_COUNT} shares are supported"
+        )));
+    }
+    if secret.len() <= DIGEST_LENGTH {
+        return Err(Error::Slip39("the secret is too short".to_string()));
+    }
+
+    if threshold == 1 {
+        return Ok((0..share_count)
+            .map(|x| RawShare {
+                x,
+                data: Zeroizing::new(secret.to_vec()),
+            })
+            .collect());
+    }
+
+    let random_share_count = threshold - 2;
+    let mut shares = Vec::with_capacity(share_count as usize);
+    for x in 0..random_share_count {
+        let mut data = Zeroizing::new(vec![0u8; secret.len()]);
+        crypto::utils::rand::fill(&mut data)?;
+        shares.push(RawShare { x, data });
+    }
+
+    let mut digest_share = Zeroizing::new(vec![0u8; secret.len()]);
+    crypto::utils::rand::fill(&mut digest_share[DIGEST_LENGTH..])?;
+    let digest = create_digest(&digest_share[DIGEST_LENGTH..], secret);
+    digest_share[..DIGEST_LENGTH].copy_from_slice(&digest);
+
+    let mut base_shares: Vec<(u8, &[u8])> = shares.iter().map(|share| (share.x, share.data.as_slice())).collect();
+    base_shares.push((DIGEST_INDEX, &digest_share));
+    base_shares.push((SECRET_INDEX, secret));
+
+    let interpolated_shares = (random_share_count..share_count)
+        .map(|x| RawShare {
+            x,
+            data: interpolate(&base_shares, x),
+        })
+        .collect::<Vec<_>>();
+    shares.extend(interpolated_shares);
+
+    Ok(shares)
+}
+
+/// Recovers the secret from `threshold` shares and verifies its digest.
+pub(super) fn recover_secret(threshold: u8, shares: &[(u8, &[u8])]) -> Result<Zeroizing<Vec<u8>>> {
+    if shares.len() < threshold as usize {
+        return Err(Error::Slip39(format!(
+            "{threshold} shares are required, but only {} were provided",
+            shares.len()
+        )));
+    }
+    let shares = &shares[..threshold as usize];
+
+    if threshold == 1 {
+        return Ok(Zeroizing::new(shares[0].1.to_vec()));
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX);
+    let digest_share = interpolate(shares, DIGEST_INDEX);
+    if digest_share[..DIGEST_LENGTH] != create_digest(&digest_share[DIGEST_LENGTH..], &secret) {
+        return Err(Error::Slip39("invalid digest of the shared secret".to_string()));
+    }
+
+    Ok(secret)
+}
+
+fn create_digest(random_data: &[u8], secret: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut mac = [0u8; 32];
+    HMAC_SHA256(secret, random_data, &mut mac);
+    let mut digest = [0u8; DIGEST_LENGTH];
+    digest.copy_from_slice(&mac[..DIGEST_LENGTH]);
+    digest
+}
+
+/// Evaluates at `x` the polynomial going through the shares, with Lagrange interpolation. The x coordinates of the
+/// shares must be distinct.
+fn interpolate(shares: &[(u8, &[u8])], x: u8) -> Zeroizing<Vec<u8>> {
+    if let Some((_, data)) = shares.iter().find(|(share_x, _)| *share_x == x) {
+        return Zeroizing::new(data.to_vec());
+    }
+
+    let (exp, log) = &TABLES;
+    let log_product: i32 = shares
+        .iter()
+        .map(|(share_x, _)| log[(share_x ^ x) as usize] as i32)
+        .sum();
+
+    let mut result = Zeroizing::new(vec![0u8; shares[0].1.len()]);
+    for (share_x, data) in shares {
+        let log_basis = (log_product
+            - log[(share_x ^ x) as usize] as i32
+            - shares
+                .iter()
+                .map(|(other_x, _)| log[(share_x ^ other_x) as usize] as i32)
+                .sum::<i32>())
+        .rem_euclid(255);
+
+        for (byte, value) in result.iter_mut().zip(data.iter()) {
+            if *value != 0 {
+                *byte ^= exp[((log[*value as usize] as i32 + log_basis) % 255) as usize];
+            }
+        }
+    }
+
+    result
+}
@@ -49,6 +49,7 @@
 mod common;
 mod secret;
 mod storage;
+mod verification;
 
 use std::{
     path::{Path, PathBuf},
@@ -63,6 +64,8 @@ use tokio::{sync::Mutex, task::JoinHandle};
 use zeroize::Zeroizing;
 
 use self::common::PRIVATE_DATA_CLIENT_PATH;
+pub(crate) use self::verification::{verify_snapshot_header, SNAPSHOT_VERSION};
+pub use self::verification::{SnapshotContent, SnapshotStatus};
 use crate::client::{storage::StorageProvider, Error, Result};
 
 /// A wrapper on [Stronghold].
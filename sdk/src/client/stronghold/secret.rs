@@ -188,7 +188,17 @@ impl StrongholdAdapter {
     }
 
     /// Store a mnemonic into the Stronghold vault.
-    pub async fn store_mnemonic(&mut self, mut mnemonic: String) -> Result<()> {
+    pub async fn store_mnemonic(&mut self, mnemonic: String) -> Result<()> {
+        self.store_bip39_seed(mnemonic, None).await
+    }
+
+    /// Store a mnemonic and its BIP-39 passphrase, also known as the 25th word, into the Stronghold vault. Only the
+    /// seed derived from both is stored.
+    pub async fn store_mnemonic_with_passphrase(&mut self, mnemonic: String, passphrase: String) -> Result<()> {
+        self.store_bip39_seed(mnemonic, Some(passphrase)).await
+    }
+
+    async fn store_bip39_seed(&mut self, mut mnemonic: String, passphrase: Option<String>) -> Result<()> {
         // The key needs to be supplied first.
         if self.key_provider.lock().await.is_none() {
             return Err(Error::StrongholdKeyCleared);
@@ -217,7 +227,40 @@ impl StrongholdAdapter {
         }
 
         // Execute the BIP-39 recovery procedure to put it into the vault (in memory).
-        self.bip39_recover(trimmed_mnemonic, None, output).await?;
+        self.bip39_recover(trimmed_mnemonic, passphrase, output).await?;
+
+        // Persist Stronghold to the disk
+        self.write_stronghold_snapshot(None).await?;
+
+        Ok(())
+    }
+
+    /// Store the master secret recovered from a threshold of SLIP-39 mnemonic shares into the Stronghold vault, as
+    /// seed.
+    pub async fn store_slip39_shares(&mut self, mut shares: Vec<String>, mut passphrase: String) -> Result<()> {
+        // The key needs to be supplied first.
+        if self.key_provider.lock().await.is_none() {
+            return Err(Error::StrongholdKeyCleared);
+        };
+
+        // Stronghold arguments.
+        let output = Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH);
+
+        let master_secret = crate::client::slip39::combine_shares(&shares, &passphrase);
+        shares.zeroize();
+        passphrase.zeroize();
+        let master_secret = master_secret?;
+
+        // We need to check if there has been a mnemonic stored in Stronghold or not to prevent overwriting it.
+        let stronghold = self.stronghold.lock().await;
+        let client = stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+        if client.record_exists(&output)? {
+            return Err(crate::client::Error::StrongholdMnemonicAlreadyStored);
+        }
+
+        // Put the master secret into the vault (in memory), where it's used like a seed derived from a mnemonic.
+        client.vault(SECRET_VAULT_PATH).write_secret(output, master_secret.to_vec())?;
+        drop(stronghold);
 
         // Persist Stronghold to the disk
         self.write_stronghold_snapshot(None).await?;
@@ -0,0 +1,248 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification and repair of Stronghold snapshots.
+
+use std::{
+    fmt::{Display, Formatter},
+    fs::File,
+    io::Read,
+    ops::Deref,
+    path::Path,
+};
+
+use crypto::ciphers::chacha;
+use iota_stronghold::{Client, ClientError, KeyProvider, Location, SnapshotPath, Stronghold};
+
+use super::{
+    common::{key_provider_from_password, PRIVATE_DATA_CLIENT_PATH, SECRET_VAULT_PATH, SEED_RECORD_PATH},
+    StrongholdAdapter,
+};
+use crate::client::{Error, Result};
+
+/// Magic bytes at the start of every Stronghold snapshot file.
+const SNAPSHOT_MAGIC: [u8; 5] = *b"PARTI";
+/// The snapshot format version written and read by the Stronghold version in use.
+pub(crate) const SNAPSHOT_VERSION: [u8; 2] = [2, 0];
+/// Magic bytes, version bytes, ephemeral public key and authentication tag.
+const SNAPSHOT_MIN_LENGTH: u64 = 5 + 2 + 32 + 16;
+
+/// The status of a Stronghold snapshot, as found by [`StrongholdAdapter::verify_snapshot()`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotStatus {
+    /// The snapshot file doesn't exist.
+    Missing,
+    /// The file isn't a Stronghold snapshot or its content is corrupted.
+    Corrupted(String),
+    /// The snapshot has been written with another snapshot format version.
+    UnsupportedVersion {
+        /// The supported snapshot format version.
+        expected: [u8; 2],
+        /// The snapshot format version of the file.
+        found: [u8; 2],
+    },
+    /// The snapshot can't be decrypted with the password. Since the encrypted content is authenticated as a whole, a
+    /// snapshot with corrupted encrypted bytes can't be told apart from a wrong password.
+    InvalidPassword,
+    /// The snapshot can be read, with the given content.
+    Valid(SnapshotContent),
+}
+
+impl Display for SnapshotStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing => write!(f, "the snapshot file doesn't exist"),
+            Self::Corrupted(reason) => write!(f, "the snapshot is corrupted: {reason}"),
+            Self::UnsupportedVersion { expected, found } => write!(
+                f,
+                "unsupported snapshot version {found:?}, only version {expected:?} is supported"
+            ),
+            Self::InvalidPassword => write!(f, "the snapshot can't be decrypted with this password"),
+            Self::Valid(content) => write!(
+                f,
+                "valid snapshot, seed: {}, wallet data: {}",
+                content.has_seed(),
+                content.has_wallet_data()
+            ),
+        }
+    }
+}
+
+/// The records found in a readable Stronghold snapshot.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SnapshotContent {
+    /// Whether the seed derived from a mnemonic is stored in the secret vault.
+    pub seed: bool,
+    /// Keys of the readable entries of the store, like the wallet data of a backup.
+    pub store_keys: Vec<String>,
+    /// Keys of the entries of the store which can't be decrypted.
+    pub corrupted_store_keys: Vec<String>,
+}
+
+impl SnapshotContent {
+    /// Whether the seed derived from a mnemonic is stored in the secret vault.
+    pub fn has_seed(&self) -> bool {
+        self.seed
+    }
+
+    /// Whether readable data, like the wallet data of a backup, is stored.
+    pub fn has_wallet_data(&self) -> bool {
+        !self.store_keys.is_empty()
+    }
+}
+
+impl StrongholdAdapter {
+    /// Verifies the Stronghold snapshot at `snapshot_path` with `password` and lists its records.
+    ///
+    /// Unlike building a [`StrongholdAdapter`], this distinguishes a missing file, a corrupted file, a snapshot format
+    /// version mismatch and a wrong password. Errors are only returned if a readable snapshot can't be inspected.
+    pub fn verify_snapshot<P: AsRef<Path>>(snapshot_path: P, password: &str) -> Result<SnapshotStatus> {
+        let snapshot_path = snapshot_path.as_ref();
+
+        if let Some(status) = verify_snapshot_header(snapshot_path) {
+            return Ok(status);
+        }
+
+        let stronghold = Stronghold::default();
+        let key_provider = key_provider_from_password(password);
+
+        match load_snapshot_client(&stronghold, &key_provider, snapshot_path) {
+            Ok(client) => Ok(SnapshotStatus::Valid(snapshot_content(&client, &key_provider)?.0)),
+            Err(status) => Ok(status),
+        }
+    }
+
+    /// Extracts the seed derived from a mnemonic and the readable store entries of the Stronghold snapshot at
+    /// `snapshot_path` into a new snapshot at `repaired_snapshot_path`, encrypted with the same password. Entries of
+    /// the store which can't be decrypted are left out.
+    ///
+    /// Returns the content of the new snapshot, or [`Error::StrongholdSnapshotUnreadable`] if the snapshot can't be
+    /// read at all.
+    pub fn repair_snapshot<P: AsRef<Path>, Q: AsRef<Path>>(
+        snapshot_path: P,
+        password: &str,
+        repaired_snapshot_path: Q,
+    ) -> Result<SnapshotContent> {
+        let snapshot_path = snapshot_path.as_ref();
+
+        if let Some(status) = verify_snapshot_header(snapshot_path) {
+            return Err(Error::StrongholdSnapshotUnreadable(Box::new(status)));
+        }
+
+        let stronghold = Stronghold::default();
+        let key_provider = key_provider_from_password(password);
+        let client = load_snapshot_client(&stronghold, &key_provider, snapshot_path)
+            .map_err(|status| Error::StrongholdSnapshotUnreadable(Box::new(status)))?;
+        let (content, corrupted_keys) = snapshot_content(&client, &key_provider)?;
+
+        if !content.seed {
+            return Err(Error::StrongholdMnemonicMissing);
+        }
+
+        let store = client.store();
+        for key in corrupted_keys {
+            store.delete(&key)?;
+        }
+
+        stronghold.write_client(PRIVATE_DATA_CLIENT_PATH)?;
+        stronghold.commit_with_keyprovider(&SnapshotPath::from_path(repaired_snapshot_path), &key_provider)?;
+
+        Ok(SnapshotContent {
+            corrupted_store_keys: Vec::new(),
+            ..content
+        })
+    }
+}
+
+/// Checks the file and its header, returns a status if it can't be a readable snapshot.
+pub(crate) fn verify_snapshot_header(snapshot_path: &Path) -> Option<SnapshotStatus> {
+    if !snapshot_path.exists() {
+        return Some(SnapshotStatus::Missing);
+    }
+
+    let read_header = || -> std::io::Result<Option<([u8; 5], [u8; 2])>> {
+        let mut file = File::open(snapshot_path)?;
+        if file.metadata()?.len() < SNAPSHOT_MIN_LENGTH {
+            return Ok(None);
+        }
+        let mut magic = [0u8; 5];
+        file.read_exact(&mut magic)?;
+        let mut version = [0u8; 2];
+        file.read_exact(&mut version)?;
+        Ok(Some((magic, version)))
+    };
+
+    match read_header() {
+        Err(err) => Some(SnapshotStatus::Corrupted(format!("can't read the file: {err}"))),
+        Ok(None) => Some(SnapshotStatus::Corrupted("file too short".to_string())),
+        Ok(Some((magic, _))) if magic != SNAPSHOT_MAGIC => {
+            Some(SnapshotStatus::Corrupted("not a Stronghold snapshot".to_string()))
+        }
+        Ok(Some((_, version))) if version != SNAPSHOT_VERSION => Some(SnapshotStatus::UnsupportedVersion {
+            expected: SNAPSHOT_VERSION,
+            found: version,
+        }),
+        Ok(Some(_)) => None,
+    }
+}
+
+/// Loads the client holding the secrets and the store, returns a status if it can't be loaded.
+fn load_snapshot_client(
+    stronghold: &Stronghold,
+    key_provider: &KeyProvider,
+    snapshot_path: &Path,
+) -> std::result::Result<Client, SnapshotStatus> {
+    match stronghold.load_client_from_snapshot(
+        PRIVATE_DATA_CLIENT_PATH,
+        key_provider,
+        &SnapshotPath::from_path(snapshot_path),
+    ) {
+        Ok(client) => Ok(client),
+        Err(ClientError::SnapshotFileMissing(_)) => Err(SnapshotStatus::Missing),
+        Err(ClientError::Inner(err_msg)) => {
+            // Matching the error string is not ideal but stronghold doesn't wrap the error types at the moment.
+            if err_msg.contains("XCHACHA20-POLY1305") {
+                Err(SnapshotStatus::InvalidPassword)
+            } else {
+                Err(SnapshotStatus::Corrupted(err_msg))
+            }
+        }
+        Err(err) => Err(SnapshotStatus::Corrupted(err.to_string())),
+    }
+}
+
+/// Lists the records of a loaded client, also returns the raw keys of the store entries which can't be decrypted.
+fn snapshot_content(client: &Client, key_provider: &KeyProvider) -> Result<(SnapshotContent, Vec<Vec<u8>>)> {
+    let seed = client.record_exists(&Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH))?;
+
+    let buffer = key_provider.try_unlock()?;
+    let buffer_ref = buffer.borrow();
+    let store = client.store();
+
+    let mut store_keys = Vec::new();
+    let mut corrupted_store_keys = Vec::new();
+    let mut corrupted_keys = Vec::new();
+    for key in store.keys()? {
+        let readable = matches!(
+            store.get(&key)?,
+            Some(data) if chacha::aead_decrypt(buffer_ref.deref(), &data).is_ok()
+        );
+        if readable {
+            store_keys.push(String::from_utf8_lossy(&key).into_owned());
+        } else {
+            corrupted_store_keys.push(String::from_utf8_lossy(&key).into_owned());
+            corrupted_keys.push(key);
+        }
+    }
+    store_keys.sort();
+    corrupted_store_keys.sort();
+
+    Ok((
+        SnapshotContent {
+            seed,
+            store_keys,
+            corrupted_store_keys,
+        },
+        corrupted_keys,
+    ))
+}
@@ -14,7 +14,10 @@ use zeroize::Zeroize;
 
 use super::Client;
 use crate::{
-    client::{Error, Result},
+    client::{
+        slip39::{self, Slip39Group},
+        Error, Result,
+    },
     types::block::{
         address::{Address, Ed25519Address},
         output::{AliasId, NftId},
@@ -62,26 +65,65 @@ pub fn generate_mnemonic() -> Result<String> {
 
 /// Returns a hex encoded seed for a mnemonic.
 pub fn mnemonic_to_hex_seed(mnemonic: &str) -> Result<String> {
-    // trim because empty spaces could create a different seed https://github.com/iotaledger/crypto.rs/issues/125
-    let mnemonic = mnemonic.trim();
-    // first we check if the mnemonic is valid to give meaningful errors
-    crypto::keys::bip39::wordlist::verify(mnemonic, &crypto::keys::bip39::wordlist::ENGLISH)
-        .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
-    let mut mnemonic_seed = [0u8; 64];
-    crypto::keys::bip39::mnemonic_to_seed(mnemonic, "", &mut mnemonic_seed);
-    Ok(prefix_hex::encode(mnemonic_seed))
+    mnemonic_to_hex_seed_with_passphrase(mnemonic, "")
+}
+
+/// Returns a hex encoded seed for a mnemonic and a BIP-39 passphrase.
+pub fn mnemonic_to_hex_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<String> {
+    let mut mnemonic_seed = bip39_seed(mnemonic, passphrase)?;
+    let hex_seed = prefix_hex::encode(mnemonic_seed);
+    mnemonic_seed.zeroize();
+    Ok(hex_seed)
 }
 
 /// Returns a seed for a mnemonic.
 pub fn mnemonic_to_seed(mnemonic: &str) -> Result<Seed> {
+    mnemonic_to_seed_with_passphrase(mnemonic, "")
+}
+
+/// Returns a seed for a mnemonic and a BIP-39 passphrase.
+pub fn mnemonic_to_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Seed> {
+    let mut mnemonic_seed = bip39_seed(mnemonic, passphrase)?;
+    let seed = Seed::from_bytes(&mnemonic_seed);
+    mnemonic_seed.zeroize();
+    Ok(seed)
+}
+
+fn bip39_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64]> {
     // trim because empty spaces could create a different seed https://github.com/iotaledger/crypto.rs/issues/125
     let mnemonic = mnemonic.trim();
     // first we check if the mnemonic is valid to give meaningful errors
     crypto::keys::bip39::wordlist::verify(mnemonic, &crypto::keys::bip39::wordlist::ENGLISH)
         .map_err(|e| crate::client::Error::InvalidMnemonic(format!("{e:?}")))?;
     let mut mnemonic_seed = [0u8; 64];
-    crypto::keys::bip39::mnemonic_to_seed(mnemonic, "", &mut mnemonic_seed);
-    Ok(Seed::from_bytes(&mnemonic_seed))
+    crypto::keys::bip39::mnemonic_to_seed(mnemonic, passphrase, &mut mnemonic_seed);
+    Ok(mnemonic_seed)
+}
+
+/// Generates a new master secret and splits it into SLIP-39 mnemonic shares, returned by group. Any `group_threshold`
+/// groups, each with the member threshold of shares, together with the `passphrase`, recover the master secret.
+pub fn generate_slip39_shares(
+    group_threshold: u8,
+    groups: &[Slip39Group],
+    passphrase: &str,
+) -> Result<Vec<Vec<String>>> {
+    let mut master_secret = [0u8; 32];
+    utils::rand::fill(&mut master_secret)?;
+    let shares = slip39::split_master_secret(&master_secret, passphrase, group_threshold, groups);
+    master_secret.zeroize();
+    shares
+}
+
+/// Returns a seed for SLIP-39 mnemonic shares, the recovered master secret.
+pub fn slip39_shares_to_seed<S: AsRef<str>>(shares: &[S], passphrase: &str) -> Result<Seed> {
+    Ok(Seed::from_bytes(&slip39::combine_shares(shares, passphrase)?))
+}
+
+/// Returns a hex encoded seed for SLIP-39 mnemonic shares, the recovered master secret.
+pub fn slip39_shares_to_hex_seed<S: AsRef<str>>(shares: &[S], passphrase: &str) -> Result<String> {
+    Ok(prefix_hex::encode(
+        slip39::combine_shares(shares, passphrase)?.as_slice(),
+    ))
 }
 
 /// Requests funds from a faucet
@@ -155,6 +197,35 @@ impl Client {
         mnemonic_to_hex_seed(mnemonic)
     }
 
+    /// Returns a seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<Seed> {
+        mnemonic_to_seed_with_passphrase(mnemonic, passphrase)
+    }
+
+    /// Returns a hex encoded seed for a mnemonic and a BIP-39 passphrase.
+    pub fn mnemonic_to_hex_seed_with_passphrase(mnemonic: &str, passphrase: &str) -> Result<String> {
+        mnemonic_to_hex_seed_with_passphrase(mnemonic, passphrase)
+    }
+
+    /// Generates a new master secret and splits it into SLIP-39 mnemonic shares, returned by group.
+    pub fn generate_slip39_shares(
+        group_threshold: u8,
+        groups: &[Slip39Group],
+        passphrase: &str,
+    ) -> Result<Vec<Vec<String>>> {
+        generate_slip39_shares(group_threshold, groups, passphrase)
+    }
+
+    /// Returns a seed for SLIP-39 mnemonic shares.
+    pub fn slip39_shares_to_seed<S: AsRef<str>>(shares: &[S], passphrase: &str) -> Result<Seed> {
+        slip39_shares_to_seed(shares, passphrase)
+    }
+
+    /// Returns a hex encoded seed for SLIP-39 mnemonic shares.
+    pub fn slip39_shares_to_hex_seed<S: AsRef<str>>(shares: &[S], passphrase: &str) -> Result<String> {
+        slip39_shares_to_hex_seed(shares, passphrase)
+    }
+
     /// UTF-8 encodes the `tag` of a given TaggedDataPayload.
     pub fn tag_to_utf8(payload: &TaggedDataPayload) -> Result<String> {
         String::from_utf8(payload.tag().to_vec()).map_err(|_| Error::TaggedData("found invalid UTF-8".to_string()))
@@ -32,6 +32,7 @@ pub struct AccountBuilder {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<String>,
+    account_client_options: Option<ClientOptions>,
     client_options: Arc<RwLock<ClientOptions>>,
     coin_type: u32,
     secret_manager: Arc<RwLock<SecretManager>>,
@@ -56,6 +57,7 @@ impl AccountBuilder {
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            account_client_options: None,
             client_options,
             coin_type,
             secret_manager,
@@ -86,6 +88,12 @@ impl AccountBuilder {
         self
     }
 
+    /// Set client options for the account only, binding it to another network than the wallet
+    pub fn with_client_options(mut self, client_options: ClientOptions) -> Self {
+        self.account_client_options.replace(client_options);
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
@@ -116,7 +124,10 @@ impl AccountBuilder {
             }
         }
 
-        let client = self.client_options.read().await.clone().finish()?;
+        let client = match &self.account_client_options {
+            Some(account_client_options) => account_client_options.clone().finish()?,
+            None => self.client_options.read().await.clone().finish()?,
+        };
 
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
@@ -145,9 +156,12 @@ impl AccountBuilder {
                         ));
                     }
 
-                    // Get bech32_hrp from address
+                    // Get bech32_hrp from address, if both accounts are on the network of the wallet
                     if let Some(address) = first_account_addresses.first() {
-                        if bech32_hrp.is_none() {
+                        if bech32_hrp.is_none()
+                            && self.account_client_options.is_none()
+                            && first_account.read().await.client_options.is_none()
+                        {
                             bech32_hrp = Some(address.address.bech32_hrp.clone());
                         }
                     }
@@ -169,6 +183,7 @@ impl AccountBuilder {
                     key_index: 0,
                     internal: false,
                     used: false,
+                    label: None,
                 };
 
                 vec![first_public_account_address]
@@ -190,6 +205,8 @@ impl AccountBuilder {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            output_labels: HashMap::new(),
+            client_options: self.account_client_options.clone(),
         };
 
         let account_handle = AccountHandle::new(
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashMap, ops::Deref, sync::Arc};
+use std::{cmp::Ordering, collections::HashMap, ops::Deref, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, RwLock};
@@ -13,14 +13,16 @@ use crate::wallet::storage::manager::StorageManagerHandle;
 use crate::{
     client::{secret::SecretManager, Client},
     types::block::{
-        output::{FoundryId, Output, OutputId, TokenId},
+        address::Address,
+        output::{unlock_condition::UnlockConditions, AliasId, FoundryId, NftId, Output, OutputId, TokenId},
         payload::transaction::TransactionId,
+        DtoError,
     },
     wallet::{
         account::{
             types::{
                 address::{AccountAddress, AddressWithUnspentOutputs},
-                OutputData, Transaction,
+                Irc27Metadata, NftIrc27Metadata, OutputData, Transaction,
             },
             Account,
         },
@@ -40,6 +42,87 @@ pub struct FilterOptions {
     /// Filter all outputs for the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6).
     #[serde(rename = "outputTypes")]
     pub output_types: Option<Vec<u8>>,
+    /// Filter all outputs holding the native token.
+    #[serde(rename = "nativeTokenId")]
+    pub native_token_id: Option<TokenId>,
+    /// Filter the alias output with the alias id.
+    #[serde(rename = "aliasId")]
+    pub alias_id: Option<AliasId>,
+    /// Filter the foundry output with the foundry id.
+    #[serde(rename = "foundryId")]
+    pub foundry_id: Option<FoundryId>,
+    /// Filter the nft output with the nft id.
+    #[serde(rename = "nftId")]
+    pub nft_id: Option<NftId>,
+    /// Filter all outputs of the bech32 address, as account address of the output or in an address unlock condition.
+    pub address: Option<String>,
+    /// Filter all outputs with or without a timelock unlock condition.
+    #[serde(rename = "hasTimelock")]
+    pub has_timelock: Option<bool>,
+    /// Filter all outputs with or without an expiration unlock condition.
+    #[serde(rename = "hasExpiration")]
+    pub has_expiration: Option<bool>,
+    /// Filter all outputs with or without a storage deposit return unlock condition.
+    #[serde(rename = "hasStorageDepositReturn")]
+    pub has_storage_deposit_return: Option<bool>,
+    /// Filter all outputs with an amount below the minimum.
+    #[serde(rename = "minAmount")]
+    pub min_amount: Option<u64>,
+    /// Filter all outputs with an amount above the maximum.
+    #[serde(rename = "maxAmount")]
+    pub max_amount: Option<u64>,
+    /// Filter all outputs with the hex encoded tag feature.
+    pub tag: Option<String>,
+    /// Filter all outputs with a metadata feature containing the hex encoded bytes.
+    pub metadata: Option<String>,
+    /// Filter all spent or unspent outputs.
+    #[serde(rename = "isSpent")]
+    pub is_spent: Option<bool>,
+    /// Filter all outputs with the provided label, set on the output or on the address of the output.
+    pub label: Option<String>,
+    /// The order of the returned outputs, from the oldest to the newest booked output by default.
+    pub order: Option<OutputOrder>,
+    /// Only return the outputs after this output in the order. To get the next page, pass the id of the last output
+    /// of the previous page. The output is needed to know its position in the order, so it has to be an output of the
+    /// account, otherwise [`Error::OutputNotFoundInAccount`](crate::wallet::Error::OutputNotFoundInAccount) is
+    /// returned. Spent outputs stay in the account, so a page of a previous call stays a valid cursor.
+    pub cursor: Option<OutputId>,
+    /// The maximum number of returned outputs.
+    pub limit: Option<usize>,
+}
+
+/// The order of outputs returned with [`FilterOptions`], outputs with the same booked timestamp or amount are ordered
+/// by output id.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum OutputOrder {
+    /// Oldest booked output first.
+    #[default]
+    BookedTimestampAscending,
+    /// Newest booked output first.
+    BookedTimestampDescending,
+    /// Smallest amount first.
+    AmountAscending,
+    /// Largest amount first.
+    AmountDescending,
+}
+
+impl OutputOrder {
+    fn compare(self, a: &OutputData, b: &OutputData) -> Ordering {
+        let ordering = match self {
+            Self::BookedTimestampAscending | Self::BookedTimestampDescending => a
+                .metadata
+                .milestone_timestamp_booked
+                .cmp(&b.metadata.milestone_timestamp_booked),
+            Self::AmountAscending | Self::AmountDescending => a.output.amount().cmp(&b.output.amount()),
+        }
+        .then_with(|| a.output_id.cmp(&b.output_id));
+
+        match self {
+            Self::BookedTimestampAscending | Self::AmountAscending => ordering,
+            Self::BookedTimestampDescending | Self::AmountDescending => ordering.reverse(),
+        }
+    }
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -150,60 +233,46 @@ impl AccountHandle {
         Ok(account.addresses_with_unspent_outputs().to_vec())
     }
 
-    /// Returns outputs of the account
+    /// Returns outputs of the account, ordered and paginated with the filter options
     pub async fn outputs(&self, filter: Option<FilterOptions>) -> Result<Vec<OutputData>> {
-        let mut outputs = Vec::new();
-
-        for output in self.read().await.outputs.values() {
-            if let Some(filter_options) = &filter {
-                if let Some(lower_bound_booked_timestamp) = filter_options.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter_options.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(output_types) = &filter_options.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-            }
-            outputs.push(output.clone());
-        }
-
-        Ok(outputs)
+        let account = self.read().await;
+        filter_outputs(&account, account.outputs.values(), &filter.unwrap_or_default())
     }
 
-    /// Returns unspent outputs of the account
+    /// Returns unspent outputs of the account, ordered and paginated with the filter options
     pub async fn unspent_outputs(&self, filter: Option<FilterOptions>) -> Result<Vec<OutputData>> {
-        let mut outputs = Vec::new();
-
-        for output in self.read().await.unspent_outputs.values() {
-            if let Some(filter_options) = &filter {
-                if let Some(lower_bound_booked_timestamp) = filter_options.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter_options.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(output_types) = &filter_options.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
+        let account = self.read().await;
+        filter_outputs(&account, account.unspent_outputs.values(), &filter.unwrap_or_default())
+    }
+
+    /// Returns the IRC27 metadata of the unspent NFTs with immutable metadata, ordered by NFT id. NFTs whose metadata
+    /// isn't valid IRC27 metadata are returned with the reason instead of failing the call.
+    pub async fn irc_27_nfts(&self) -> Result<Vec<NftIrc27Metadata>> {
+        let mut nfts = self
+            .read()
+            .await
+            .unspent_outputs
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Nft(nft_output) => {
+                    let metadata = nft_output.immutable_features().metadata()?;
+                    let (metadata, error) = match Irc27Metadata::from_bytes(metadata.data()) {
+                        Ok(metadata) => (Some(metadata), None),
+                        Err(e) => (None, Some(e.to_string())),
+                    };
+                    Some(NftIrc27Metadata {
+                        nft_id: nft_output.nft_id_non_null(&output_data.output_id),
+                        output_id: output_data.output_id,
+                        metadata,
+                        error,
+                    })
                 }
-            }
-            outputs.push(output.clone());
-        }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        nfts.sort_by_key(|nft| nft.nft_id);
 
-        Ok(outputs)
+        Ok(nfts)
     }
 
     /// Returns all incoming transactions of the account
@@ -260,3 +329,293 @@ impl Deref for AccountHandle {
         self.account.deref()
     }
 }
+
+/// Returns the outputs matching the filter, in the order of the filter, after its cursor and at most its limit.
+/// Returns [`Error::OutputNotFoundInAccount`](crate::wallet::Error::OutputNotFoundInAccount) if the cursor isn't an
+/// output of the account.
+fn filter_outputs<'a>(
+    account: &Account,
+    outputs: impl Iterator<Item = &'a OutputData>,
+    filter: &FilterOptions,
+) -> Result<Vec<OutputData>> {
+    let address = filter.address.as_deref().map(Address::try_from_bech32).transpose()?;
+    let tag = filter
+        .tag
+        .as_deref()
+        .map(|tag| prefix_hex::decode::<Vec<u8>>(tag).map_err(|_| DtoError::InvalidField("tag")))
+        .transpose()?;
+    let metadata = filter
+        .metadata
+        .as_deref()
+        .map(|metadata| prefix_hex::decode::<Vec<u8>>(metadata).map_err(|_| DtoError::InvalidField("metadata")))
+        .transpose()?;
+    let order = filter.order.unwrap_or_default();
+    // The cursor can be any output of the account, it doesn't need to match the filter
+    let cursor = filter
+        .cursor
+        .map(|output_id| {
+            account
+                .outputs
+                .get(&output_id)
+                .or_else(|| account.unspent_outputs.get(&output_id))
+                .ok_or_else(|| crate::wallet::Error::OutputNotFoundInAccount(output_id.to_string()))
+        })
+        .transpose()?;
+
+    let mut filtered_outputs = Vec::new();
+
+    for output_data in outputs {
+        let output = &output_data.output;
+        let unlock_conditions = output.unlock_conditions();
+        let features = output.features();
+
+        if let Some(cursor) = cursor {
+            if order.compare(output_data, cursor).is_le() {
+                continue;
+            }
+        }
+        if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
+            if output_data.metadata.milestone_timestamp_booked < lower_bound_booked_timestamp {
+                continue;
+            }
+        }
+        if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
+            if output_data.metadata.milestone_timestamp_booked > upper_bound_booked_timestamp {
+                continue;
+            }
+        }
+        if let Some(output_types) = &filter.output_types {
+            if !output_types.contains(&output.kind()) {
+                continue;
+            }
+        }
+        if let Some(token_id) = &filter.native_token_id {
+            if !output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter())
+                .any(|native_token| native_token.token_id() == token_id)
+            {
+                continue;
+            }
+        }
+        if let Some(alias_id) = &filter.alias_id {
+            if !matches!(output, Output::Alias(alias) if &alias.alias_id_non_null(&output_data.output_id) == alias_id) {
+                continue;
+            }
+        }
+        if let Some(foundry_id) = &filter.foundry_id {
+            if !matches!(output, Output::Foundry(foundry) if &foundry.id() == foundry_id) {
+                continue;
+            }
+        }
+        if let Some(nft_id) = &filter.nft_id {
+            if !matches!(output, Output::Nft(nft) if &nft.nft_id_non_null(&output_data.output_id) == nft_id) {
+                continue;
+            }
+        }
+        if let Some(address) = &address {
+            if output_data.address != *address
+                && !unlock_conditions
+                    .into_iter()
+                    .flat_map(unlock_condition_addresses)
+                    .any(|a| a == address)
+            {
+                continue;
+            }
+        }
+        if let Some(has_timelock) = filter.has_timelock {
+            if unlock_conditions.and_then(|ucs| ucs.timelock()).is_some() != has_timelock {
+                continue;
+            }
+        }
+        if let Some(has_expiration) = filter.has_expiration {
+            if unlock_conditions.and_then(|ucs| ucs.expiration()).is_some() != has_expiration {
+                continue;
+            }
+        }
+        if let Some(has_storage_deposit_return) = filter.has_storage_deposit_return {
+            if unlock_conditions.and_then(|ucs| ucs.storage_deposit_return()).is_some() != has_storage_deposit_return {
+                continue;
+            }
+        }
+        if let Some(min_amount) = filter.min_amount {
+            if output.amount() < min_amount {
+                continue;
+            }
+        }
+        if let Some(max_amount) = filter.max_amount {
+            if output.amount() > max_amount {
+                continue;
+            }
+        }
+        if let Some(tag) = &tag {
+            if features.and_then(|features| features.tag()).map(|tag| tag.tag()) != Some(tag.as_slice()) {
+                continue;
+            }
+        }
+        if let Some(metadata) = &metadata {
+            if !features
+                .and_then(|features| features.metadata())
+                .into_iter()
+                .any(|metadata_feature| contains_bytes(metadata_feature.data(), metadata))
+            {
+                continue;
+            }
+        }
+        if let Some(is_spent) = filter.is_spent {
+            if output_data.is_spent != is_spent {
+                continue;
+            }
+        }
+        if let Some(label) = &filter.label {
+            if !account.output_has_label(output_data, label) {
+                continue;
+            }
+        }
+        filtered_outputs.push(output_data);
+    }
+
+    filtered_outputs.sort_by(|a, b| order.compare(a, b));
+    if let Some(limit) = filter.limit {
+        filtered_outputs.truncate(limit);
+    }
+
+    Ok(filtered_outputs.into_iter().cloned().collect())
+}
+
+/// Returns the addresses of the address, state controller, governor and immutable alias address unlock conditions.
+fn unlock_condition_addresses(unlock_conditions: &UnlockConditions) -> impl Iterator<Item = &Address> {
+    [
+        unlock_conditions.address().map(|uc| uc.address()),
+        unlock_conditions.state_controller_address().map(|uc| uc.address()),
+        unlock_conditions.governor_address().map(|uc| uc.address()),
+        unlock_conditions.immutable_alias_address().map(|uc| uc.address()),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+fn contains_bytes(data: &[u8], bytes: &[u8]) -> bool {
+    bytes.is_empty() || data.windows(bytes.len()).any(|window| window == bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::Ed25519Address,
+            output::{
+                feature::TagFeature,
+                unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition, UnlockCondition},
+                BasicOutputBuilder,
+            },
+            protocol::protocol_parameters,
+        },
+        wallet::account::test_utils::{account, output_data, output_id},
+    };
+
+    fn basic_output_data(
+        output_index: u16,
+        amount: u64,
+        booked_timestamp: u32,
+        tag: Option<&[u8]>,
+        timelock: bool,
+    ) -> OutputData {
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let mut builder = BasicOutputBuilder::new_with_amount(amount)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)));
+        if let Some(tag) = tag {
+            builder = builder.add_feature(TagFeature::new(tag.to_vec()).unwrap());
+        }
+        if timelock {
+            builder = builder.add_unlock_condition(UnlockCondition::Timelock(TimelockUnlockCondition::new(1).unwrap()));
+        }
+
+        output_data(
+            output_index,
+            builder.finish_output(protocol_parameters().token_supply()).unwrap(),
+            address,
+            (1, booked_timestamp),
+        )
+    }
+
+    #[test]
+    fn filter_order_and_paginate_outputs() {
+        let outputs = [
+            basic_output_data(0, 3_000_000, 300, Some(b"tag"), false),
+            basic_output_data(1, 1_000_000, 100, None, true),
+            basic_output_data(2, 2_000_000, 200, Some(b"tag"), false),
+            basic_output_data(3, 4_000_000, 200, None, false),
+        ];
+        let account = account(outputs.clone());
+        let filter = |filter: FilterOptions| {
+            filter_outputs(&account, account.outputs.values(), &filter)
+                .unwrap()
+                .iter()
+                .map(|o| o.metadata.output_index)
+                .collect::<Vec<_>>()
+        };
+
+        // Oldest first, outputs booked at the same time are ordered by output id
+        assert_eq!(filter(FilterOptions::default()), [1, 2, 3, 0]);
+        assert_eq!(
+            filter(FilterOptions {
+                order: Some(OutputOrder::AmountDescending),
+                ..Default::default()
+            }),
+            [3, 0, 2, 1]
+        );
+
+        // Pages of two outputs
+        let first_page = FilterOptions {
+            limit: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(filter(first_page.clone()), [1, 2]);
+        assert_eq!(
+            filter(FilterOptions {
+                cursor: Some(outputs[2].output_id),
+                ..first_page
+            }),
+            [3, 0]
+        );
+        // The position of an unknown cursor in the order can't be known
+        assert!(matches!(
+            filter_outputs(
+                &account,
+                account.outputs.values(),
+                &FilterOptions {
+                    cursor: Some(output_id(4)),
+                    ..Default::default()
+                },
+            ),
+            Err(crate::wallet::Error::OutputNotFoundInAccount(_))
+        ));
+
+        assert_eq!(
+            filter(FilterOptions {
+                min_amount: Some(2_000_000),
+                max_amount: Some(3_000_000),
+                ..Default::default()
+            }),
+            [2, 0]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                tag: Some(prefix_hex::encode(b"tag")),
+                ..Default::default()
+            }),
+            [2, 0]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                has_timelock: Some(false),
+                ..Default::default()
+            }),
+            [2, 3, 0]
+        );
+    }
+}
@@ -9,6 +9,9 @@ pub(crate) mod constants;
 pub(crate) mod handle;
 /// The account operations like address generation, syncing and creating transactions.
 pub(crate) mod operations;
+/// Fixtures shared by the tests of the account.
+#[cfg(test)]
+pub(crate) mod test_utils;
 /// Types used in an account and returned from methods.
 pub mod types;
 /// Methods to update the account state.
@@ -27,7 +30,7 @@ use self::types::{
     AccountBalance, OutputData, Transaction,
 };
 pub use self::{
-    handle::{AccountHandle, FilterOptions},
+    handle::{AccountHandle, FilterOptions, OutputOrder},
     operations::{
         address_generation::AddressGenerationOptions,
         output_claiming::OutputsToClaim,
@@ -40,10 +43,14 @@ pub use self::{
             prepare_output::{Assets, Features, OutputOptions, ReturnStrategy, StorageDeposit, Unlocks},
             RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
         },
+        transaction_history::{
+            AssetMovement, AssetMovementDto, MovedAsset, MovementDirection, TransactionHistoryFormat,
+        },
     },
     types::OutputDataDto,
 };
 use crate::{
+    client::Client,
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
@@ -55,7 +62,7 @@ use crate::{
             BlockId,
         },
     },
-    wallet::account::types::InclusionState,
+    wallet::{account::types::InclusionState, ClientOptions},
 };
 
 /// An Account.
@@ -106,6 +113,56 @@ pub struct Account {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Labels set by the user for outputs, removed together with the outputs
+    #[serde(default)]
+    output_labels: HashMap<OutputId, String>,
+    /// Client options of the account, if it's bound to another network than the wallet
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) client_options: Option<ClientOptions>,
+}
+
+impl Account {
+    /// Returns whether the output or the account address of the output has the label.
+    pub(crate) fn output_has_label(&self, output: &OutputData, label: &str) -> bool {
+        self.output_labels.get(&output.output_id).map(String::as_str) == Some(label)
+            || self
+                .public_addresses
+                .iter()
+                .chain(self.internal_addresses.iter())
+                .any(|a| a.address.inner == output.address && a.label.as_deref() == Some(label))
+    }
+}
+
+/// The clients of accounts, accounts bound to the same client options share one client.
+pub(crate) struct AccountClients {
+    wallet_client: Client,
+    bound_clients: Vec<(ClientOptions, Client)>,
+}
+
+impl AccountClients {
+    pub(crate) fn new(wallet_client: Client) -> Self {
+        Self {
+            wallet_client,
+            bound_clients: Vec::new(),
+        }
+    }
+
+    /// Returns a client for the network of the account, which is the one of the wallet unless the account has its own
+    /// client options. The client is only built for the first account with these client options.
+    pub(crate) fn get(&mut self, account: &Account) -> crate::wallet::Result<Client> {
+        let Some(client_options) = &account.client_options else {
+            return Ok(self.wallet_client.clone());
+        };
+
+        if let Some((_, client)) = self.bound_clients.iter().find(|(options, _)| options == client_options) {
+            return Ok(client.clone());
+        }
+
+        let client = client_options.clone().finish()?;
+        self.bound_clients.push((client_options.clone(), client.clone()));
+
+        Ok(client)
+    }
 }
 
 // Custom deserialization to stay backwards compatible
@@ -253,7 +310,31 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        output_labels: HashMap::new(),
+        client_options: None,
     };
 
     serde_json::from_str::<Account>(&serde_json::to_string(&account).unwrap()).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn account_clients_are_shared_per_network() {
+        let bound_account = |node: &str| {
+            let mut account = test_utils::account([]);
+            account.client_options = Some(ClientOptions::new().with_node(node).unwrap());
+            account
+        };
+        let mut account_clients = AccountClients::new(Client::builder().finish().unwrap());
+
+        account_clients.get(&test_utils::account([])).unwrap();
+        account_clients.get(&bound_account("http://localhost:14265")).unwrap();
+        account_clients.get(&bound_account("http://localhost:14265")).unwrap();
+        account_clients.get(&bound_account("http://localhost:14266")).unwrap();
+
+        assert_eq!(account_clients.bound_clients.len(), 2);
+    }
+}
@@ -163,6 +163,7 @@ impl AccountHandle {
                 key_index: highest_current_index_plus_one + index as u32,
                 internal: options.internal,
                 used: false,
+                label: None,
             })
             .collect();
 
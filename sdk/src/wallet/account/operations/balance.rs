@@ -1,17 +1,28 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use primitive_types::U256;
 
 use crate::{
-    types::block::output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
-    wallet::account::{
-        handle::AccountHandle,
-        operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
-        types::{AccountBalance, BaseCoinBalance, NativeTokensBalance, RequiredStorageDeposit},
-        OutputsToClaim,
+    types::block::{
+        address::Address,
+        input::Input,
+        output::{unlock_condition::UnlockCondition, FoundryId, NativeTokensBuilder, Output, Rent},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            handle::AccountHandle,
+            operations::helpers::time::can_output_be_unlocked_forever_from_now_on,
+            types::{
+                AccountBalance, BalancePoint, BaseCoinBalance, HistoricalBalance, InclusionState, Irc30Metadata,
+                NativeTokensBalance, OutputData, RequiredStorageDeposit, Transaction,
+            },
+            Account, OutputsToClaim,
+        },
+        Error,
     },
 };
 
@@ -272,10 +283,15 @@ impl AccountHandle {
                 .get(&FoundryId::from(*native_token.token_id()))
                 .and_then(|foundry| foundry.immutable_features().metadata())
                 .cloned();
+            // Foundry metadata which doesn't follow IRC30 is still returned as raw metadata
+            let irc_30_metadata = metadata
+                .as_ref()
+                .and_then(|metadata| Irc30Metadata::from_bytes(metadata.data()).ok());
 
             native_tokens_balance.push(NativeTokensBalance {
                 token_id: *native_token.token_id(),
                 metadata,
+                irc_30_metadata,
                 total: native_token.amount(),
                 available: native_token.amount() - *locked_amount.unwrap_or(&U256::from(0u8)),
             })
@@ -301,6 +317,212 @@ impl AccountHandle {
     }
 }
 
+impl AccountHandle {
+    /// Get the balance the account held at a milestone timestamp or index. It's computed from the outputs and
+    /// transactions stored in the account, so the account needs to be synced after the requested point.
+    pub async fn balance_at(&self, point: BalancePoint) -> crate::wallet::Result<HistoricalBalance> {
+        log::debug!("[BALANCE] get balance at {:?}", point);
+        let network_id = self.client.get_network_id().await?;
+
+        historical_balance(&*self.read().await, network_id, point)
+    }
+
+    /// Get the balances the account held from `start` to `end`, every `step` seconds for timestamps or every `step`
+    /// milestones for milestone indexes. The balance at `end` is always included, at most 1000 balances can be
+    /// requested.
+    pub async fn balance_history(
+        &self,
+        start: BalancePoint,
+        end: BalancePoint,
+        step: u32,
+    ) -> crate::wallet::Result<Vec<HistoricalBalance>> {
+        log::debug!("[BALANCE] get balance history from {:?} to {:?}", start, end);
+        let points = balance_points(start, end, step)?;
+        let network_id = self.client.get_network_id().await?;
+        let account = self.read().await;
+
+        points
+            .into_iter()
+            .map(|point| historical_balance(&account, network_id, point))
+            .collect()
+    }
+}
+
+/// The maximum number of balances in a balance history.
+const MAX_BALANCE_POINTS: u64 = 1000;
+
+// Get the points from `start` to `end` in steps of `step`, including `end`
+fn balance_points(start: BalancePoint, end: BalancePoint, step: u32) -> crate::wallet::Result<Vec<BalancePoint>> {
+    let (start_value, end_value, to_point): (u32, u32, fn(u32) -> BalancePoint) = match (start, end) {
+        (BalancePoint::Timestamp(start), BalancePoint::Timestamp(end)) => (start, end, BalancePoint::Timestamp),
+        (BalancePoint::MilestoneIndex(start), BalancePoint::MilestoneIndex(end)) => {
+            (start, end, BalancePoint::MilestoneIndex)
+        }
+        _ => {
+            return Err(Error::InvalidBalanceRange(
+                "start and end need to be both timestamps or both milestone indexes".to_string(),
+            ));
+        }
+    };
+    if start_value > end_value {
+        return Err(Error::InvalidBalanceRange("start is after end".to_string()));
+    }
+    if step == 0 {
+        return Err(Error::InvalidBalanceRange(
+            "step needs to be greater than 0".to_string(),
+        ));
+    }
+
+    let point_count = u64::from(end_value - start_value).div_ceil(u64::from(step)) + 1;
+    if point_count > MAX_BALANCE_POINTS {
+        return Err(Error::InvalidBalanceRange(format!(
+            "{point_count} balances requested, at most {MAX_BALANCE_POINTS} are allowed"
+        )));
+    }
+
+    let mut points = (start_value..end_value)
+        .step_by(step as usize)
+        .map(to_point)
+        .collect::<Vec<_>>();
+    points.push(to_point(end_value));
+
+    Ok(points)
+}
+
+// Compute the balance of the outputs the account held at `point`, which are the outputs booked at or before it and
+// spent after it
+pub(crate) fn historical_balance(
+    account: &Account,
+    network_id: u64,
+    point: BalancePoint,
+) -> crate::wallet::Result<HistoricalBalance> {
+    let account_addresses = account
+        .public_addresses
+        .iter()
+        .chain(account.internal_addresses.iter())
+        .map(|a| &a.address.inner)
+        .collect::<Vec<&Address>>();
+
+    let mut base_coin = 0;
+    let mut native_tokens = NativeTokensBuilder::new();
+    let mut nfts = Vec::new();
+    let mut aliases = Vec::new();
+
+    for output_data in account.outputs.values() {
+        // Check if output is from the network we're currently connected to
+        if output_data.network_id != network_id || !was_output_held_at(account, output_data, point) {
+            continue;
+        }
+
+        let mut amount = output_data.output.amount();
+        if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
+            // Outputs with an expiration belong to the return address once they're expired. For milestone indexes the
+            // timestamp isn't known, so they're attributed to the address of the address unlock condition.
+            if let (Some(address), Some(_)) = (unlock_conditions.address(), unlock_conditions.expiration()) {
+                let locked_address = match point {
+                    BalancePoint::Timestamp(timestamp) => {
+                        unlock_conditions.locked_address(address.address(), timestamp)
+                    }
+                    BalancePoint::MilestoneIndex(_) => address.address(),
+                };
+                if !account_addresses.contains(&locked_address) {
+                    continue;
+                }
+            }
+            // The amount of a storage deposit return to someone else isn't ours
+            if let Some(sdr) = unlock_conditions.storage_deposit_return() {
+                if !account_addresses.contains(&sdr.return_address()) {
+                    amount -= sdr.amount();
+                }
+            }
+        }
+
+        base_coin += amount;
+        if let Some(output_native_tokens) = output_data.output.native_tokens() {
+            native_tokens.add_native_tokens(output_native_tokens.clone())?;
+        }
+        match &output_data.output {
+            Output::Alias(output) => aliases.push(output.alias_id_non_null(&output_data.output_id)),
+            Output::Nft(output) => nfts.push(output.nft_id_non_null(&output_data.output_id)),
+            _ => {}
+        }
+    }
+
+    Ok(HistoricalBalance {
+        point,
+        base_coin,
+        native_tokens: native_tokens.finish_vec()?,
+        nfts,
+        aliases,
+    })
+}
+
+// Check if an output was booked at or before `point` and not spent at it. If the node didn't return when a spent
+// output was spent, the booking of an account output created by the spending transaction is used, or for timestamps
+// the creation time of the confirmed account transaction spending it. Spent outputs that can't be placed in time
+// aren't counted.
+fn was_output_held_at(account: &Account, output_data: &OutputData, point: BalancePoint) -> bool {
+    let metadata = &output_data.metadata;
+    let (booked, spent) = match point {
+        BalancePoint::Timestamp(_) => (
+            metadata.milestone_timestamp_booked,
+            metadata
+                .milestone_timestamp_spent
+                .or_else(|| spending_transaction_booking(account, output_data).map(|(_, timestamp)| timestamp))
+                .or_else(|| {
+                    spending_transaction(account, output_data).map(|transaction| (transaction.timestamp / 1000) as u32)
+                }),
+        ),
+        BalancePoint::MilestoneIndex(_) => (
+            metadata.milestone_index_booked,
+            metadata
+                .milestone_index_spent
+                .or_else(|| spending_transaction_booking(account, output_data).map(|(index, _)| index)),
+        ),
+    };
+    let (BalancePoint::Timestamp(value) | BalancePoint::MilestoneIndex(value)) = point;
+
+    if booked > value {
+        return false;
+    }
+    spent.map_or(!output_data.is_spent && !metadata.is_spent, |spent| spent > value)
+}
+
+// Get the milestone index and timestamp at which an account output created by the transaction spending the output was
+// booked, which is when the output got spent
+fn spending_transaction_booking(account: &Account, output_data: &OutputData) -> Option<(u32, u32)> {
+    let transaction_id = match &output_data.metadata.transaction_id_spent {
+        Some(transaction_id) => TransactionId::from_str(transaction_id).ok()?,
+        None => spending_transaction(account, output_data)?.transaction_id,
+    };
+
+    account
+        .outputs
+        .values()
+        .find(|output| output.output_id.transaction_id() == &transaction_id)
+        .map(|output| {
+            (
+                output.metadata.milestone_index_booked,
+                output.metadata.milestone_timestamp_booked,
+            )
+        })
+}
+
+// Get the confirmed account transaction which spent the output
+fn spending_transaction<'a>(account: &'a Account, output_data: &OutputData) -> Option<&'a Transaction> {
+    account
+        .transactions
+        .values()
+        .filter(|transaction| transaction.inclusion_state == InclusionState::Confirmed)
+        .find(|transaction| {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            essence
+                .inputs()
+                .iter()
+                .any(|input| matches!(input, Input::Utxo(input) if *input.output_id() == output_data.output_id))
+        })
+}
+
 pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::wallet::Result<AccountBalance> {
     let mut total_balance: AccountBalance = Default::default();
 
@@ -324,6 +546,7 @@ pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::wallet::Resu
                 total_balance.native_tokens.push(NativeTokensBalance {
                     token_id: native_token_balance.token_id,
                     metadata: native_token_balance.metadata.clone(),
+                    irc_30_metadata: native_token_balance.irc_30_metadata.clone(),
                     total: native_token_balance.total,
                     available: native_token_balance.available,
                 })
@@ -333,3 +556,104 @@ pub(crate) fn add_balances(balances: Vec<AccountBalance>) -> crate::wallet::Resu
 
     Ok(total_balance)
 }
+
+#[test]
+fn historical_balance_from_outputs() {
+    use crate::{
+        types::block::{
+            address::Ed25519Address,
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, NftOutputBuilder, OutputId},
+            protocol::protocol_parameters,
+        },
+        wallet::account::test_utils::{account, output_data, NETWORK_ID},
+    };
+
+    let token_supply = protocol_parameters().token_supply();
+    let address = Address::from(Ed25519Address::new([1; 32]));
+    let output_data = |output_index: u16, output: Output, booked: (u32, u32), spent: Option<(u32, u32)>| {
+        let mut output_data = output_data(output_index, output, address, booked);
+        if let Some((milestone_index, milestone_timestamp)) = spent {
+            output_data.is_spent = true;
+            output_data.metadata.is_spent = true;
+            output_data.metadata.milestone_index_spent = Some(milestone_index);
+            output_data.metadata.milestone_timestamp_spent = Some(milestone_timestamp);
+        }
+        output_data
+    };
+    let basic_output = |amount| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .unwrap()
+            .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)))
+            .finish_output(token_supply)
+            .unwrap()
+    };
+    let nft_output = NftOutputBuilder::new_with_amount(100_000, NftId::null())
+        .unwrap()
+        .add_unlock_condition(UnlockCondition::Address(AddressUnlockCondition::new(address)))
+        .finish_output(token_supply)
+        .unwrap();
+
+    let outputs = [
+        output_data(0, basic_output(1_000_000), (10, 1000), Some((20, 2000))),
+        output_data(1, basic_output(2_000_000), (15, 1500), None),
+        output_data(2, nft_output, (20, 2000), None),
+    ];
+    let nft_id = NftId::from(&outputs[2].output_id);
+
+    let mut account = account(outputs);
+
+    // Before anything was booked
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::MilestoneIndex(9)).unwrap();
+    assert_eq!(balance.base_coin, 0);
+
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::Timestamp(1500)).unwrap();
+    assert_eq!(balance.base_coin, 3_000_000);
+    assert!(balance.nfts.is_empty());
+
+    // The first output got spent in the same milestone which booked the nft output
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::MilestoneIndex(20)).unwrap();
+    assert_eq!(balance.base_coin, 2_100_000);
+    assert_eq!(balance.nfts, vec![nft_id]);
+
+    // Outputs of other networks are ignored
+    let balance = historical_balance(&account, NETWORK_ID + 1, BalancePoint::Timestamp(2000)).unwrap();
+    assert_eq!(balance.base_coin, 0);
+
+    // An output spent without the node returning when is spent when the output created by the spending transaction got
+    // booked
+    let spending_transaction_id = TransactionId::new([2; 32]);
+    let mut spent_output = output_data(3, basic_output(500_000), (21, 2100), Some((0, 0)));
+    spent_output.metadata.milestone_index_spent = None;
+    spent_output.metadata.milestone_timestamp_spent = None;
+    spent_output.metadata.transaction_id_spent = Some(spending_transaction_id.to_string());
+    let mut created_output = output_data(0, basic_output(400_000), (30, 3000), None);
+    created_output.output_id = OutputId::new(spending_transaction_id, 0).unwrap();
+    account.outputs.insert(spent_output.output_id, spent_output);
+    account.outputs.insert(created_output.output_id, created_output);
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::MilestoneIndex(29)).unwrap();
+    assert_eq!(balance.base_coin, 2_600_000);
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::MilestoneIndex(30)).unwrap();
+    assert_eq!(balance.base_coin, 2_500_000);
+    let balance = historical_balance(&account, NETWORK_ID, BalancePoint::Timestamp(3000)).unwrap();
+    assert_eq!(balance.base_coin, 2_500_000);
+
+    let points = balance_points(BalancePoint::Timestamp(1000), BalancePoint::Timestamp(2500), 1000).unwrap();
+    assert_eq!(
+        points,
+        vec![
+            BalancePoint::Timestamp(1000),
+            BalancePoint::Timestamp(2000),
+            BalancePoint::Timestamp(2500)
+        ]
+    );
+    assert!(balance_points(BalancePoint::Timestamp(1000), BalancePoint::MilestoneIndex(20), 1).is_err());
+    // The number of points is capped
+    assert_eq!(
+        balance_points(BalancePoint::MilestoneIndex(1), BalancePoint::MilestoneIndex(1000), 1)
+            .unwrap()
+            .len(),
+        1000
+    );
+    assert!(balance_points(BalancePoint::MilestoneIndex(0), BalancePoint::MilestoneIndex(1000), 1).is_err());
+    assert!(balance_points(BalancePoint::Timestamp(0), BalancePoint::Timestamp(u32::MAX), 1).is_err());
+}
@@ -9,7 +9,9 @@
 // If the user has designated funds to vote with, the resulting output MUST NOT be used for input selection.
 
 pub(crate) mod event;
+pub(crate) mod staking;
 pub(crate) mod voting;
+pub(crate) mod voting_plan;
 pub(crate) mod voting_power;
 
 use std::collections::{hash_map::Entry, HashMap, HashSet};
@@ -21,7 +23,9 @@ use crate::{
     types::{
         api::plugins::participation::{
             responses::TrackedParticipation,
-            types::{ParticipationEventData, ParticipationEventId, Participations, PARTICIPATION_TAG},
+            types::{
+                AddressStakingStatus, ParticipationEventData, ParticipationEventId, Participations, PARTICIPATION_TAG,
+            },
         },
         block::output::{unlock_condition::UnlockCondition, Output, OutputId},
     },
@@ -36,6 +40,10 @@ use crate::{
 pub struct AccountParticipationOverview {
     /// Output participations for events.
     pub participations: HashMap<ParticipationEventId, HashMap<OutputId, TrackedParticipation>>,
+    /// Staking rewards of the account's addresses, keyed by bech32 address. Only requested if the account participated
+    /// in a registered staking event.
+    #[serde(rename = "stakingRewards", default)]
+    pub staking_rewards: HashMap<String, AddressStakingStatus>,
 }
 
 /// A participation event with the provided client nodes.
@@ -52,6 +60,7 @@ pub struct ParticipationEventWithNodes {
 impl AccountHandle {
     /// Calculates the voting overview of an account. If event_ids are provided, only return outputs and tracked
     /// participations for them.
+    /// Includes the staking rewards of the account's addresses if it participated in a registered staking event.
     pub async fn get_participation_overview(
         &self,
         event_ids: Option<Vec<ParticipationEventId>>,
@@ -215,7 +224,22 @@ impl AccountHandle {
                 .await?;
         }
 
-        Ok(AccountParticipationOverview { participations })
+        let registered_events = self.get_participation_events().await?;
+        let staking_event_id = participations.keys().find(
+            |event_id| matches!(registered_events.get(event_id), Some(event) if staking::is_staking_event(&event.data)),
+        );
+        let staking_rewards = match staking_event_id {
+            Some(event_id) => {
+                let event_client = self.get_client_for_event(event_id).await?;
+                self.address_staking_status(&event_client).await?
+            }
+            None => HashMap::new(),
+        };
+
+        Ok(AccountParticipationOverview {
+            participations,
+            staking_rewards,
+        })
     }
 
     /// Returns the voting output ("PARTICIPATION" tag).
@@ -0,0 +1,258 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{StakingEndedEvent, WalletEvent};
+use crate::{
+    client::Client,
+    types::api::plugins::participation::types::{
+        AddressStakingStatus, ParticipationEventData, ParticipationEventId, ParticipationEventPayload,
+    },
+    wallet::{
+        account::{types::Transaction, AccountHandle},
+        task, Result,
+    },
+};
+
+/// Staking rewards of an account, as calculated by the participation plugin.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountStakingRewards {
+    /// Rewards of the account's addresses that staked, keyed by bech32 address.
+    pub addresses: HashMap<String, AddressStakingStatus>,
+    /// Rewards of all addresses summed up, keyed by staking event id.
+    pub total: HashMap<String, u64>,
+}
+
+impl AccountStakingRewards {
+    fn new(addresses: HashMap<String, AddressStakingStatus>) -> Self {
+        let mut total: HashMap<String, u64> = HashMap::new();
+        for status in addresses.values() {
+            for (event_id, staking_status) in &status.rewards {
+                *total.entry(event_id.clone()).or_default() += staking_status.amount;
+            }
+        }
+
+        Self { addresses, total }
+    }
+}
+
+impl AccountHandle {
+    /// Starts staking for an event with the voting power of the account.
+    ///
+    /// The event is added to the voting plan, so staking continues when the voting power changes.
+    /// If the event isn't a staking event or has ended, throws an error.
+    pub async fn join_staking_event(&self, event_id: ParticipationEventId) -> Result<Transaction> {
+        self.check_staking_event(&event_id).await?;
+        // Staking participations don't have answers.
+        self.vote(Some(event_id), Some(Vec::new())).await
+    }
+
+    /// Stops staking for an event and removes it from the voting plan.
+    ///
+    /// If the event isn't a staking event or the account isn't staking for it, throws an error.
+    pub async fn leave_staking_event(&self, event_id: ParticipationEventId) -> Result<Transaction> {
+        self.check_staking_event(&event_id).await?;
+        self.stop_participating(event_id).await
+    }
+
+    /// Returns the staking rewards the addresses of the account accrued.
+    ///
+    /// Uses the nodes of a registered staking event if there is one, the client of the account otherwise.
+    pub async fn get_staking_rewards(&self) -> Result<AccountStakingRewards> {
+        log::debug!("[get_staking_rewards]");
+        let staking_event_id = self
+            .get_participation_events()
+            .await?
+            .into_iter()
+            .find(|(_, event)| is_staking_event(&event.data))
+            .map(|(event_id, _)| event_id);
+
+        let client = match staking_event_id {
+            Some(event_id) => self.get_client_for_event(&event_id).await?,
+            None => self.client().clone(),
+        };
+
+        Ok(AccountStakingRewards::new(self.address_staking_status(&client).await?))
+    }
+
+    /// Returns the staking status of the addresses of the account with rewards.
+    pub(crate) async fn address_staking_status(
+        &self,
+        client: &Client,
+    ) -> Result<HashMap<String, AddressStakingStatus>> {
+        let bech32_addresses = self
+            .addresses()
+            .await?
+            .iter()
+            .map(|address| address.address.to_bech32())
+            .collect::<Vec<_>>();
+
+        let mut staking_status = HashMap::new();
+        for bech32_address_chunk in bech32_addresses.chunks(100).map(|x| x.to_vec()) {
+            let mut tasks = Vec::new();
+            for bech32_address in bech32_address_chunk {
+                let client = client.clone();
+                tasks.push(async move {
+                    task::spawn(async move { (client.address_staking_status(&bech32_address).await, bech32_address) })
+                        .await
+                });
+            }
+
+            let results = futures::future::try_join_all(tasks).await?;
+            for (result, bech32_address) in results {
+                match result {
+                    Ok(status) => {
+                        if !status.rewards.is_empty() {
+                            staking_status.insert(bech32_address, status);
+                        }
+                    }
+                    Err(crate::client::Error::NotFound(_)) => {}
+                    Err(e) => return Err(crate::wallet::Error::Client(e.into())),
+                }
+            }
+        }
+
+        Ok(staking_status)
+    }
+
+    /// Returns the data of an event, from the registered events or from the node.
+    pub(crate) async fn get_participation_event_data(
+        &self,
+        event_id: &ParticipationEventId,
+    ) -> Result<ParticipationEventData> {
+        match self.get_participation_event(*event_id).await? {
+            Some(event_with_nodes) => Ok(event_with_nodes.data),
+            None => Ok(self.get_client_for_event(event_id).await?.event(event_id).await?),
+        }
+    }
+
+    /// Emits a [`WalletEvent::StakingEnded`] for the registered staking events which ended since they were last
+    /// checked.
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit_ended_staking_events(&self) -> Result<()> {
+        let account_index = self.read().await.index;
+        let ended_staking_events = self
+            .storage_manager
+            .lock()
+            .await
+            .get_ended_staking_events(account_index)
+            .await?;
+
+        for (event_id, event) in self.get_participation_events().await? {
+            if ended_staking_events.contains(&event_id) || !is_staking_event(&event.data) {
+                continue;
+            }
+            if self.get_participation_event_status(&event_id).await?.status() == "ended" {
+                self.emit_staking_ended(&event_id).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emits a [`WalletEvent::StakingEnded`] with the rewards of the account, if the event is a staking event. The
+    /// event is only emitted once per staking event.
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit_staking_ended(&self, event_id: &ParticipationEventId) -> Result<()> {
+        let account_index = self.read().await.index;
+        let mut ended_staking_events = self
+            .storage_manager
+            .lock()
+            .await
+            .get_ended_staking_events(account_index)
+            .await?;
+        if ended_staking_events.contains(event_id)
+            || !is_staking_event(&self.get_participation_event_data(event_id).await?)
+        {
+            return Ok(());
+        }
+
+        let event_client = self.get_client_for_event(event_id).await?;
+        let event_key = event_id.to_string();
+        let rewards = self
+            .address_staking_status(&event_client)
+            .await?
+            .into_iter()
+            .filter_map(|(bech32_address, mut status)| {
+                status
+                    .rewards
+                    .remove(&event_key)
+                    .map(|staking_status| (bech32_address, staking_status))
+            })
+            .collect();
+
+        self.event_emitter.lock().await.emit(
+            account_index,
+            WalletEvent::StakingEnded(StakingEndedEvent {
+                event_id: *event_id,
+                rewards,
+            }),
+        );
+
+        ended_staking_events.insert(*event_id);
+        self.storage_manager
+            .lock()
+            .await
+            .set_ended_staking_events(account_index, &ended_staking_events)
+            .await
+    }
+
+    async fn check_staking_event(&self, event_id: &ParticipationEventId) -> Result<()> {
+        if is_staking_event(&self.get_participation_event_data(event_id).await?) {
+            Ok(())
+        } else {
+            Err(crate::wallet::Error::Voting(format!(
+                "event {event_id} is not a staking event"
+            )))
+        }
+    }
+}
+
+pub(crate) fn is_staking_event(event_data: &ParticipationEventData) -> bool {
+    matches!(event_data.payload(), ParticipationEventPayload::StakingEventPayload(_))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::plugins::participation::types::StakingStatus;
+
+    #[test]
+    fn staking_rewards_total() {
+        let staking_status = |amounts: &[(&str, u64)]| AddressStakingStatus {
+            rewards: amounts
+                .iter()
+                .map(|(event_id, amount)| {
+                    (
+                        event_id.to_string(),
+                        StakingStatus {
+                            amount: *amount,
+                            symbol: "SMR".to_string(),
+                            minimum_reached: true,
+                        },
+                    )
+                })
+                .collect(),
+            milestone_index: 1,
+        };
+
+        let rewards = AccountStakingRewards::new(HashMap::from([
+            (
+                "address_a".to_string(),
+                staking_status(&[("event_a", 10), ("event_b", 5)]),
+            ),
+            ("address_b".to_string(), staking_status(&[("event_a", 7)])),
+        ]));
+
+        assert_eq!(rewards.addresses.len(), 2);
+        assert_eq!(
+            rewards.total,
+            HashMap::from([("event_a".to_string(), 17), ("event_b".to_string(), 5)])
+        );
+    }
+}
@@ -2,18 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    types::{
-        api::plugins::participation::types::{Participation, ParticipationEventId, Participations, PARTICIPATION_TAG},
-        block::{
-            output::{
-                feature::{MetadataFeature, TagFeature},
-                BasicOutputBuilder, Feature,
-            },
-            payload::TaggedDataPayload,
-        },
-    },
+    types::api::plugins::participation::types::{Participation, ParticipationEventId, Participations},
     wallet::{
-        account::{types::Transaction, AccountHandle, TransactionOptions},
+        account::{types::Transaction, AccountHandle},
         Result,
     },
 };
@@ -31,6 +22,7 @@ impl AccountHandle {
     /// If event has expired, throws an error (do NOT remove previous votes).
     ///
     /// This is an add OR update function, not just add.
+    /// The vote is also added to the voting plan, see [`AccountHandle::apply_voting_plan()`].
     /// This should use regular client options, NOT specific node for the event.
     pub async fn vote(&self, event_id: Option<ParticipationEventId>, answers: Option<Vec<u8>>) -> Result<Transaction> {
         if let Some(event_id) = event_id {
@@ -51,7 +43,7 @@ impl AccountHandle {
         let output = voting_output.output.as_basic();
 
         // Updates or creates participation.
-        let participation_bytes = match output.features().metadata() {
+        let participations = match output.features().metadata() {
             Some(metadata) => {
                 let mut participations = Participations::from_bytes(&mut metadata.data())?;
 
@@ -61,7 +53,7 @@ impl AccountHandle {
                 if let Some(event_id) = event_id {
                     participations.add_or_replace(Participation {
                         event_id,
-                        answers: answers.unwrap_or_default(),
+                        answers: answers.clone().unwrap_or_default(),
                     });
                 }
 
@@ -72,38 +64,29 @@ impl AccountHandle {
                     Participations {
                         participations: vec![Participation {
                             event_id,
-                            answers: answers.unwrap_or_default(),
+                            answers: answers.clone().unwrap_or_default(),
                         }],
                     }
                 } else {
                     return Err(crate::wallet::Error::Voting("No event to vote for".to_string()));
                 }
             }
+        };
+
+        let transaction = self.send_participations(&voting_output, participations).await?;
+
+        // Keep the vote in the voting plan, so it's cast again when the voting output gets re-created.
+        if let Some(event_id) = event_id {
+            self.update_voting_plan(|voting_plan| {
+                voting_plan.add_or_replace(Participation {
+                    event_id,
+                    answers: answers.unwrap_or_default(),
+                })
+            })
+            .await?;
         }
-        .to_bytes()?;
-
-        let new_output = BasicOutputBuilder::from(output)
-            // TODO maybe replace ?
-            .with_features(vec![
-                Feature::Tag(TagFeature::new(PARTICIPATION_TAG.as_bytes().to_vec())?),
-                Feature::Metadata(MetadataFeature::new(participation_bytes.clone())?),
-            ])
-            .finish_output(self.client().get_token_supply().await?)?;
-
-        self.send(
-            vec![new_output],
-            Some(TransactionOptions {
-                // Only use previous voting output as input.
-                custom_inputs: Some(vec![voting_output.output_id]),
-                mandatory_inputs: Some(vec![voting_output.output_id]),
-                tagged_data_payload: Some(TaggedDataPayload::new(
-                    PARTICIPATION_TAG.as_bytes().to_vec(),
-                    participation_bytes,
-                )?),
-                ..Default::default()
-            }),
-        )
-        .await
+
+        Ok(transaction)
     }
 
     /// Removes metadata corresponding to a given (voting) event ID from any outputs that contains it.
@@ -114,6 +97,7 @@ impl AccountHandle {
     /// TODO: is it really doing that ?
     /// If multiple outputs contain metadata for this event, removes all of them.
     /// If NOT already voting for this event, throws an error (e.g. output with this event ID not found).
+    /// The event is also removed from the voting plan.
     pub async fn stop_participating(&self, event_id: ParticipationEventId) -> Result<Transaction> {
         let voting_output = self
             .get_voting_output()
@@ -122,7 +106,7 @@ impl AccountHandle {
         let output = voting_output.output.as_basic();
 
         // Removes participation.
-        let participation_bytes = match output.features().metadata() {
+        let participations = match output.features().metadata() {
             Some(metadata) => {
                 let mut participations = Participations::from_bytes(&mut metadata.data())?;
 
@@ -149,30 +133,15 @@ impl AccountHandle {
                     "currently not participating for {event_id}"
                 )));
             }
-        }
-        .to_bytes()?;
-
-        let new_output = BasicOutputBuilder::from(output)
-            // TODO maybe replace ?
-            .with_features(vec![
-                Feature::Tag(TagFeature::new(PARTICIPATION_TAG.as_bytes().to_vec())?),
-                Feature::Metadata(MetadataFeature::new(participation_bytes.clone())?),
-            ])
-            .finish_output(self.client().get_token_supply().await?)?;
-
-        self.send(
-            vec![new_output],
-            Some(TransactionOptions {
-                // Only use previous voting output as input.
-                custom_inputs: Some(vec![voting_output.output_id]),
-                mandatory_inputs: Some(vec![voting_output.output_id]),
-                tagged_data_payload: Some(TaggedDataPayload::new(
-                    PARTICIPATION_TAG.as_bytes().to_vec(),
-                    participation_bytes,
-                )?),
-                ..Default::default()
-            }),
-        )
-        .await
+        };
+
+        let transaction = self.send_participations(&voting_output, participations).await?;
+
+        self.update_voting_plan(|voting_plan| {
+            voting_plan.remove(&event_id);
+        })
+        .await?;
+
+        Ok(transaction)
     }
 }
@@ -0,0 +1,306 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        api::plugins::participation::{
+            responses::TrackedParticipation,
+            types::{Participation, ParticipationEventId, ParticipationEventStatus, Participations, PARTICIPATION_TAG},
+        },
+        block::{
+            output::{
+                feature::{MetadataFeature, TagFeature},
+                BasicOutputBuilder, Feature, OutputId,
+            },
+            payload::TaggedDataPayload,
+        },
+    },
+    wallet::{
+        account::{types::Transaction, AccountHandle, OutputData, TransactionOptions},
+        Result,
+    },
+};
+
+/// The votes an account keeps cast across multiple events.
+///
+/// The voting plan is stored locally and cast again whenever the voting output gets re-created, e.g. when the voting
+/// power changes. Events that ended are removed from it when it's applied.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct VotingPlan {
+    /// The votes, at most one per event.
+    pub votes: Vec<Participation>,
+}
+
+impl VotingPlan {
+    /// Replaces the answers if there is already a vote for the event or adds the vote.
+    pub(crate) fn add_or_replace(&mut self, participation: Participation) {
+        match self.votes.iter_mut().find(|p| p.event_id == participation.event_id) {
+            Some(existing) => existing.answers = participation.answers,
+            None => self.votes.push(participation),
+        }
+    }
+
+    /// Removes the vote for the event, returns whether there was one.
+    pub(crate) fn remove(&mut self, event_id: &ParticipationEventId) -> bool {
+        let length_before = self.votes.len();
+        self.votes.retain(|p| &p.event_id != event_id);
+        length_before != self.votes.len()
+    }
+
+    /// Adds the votes of the plan to the participations, replacing previous answers for the same events.
+    pub(crate) fn cast(&self, participations: &mut Participations) {
+        for vote in &self.votes {
+            participations.add_or_replace(vote.clone());
+        }
+    }
+}
+
+/// The history of an account's participation in an event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationEventHistory {
+    /// The event id.
+    pub event_id: ParticipationEventId,
+    /// The latest status of the event with the tally of its answers.
+    pub status: ParticipationEventStatus,
+    /// The participations of the account's outputs, tracked by the participation plugin.
+    pub participations: HashMap<OutputId, TrackedParticipation>,
+}
+
+impl AccountHandle {
+    /// Returns the voting plan of the account.
+    pub async fn get_voting_plan(&self) -> Result<VotingPlan> {
+        let account_index = self.read().await.index;
+        self.storage_manager.lock().await.get_voting_plan(account_index).await
+    }
+
+    /// Votes for multiple events in a single transaction and adds the votes to the voting plan.
+    ///
+    /// Votes for other events are kept. If an event has ended, throws an error.
+    pub async fn vote_for_events(&self, votes: Vec<Participation>) -> Result<Transaction> {
+        if votes.is_empty() {
+            return Err(crate::wallet::Error::Voting("No event to vote for".to_string()));
+        }
+        for vote in &votes {
+            if self.get_participation_event_status(&vote.event_id).await?.status() == "ended" {
+                return Err(crate::wallet::Error::Voting(format!(
+                    "event {} already ended",
+                    vote.event_id
+                )));
+            }
+        }
+
+        let voting_output = self
+            .get_voting_output()
+            .await?
+            .ok_or_else(|| crate::wallet::Error::Voting("No unspent voting output found".to_string()))?;
+
+        let mut voting_plan = self.get_voting_plan().await?;
+        for vote in votes.iter().cloned() {
+            voting_plan.add_or_replace(vote);
+        }
+
+        let mut participations = current_participations(&voting_output);
+        voting_plan.cast(&mut participations);
+        self.remove_ended_participation_events(&mut participations).await?;
+
+        let transaction = self.send_participations(&voting_output, participations).await?;
+        self.update_voting_plan(|voting_plan| {
+            for vote in votes {
+                voting_plan.add_or_replace(vote);
+            }
+        })
+        .await?;
+
+        Ok(transaction)
+    }
+
+    /// Brings the voting output in line with the voting plan.
+    ///
+    /// Removes events that ended from the voting plan and the voting output and casts votes of the plan that aren't in
+    /// the voting output yet. Returns `None` if the voting plan is empty, there is no voting output or the votes are
+    /// already up to date.
+    /// For staking events that ended, a `StakingEnded` wallet event with the rewards of the account is emitted.
+    pub async fn apply_voting_plan(&self) -> Result<Option<Transaction>> {
+        log::debug!("[apply_voting_plan]");
+        let mut voting_plan = self.get_voting_plan().await?;
+        if voting_plan.votes.is_empty() {
+            return Ok(None);
+        }
+
+        let mut ended_events = Vec::new();
+        for vote in &voting_plan.votes {
+            if self.get_participation_event_status(&vote.event_id).await?.status() == "ended" {
+                ended_events.push(vote.event_id);
+            }
+        }
+        if !ended_events.is_empty() {
+            for event_id in &ended_events {
+                voting_plan.remove(event_id);
+                #[cfg(feature = "events")]
+                if let Err(err) = self.emit_staking_ended(event_id).await {
+                    log::debug!("[apply_voting_plan] couldn't get staking rewards for {event_id}: {err}");
+                }
+            }
+            // Only remove the ended events from the stored voting plan, votes may have been added in the meantime
+            self.update_voting_plan(|stored_voting_plan| {
+                for event_id in &ended_events {
+                    stored_voting_plan.remove(event_id);
+                }
+            })
+            .await?;
+        }
+
+        let voting_output = match self.get_voting_output().await? {
+            Some(voting_output) => voting_output,
+            None => return Ok(None),
+        };
+        // A transaction re-creating the voting output is still pending.
+        if self.read().await.locked_outputs.contains(&voting_output.output_id) {
+            return Ok(None);
+        }
+
+        let current = current_participations(&voting_output);
+        let mut participations = current.clone();
+        for event_id in &ended_events {
+            participations.remove(event_id);
+        }
+        voting_plan.cast(&mut participations);
+        self.remove_ended_participation_events(&mut participations).await?;
+
+        if participations == current {
+            return Ok(None);
+        }
+
+        log::debug!(
+            "[apply_voting_plan] casting {} participations",
+            participations.participations.len()
+        );
+        self.send_participations(&voting_output, participations).await.map(Some)
+    }
+
+    /// Returns the history of the account's participation in an event, with the tally of the event and the
+    /// participations of the account's outputs, as tracked by the participation plugin.
+    pub async fn get_participation_event_history(
+        &self,
+        event_id: ParticipationEventId,
+    ) -> Result<ParticipationEventHistory> {
+        let status = self.get_participation_event_status(&event_id).await?;
+        let participations = self
+            .get_participation_overview(Some(vec![event_id]))
+            .await?
+            .participations
+            .remove(&event_id)
+            .unwrap_or_default();
+
+        Ok(ParticipationEventHistory {
+            event_id,
+            status,
+            participations,
+        })
+    }
+
+    /// Updates the voting plan of the account with the provided closure and stores it.
+    pub(crate) async fn update_voting_plan(&self, f: impl FnOnce(&mut VotingPlan) + Send) -> Result<()> {
+        let account_index = self.read().await.index;
+        // Hold the storage lock from reading to storing the voting plan, so concurrent updates aren't lost
+        let mut storage_manager = self.storage_manager.lock().await;
+        let mut voting_plan = storage_manager.get_voting_plan(account_index).await?;
+        f(&mut voting_plan);
+        storage_manager.set_voting_plan(account_index, &voting_plan).await
+    }
+
+    /// Re-creates the voting output with the provided participations.
+    pub(crate) async fn send_participations(
+        &self,
+        voting_output: &OutputData,
+        participations: Participations,
+    ) -> Result<Transaction> {
+        let participation_bytes = participations.to_bytes()?;
+
+        let new_output = BasicOutputBuilder::from(voting_output.output.as_basic())
+            .with_features(vec![
+                Feature::Tag(TagFeature::new(PARTICIPATION_TAG.as_bytes().to_vec())?),
+                Feature::Metadata(MetadataFeature::new(participation_bytes.clone())?),
+            ])
+            .finish_output(self.client().get_token_supply().await?)?;
+
+        self.send(
+            vec![new_output],
+            Some(TransactionOptions {
+                // Only use previous voting output as input.
+                custom_inputs: Some(vec![voting_output.output_id]),
+                mandatory_inputs: Some(vec![voting_output.output_id]),
+                tagged_data_payload: Some(TaggedDataPayload::new(
+                    PARTICIPATION_TAG.as_bytes().to_vec(),
+                    participation_bytes,
+                )?),
+                ..Default::default()
+            }),
+        )
+        .await
+    }
+}
+
+/// Returns the participations in the metadata of the voting output, empty if there are none.
+fn current_participations(voting_output: &OutputData) -> Participations {
+    voting_output
+        .output
+        .features()
+        .and_then(|f| f.metadata())
+        .and_then(|metadata| Participations::from_bytes(&mut metadata.data()).ok())
+        .unwrap_or(Participations {
+            participations: Vec::new(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn voting_plan_cast() {
+        let event_a = ParticipationEventId::new([1; 32]);
+        let event_b = ParticipationEventId::new([2; 32]);
+
+        let mut voting_plan = VotingPlan::default();
+        voting_plan.add_or_replace(Participation {
+            event_id: event_a,
+            answers: vec![0],
+        });
+        voting_plan.add_or_replace(Participation {
+            event_id: event_b,
+            answers: vec![1, 2],
+        });
+        voting_plan.add_or_replace(Participation {
+            event_id: event_a,
+            answers: vec![1],
+        });
+        assert_eq!(voting_plan.votes.len(), 2);
+
+        let mut participations = Participations {
+            participations: vec![Participation {
+                event_id: event_b,
+                answers: vec![0, 0],
+            }],
+        };
+        voting_plan.cast(&mut participations);
+        assert_eq!(participations.participations.len(), 2);
+        assert!(participations.participations.contains(&Participation {
+            event_id: event_a,
+            answers: vec![1],
+        }));
+        assert!(participations.participations.contains(&Participation {
+            event_id: event_b,
+            answers: vec![1, 2],
+        }));
+
+        assert!(voting_plan.remove(&event_b));
+        assert!(!voting_plan.remove(&event_b));
+        assert_eq!(voting_plan.votes.len(), 1);
+    }
+}
@@ -39,6 +39,7 @@ impl AccountHandle {
     /// cached event information, checks event milestones in there against latest network milestone).
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then increases again immediately after).
+    /// The votes of the voting plan are cast again with the new voting output.
     pub async fn increase_voting_power(&self, amount: u64) -> Result<Transaction> {
         let token_supply = self.client().get_token_supply().await?;
 
@@ -62,8 +63,8 @@ impl AccountHandle {
                     }),
                 )
             }
-            None => (
-                BasicOutputBuilder::new_with_amount(amount)?
+            None => {
+                let new_output = BasicOutputBuilder::new_with_amount(amount)?
                     .add_unlock_condition(AddressUnlockCondition::new(
                         self.public_addresses()
                             .await
@@ -73,9 +74,25 @@ impl AccountHandle {
                             .inner,
                     ))
                     .add_feature(TagFeature::new(PARTICIPATION_TAG.as_bytes().to_vec())?)
-                    .finish_output(token_supply)?,
-                None,
-            ),
+                    .finish_output(token_supply)?;
+
+                if self.get_voting_plan().await?.votes.is_empty() {
+                    (new_output, None)
+                } else {
+                    // Cast the votes of the voting plan right away.
+                    let (new_output, tagged_data_payload) = self
+                        .new_voting_output_and_tagged_data(new_output.as_basic(), amount, token_supply)
+                        .await?;
+
+                    (
+                        new_output,
+                        Some(TransactionOptions {
+                            tagged_data_payload: Some(tagged_data_payload),
+                            ..Default::default()
+                        }),
+                    )
+                }
+            }
         };
 
         self.send(vec![new_output], tx_options).await
@@ -90,6 +107,8 @@ impl AccountHandle {
     /// milestones in there against latest network milestone).
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then decreases immediately after).
+    /// Unless the whole voting power is removed, the votes of the voting plan are cast again with the new voting
+    /// output.
     pub async fn decrease_voting_power(&self, amount: u64) -> Result<Transaction> {
         let token_supply = self.client().get_token_supply().await?;
         let current_output_data = self
@@ -137,8 +156,20 @@ impl AccountHandle {
     ) -> Result<(Output, TaggedDataPayload)> {
         let mut output_builder = BasicOutputBuilder::from(output).with_amount(amount)?;
         let mut participation_bytes = output.features().metadata().map(|m| m.data()).unwrap_or(&[]);
+        let voting_plan = self.get_voting_plan().await?;
+
+        let participations = match Participations::from_bytes(&mut participation_bytes) {
+            Ok(participations) => Some(participations),
+            // Without previous participations, the votes of the voting plan can still be cast.
+            Err(_) if !voting_plan.votes.is_empty() => Some(Participations {
+                participations: Vec::new(),
+            }),
+            Err(_) => None,
+        };
 
-        let participation_bytes = if let Ok(mut participations) = Participations::from_bytes(&mut participation_bytes) {
+        let participation_bytes = if let Some(mut participations) = participations {
+            // Cast the votes of the voting plan again, so a new voting output keeps voting for them.
+            voting_plan.cast(&mut participations);
             // Remove ended participations.
             self.remove_ended_participation_events(&mut participations).await?;
 
@@ -4,11 +4,38 @@
 use std::collections::HashSet;
 
 use crate::{
-    types::block::output::{FoundryId, Output},
-    wallet::{account::handle::AccountHandle, task},
+    types::block::output::{FoundryId, Output, TokenId},
+    wallet::{
+        account::{handle::AccountHandle, types::Irc30Metadata},
+        task,
+    },
 };
 
 impl AccountHandle {
+    /// Get the IRC30 metadata of a native token from the foundries cached in the account, an unknown foundry is
+    /// requested from the node and cached first. Returns `None` if the foundry doesn't exist or has no metadata.
+    pub async fn native_token_metadata(&self, token_id: TokenId) -> crate::wallet::Result<Option<Irc30Metadata>> {
+        log::debug!("[native_token_metadata] {token_id}");
+        let foundry_id = FoundryId::from(token_id);
+
+        if !self.read().await.native_token_foundries().contains_key(&foundry_id) {
+            self.request_and_store_foundry_outputs(HashSet::from([foundry_id]))
+                .await?;
+        }
+
+        let metadata = self
+            .read()
+            .await
+            .native_token_foundries()
+            .get(&foundry_id)
+            .and_then(|foundry| foundry.immutable_features().metadata())
+            .cloned();
+
+        metadata
+            .map(|metadata| Irc30Metadata::from_bytes(metadata.data()))
+            .transpose()
+    }
+
     pub(crate) async fn request_and_store_foundry_outputs(
         &self,
         foundry_ids: HashSet<FoundryId>,
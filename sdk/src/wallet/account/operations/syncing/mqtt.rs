@@ -0,0 +1,48 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::mqtt::{Topic, TopicEvent},
+    wallet::account::{handle::AccountHandle, operations::syncing::SyncOptions},
+};
+
+impl AccountHandle {
+    /// Returns the MQTT topics that notify about changes of the account: outputs unlockable by its addresses and the
+    /// inclusion of its pending transactions.
+    pub(crate) async fn mqtt_syncing_topics(&self) -> crate::wallet::Result<Vec<Topic>> {
+        let account = self.read().await;
+        let topics = account
+            .public_addresses()
+            .iter()
+            .chain(account.internal_addresses())
+            .map(|address| format!("outputs/unlock/address/{}", address.address().to_bech32()))
+            .chain(
+                account
+                    .pending_transactions()
+                    .iter()
+                    .map(|transaction_id| format!("transactions/{transaction_id}/included-block")),
+            )
+            .map(Topic::try_new)
+            .collect::<Result<Vec<_>, _>>();
+        drop(account);
+
+        Ok(topics.map_err(crate::client::Error::from)?)
+    }
+
+    /// Syncs the part of the account that is affected by an MQTT event.
+    pub(crate) async fn sync_mqtt_event(&self, event: &TopicEvent, options: &SyncOptions) -> crate::wallet::Result<()> {
+        log::debug!("[SYNC] syncing from MQTT event on {}", event.topic);
+        let mut options = SyncOptions {
+            force_syncing: true,
+            ..options.clone()
+        };
+        // Only the address with the new or spent output needs to be synced, for an included transaction the pending
+        // transactions are checked during every sync.
+        if let Some(bech32_address) = event.topic.strip_prefix("outputs/unlock/address/") {
+            options.addresses = vec![bech32_address.to_string()];
+        }
+        self.sync(Some(options)).await?;
+
+        Ok(())
+    }
+}
@@ -56,6 +56,11 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(rename = "syncNativeTokenFoundries", default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Apply the voting plan of an account after it synced in the background syncing, which casts its votes again and
+    /// stops participating in ended events. Sends transactions, so it's disabled by default.
+    #[cfg(feature = "participation")]
+    #[serde(rename = "applyVotingPlan", default)]
+    pub apply_voting_plan: bool,
 }
 
 fn default_address_start_index() -> u32 {
@@ -96,6 +101,8 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            #[cfg(feature = "participation")]
+            apply_voting_plan: false,
         }
     }
 }
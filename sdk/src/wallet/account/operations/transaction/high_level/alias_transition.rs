@@ -0,0 +1,376 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Address,
+        output::{
+            feature::MetadataFeature,
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutput, AliasOutputBuilder, AliasTransition, Output, OutputId, Rent,
+        },
+        DtoError,
+    },
+    wallet::{
+        account::{handle::AccountHandle, types::Transaction, TransactionOptions},
+        Error,
+    },
+};
+
+/// Changes of an alias output for `transition_alias_state()`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AliasStateTransitionOptions {
+    /// New alias state metadata, an empty state metadata clears it
+    #[serde(rename = "stateMetadata")]
+    pub state_metadata: Option<Vec<u8>>,
+    /// New amount of the alias output, raised to the minimum storage deposit if it's lower
+    pub amount: Option<u64>,
+}
+
+/// Dto for `AliasStateTransitionOptions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AliasStateTransitionOptionsDto {
+    /// New alias state metadata, hex encoded bytes
+    #[serde(rename = "stateMetadata")]
+    pub state_metadata: Option<String>,
+    /// New amount of the alias output
+    pub amount: Option<String>,
+}
+
+impl TryFrom<&AliasStateTransitionOptionsDto> for AliasStateTransitionOptions {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: &AliasStateTransitionOptionsDto) -> crate::wallet::Result<Self> {
+        Ok(Self {
+            state_metadata: match &value.state_metadata {
+                Some(metadata) => {
+                    Some(prefix_hex::decode(metadata).map_err(|_| DtoError::InvalidField("state_metadata"))?)
+                }
+                None => None,
+            },
+            amount: match &value.amount {
+                Some(amount) => {
+                    Some(u64::from_str(amount).map_err(|_| crate::client::Error::InvalidAmount(amount.clone()))?)
+                }
+                None => None,
+            },
+        })
+    }
+}
+
+/// Changes of an alias output for `transition_alias_governance()`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AliasGovernanceTransitionOptions {
+    /// Bech32 encoded address of the new state controller
+    #[serde(rename = "stateControllerAddress")]
+    pub state_controller_address: Option<String>,
+    /// Bech32 encoded address of the new governor
+    #[serde(rename = "governorAddress")]
+    pub governor_address: Option<String>,
+    /// New alias metadata, an empty metadata removes the metadata feature
+    pub metadata: Option<Vec<u8>>,
+}
+
+/// Dto for `AliasGovernanceTransitionOptions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AliasGovernanceTransitionOptionsDto {
+    /// Bech32 encoded address of the new state controller
+    #[serde(rename = "stateControllerAddress")]
+    pub state_controller_address: Option<String>,
+    /// Bech32 encoded address of the new governor
+    #[serde(rename = "governorAddress")]
+    pub governor_address: Option<String>,
+    /// New alias metadata, hex encoded bytes
+    pub metadata: Option<String>,
+}
+
+impl TryFrom<&AliasGovernanceTransitionOptionsDto> for AliasGovernanceTransitionOptions {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: &AliasGovernanceTransitionOptionsDto) -> crate::wallet::Result<Self> {
+        Ok(Self {
+            state_controller_address: value.state_controller_address.clone(),
+            governor_address: value.governor_address.clone(),
+            metadata: match &value.metadata {
+                Some(metadata) => Some(prefix_hex::decode(metadata).map_err(|_| DtoError::InvalidField("metadata"))?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl AccountHandle {
+    /// Function to do a state transition of an alias output, which increments its state index. It has to be signed
+    /// by the state controller.
+    /// ```ignore
+    /// let state_options = AliasStateTransitionOptions {
+    ///     state_metadata: Some(b"new alias state".to_vec()),
+    ///     amount: None,
+    /// };
+    ///
+    /// let transaction = account.transition_alias_state(alias_id, state_options, None).await?;
+    /// ```
+    pub async fn transition_alias_state(
+        &self,
+        alias_id: AliasId,
+        state_options: AliasStateTransitionOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_transition_alias_state(alias_id, state_options, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    pub(crate) async fn prepare_transition_alias_state(
+        &self,
+        alias_id: AliasId,
+        state_options: AliasStateTransitionOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_transition_alias_state");
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+
+        let (output_id, alias_output) = self.unspent_alias_output(alias_id).await?;
+
+        let mut alias_output_builder = AliasOutputBuilder::from(&alias_output)
+            .with_alias_id(alias_id)
+            .with_state_index(alias_output.state_index() + 1);
+        if let Some(state_metadata) = state_options.state_metadata {
+            alias_output_builder = alias_output_builder.with_state_metadata(state_metadata);
+        }
+        if let Some(amount) = state_options.amount {
+            alias_output_builder = alias_output_builder.with_amount(amount)?;
+        }
+
+        // A larger state metadata can require a higher storage deposit
+        let mut output = alias_output_builder.clone().finish_output(token_supply)?;
+        let required_storage_deposit = output.rent_cost(&rent_structure);
+        if output.amount() < required_storage_deposit {
+            output = alias_output_builder
+                .with_amount(required_storage_deposit)?
+                .finish_output(token_supply)?;
+        }
+
+        validate_alias_transition(
+            &alias_id,
+            &output_id,
+            &alias_output,
+            output.as_alias(),
+            AliasTransition::State,
+        )?;
+
+        self.prepare_transaction(vec![output], options).await
+    }
+
+    /// Function to do a governance transition of an alias output, which keeps its state index and can rotate the
+    /// state controller and governor addresses or update the metadata feature. It has to be signed by the governor.
+    /// ```ignore
+    /// let governance_options = AliasGovernanceTransitionOptions {
+    ///     state_controller_address: Some("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string()),
+    ///     governor_address: None,
+    ///     metadata: None,
+    /// };
+    ///
+    /// let transaction = account.transition_alias_governance(alias_id, governance_options, None).await?;
+    /// ```
+    pub async fn transition_alias_governance(
+        &self,
+        alias_id: AliasId,
+        governance_options: AliasGovernanceTransitionOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_transition_alias_governance(alias_id, governance_options, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    pub(crate) async fn prepare_transition_alias_governance(
+        &self,
+        alias_id: AliasId,
+        governance_options: AliasGovernanceTransitionOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_transition_alias_governance");
+        let token_supply = self.client.get_token_supply().await?;
+
+        let (output_id, alias_output) = self.unspent_alias_output(alias_id).await?;
+
+        let mut alias_output_builder = AliasOutputBuilder::from(&alias_output).with_alias_id(alias_id);
+        if let Some(bech32_address) = governance_options.state_controller_address {
+            let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(bech32_address)?;
+            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+            alias_output_builder =
+                alias_output_builder.replace_unlock_condition(StateControllerAddressUnlockCondition::new(address));
+        }
+        if let Some(bech32_address) = governance_options.governor_address {
+            let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(bech32_address)?;
+            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+            alias_output_builder =
+                alias_output_builder.replace_unlock_condition(GovernorAddressUnlockCondition::new(address));
+        }
+        if let Some(metadata) = governance_options.metadata {
+            alias_output_builder = if metadata.is_empty() {
+                alias_output_builder.with_features(
+                    alias_output
+                        .features()
+                        .iter()
+                        .filter(|feature| !feature.is_metadata())
+                        .cloned(),
+                )
+            } else {
+                alias_output_builder.replace_feature(MetadataFeature::new(metadata)?)
+            };
+        }
+
+        let output = alias_output_builder.finish_output(token_supply)?;
+
+        validate_alias_transition(
+            &alias_id,
+            &output_id,
+            &alias_output,
+            output.as_alias(),
+            AliasTransition::Governance,
+        )?;
+
+        self.prepare_transaction(vec![output], options).await
+    }
+
+    /// Get the output id and the unspent alias output with the alias id.
+    async fn unspent_alias_output(&self, alias_id: AliasId) -> crate::wallet::Result<(OutputId, AliasOutput)> {
+        self.read()
+            .await
+            .unspent_outputs()
+            .values()
+            .find_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output) if alias_output.alias_id_non_null(&output_data.output_id) == alias_id => {
+                    Some((output_data.output_id, alias_output.clone()))
+                }
+                _ => None,
+            })
+            .ok_or_else(|| Error::InvalidAliasTransition(format!("alias output {alias_id} not found")))
+    }
+}
+
+/// Checks that the next alias output only changes the fields the transition is allowed to change, so the transaction
+/// isn't rejected by the node.
+pub(crate) fn validate_alias_transition(
+    alias_id: &AliasId,
+    output_id: &OutputId,
+    current: &AliasOutput,
+    next: &AliasOutput,
+    transition: AliasTransition,
+) -> crate::wallet::Result<()> {
+    let invalid = |reason: &str| {
+        Err(Error::InvalidAliasTransition(format!(
+            "{transition} transition {reason}"
+        )))
+    };
+
+    if current.alias_id_non_null(output_id) != *alias_id || next.alias_id() != alias_id {
+        return invalid("can't change the alias id");
+    }
+    if current.immutable_features() != next.immutable_features() {
+        return invalid("can't change the immutable features");
+    }
+
+    match transition {
+        AliasTransition::State => {
+            if current.state_index().checked_add(1) != Some(next.state_index()) {
+                return invalid("has to increment the state index");
+            }
+            if current.state_controller_address() != next.state_controller_address()
+                || current.governor_address() != next.governor_address()
+            {
+                return invalid("can't change the state controller or governor address");
+            }
+            if current.features().metadata() != next.features().metadata() {
+                return invalid("can't change the metadata feature");
+            }
+            if next.foundry_counter() < current.foundry_counter() {
+                return invalid("can't decrease the foundry counter");
+            }
+        }
+        AliasTransition::Governance => {
+            if current.state_index() != next.state_index() {
+                return invalid("can't change the state index");
+            }
+            if current.amount() != next.amount()
+                || current.native_tokens() != next.native_tokens()
+                || current.state_metadata() != next.state_metadata()
+                || current.foundry_counter() != next.foundry_counter()
+            {
+                return invalid("can't change the amount, native tokens, state metadata or foundry counter");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{address::Ed25519Address, output::feature::IssuerFeature, protocol::protocol_parameters};
+
+    const ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+    const OUTPUT_ID: &str = "0x1e857d380f813d8035e487b6dfd2ff4740b6775273ba1b576f01381ba2a1a44c0000";
+
+    fn alias_output(alias_id: AliasId, state_index: u32) -> AliasOutputBuilder {
+        let address = Address::try_from_bech32(ADDRESS).unwrap();
+        AliasOutputBuilder::new_with_amount(1_000_000, alias_id)
+            .unwrap()
+            .with_state_index(state_index)
+            .with_foundry_counter(0)
+            .with_state_metadata(b"state".to_vec())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+            .add_immutable_feature(IssuerFeature::new(address))
+    }
+
+    #[test]
+    fn validate_state_and_governance_transitions() {
+        let token_supply = protocol_parameters().token_supply();
+        let output_id = OutputId::from_str(OUTPUT_ID).unwrap();
+        let alias_id = AliasId::from(&output_id);
+        let current = alias_output(AliasId::null(), 0).finish(token_supply).unwrap();
+        let other_address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let validate = |next: AliasOutputBuilder, transition| {
+            validate_alias_transition(
+                &alias_id,
+                &output_id,
+                &current,
+                &next.finish(token_supply).unwrap(),
+                transition,
+            )
+        };
+
+        let state = alias_output(alias_id, 1).with_state_metadata(b"new state".to_vec());
+        assert!(validate(state.clone(), AliasTransition::State).is_ok());
+        assert!(validate(state.clone(), AliasTransition::Governance).is_err());
+        assert!(validate(
+            state.replace_unlock_condition(GovernorAddressUnlockCondition::new(other_address)),
+            AliasTransition::State
+        )
+        .is_err());
+
+        let governance = alias_output(alias_id, 0)
+            .replace_unlock_condition(StateControllerAddressUnlockCondition::new(other_address))
+            .add_feature(MetadataFeature::new(b"metadata".to_vec()).unwrap());
+        assert!(validate(governance.clone(), AliasTransition::Governance).is_ok());
+        assert!(validate(governance.clone(), AliasTransition::State).is_err());
+        assert!(validate(
+            governance.with_state_metadata(b"new state".to_vec()),
+            AliasTransition::Governance
+        )
+        .is_err());
+        assert!(validate(alias_output(AliasId::null(), 0), AliasTransition::Governance).is_err());
+    }
+}
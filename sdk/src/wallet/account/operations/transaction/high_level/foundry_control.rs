@@ -0,0 +1,237 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::{Address, AliasAddress},
+        dto::U256Dto,
+        output::{dto::TokenIdDto, AliasId, FoundryId, Output, OutputId, TokenId, TokenScheme},
+    },
+    wallet::{
+        account::{
+            handle::AccountHandle,
+            operations::transaction::high_level::alias_transition::AliasGovernanceTransitionOptions,
+            types::Transaction, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A foundry controlled by an alias output of the account, returned by `controlled_foundries()`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ControlledFoundry {
+    /// The id of the foundry
+    pub foundry_id: FoundryId,
+    /// The id of the native token minted by the foundry
+    pub token_id: TokenId,
+    /// The id of the alias controlling the foundry
+    pub alias_id: AliasId,
+    /// The id of the foundry output
+    pub output_id: OutputId,
+    /// Amount of minted tokens minus the amount of melted tokens
+    pub circulating_supply: U256,
+    /// Amount of minted tokens
+    pub minted_tokens: U256,
+    /// Amount of melted tokens
+    pub melted_tokens: U256,
+    /// Maximum supply of tokens
+    pub maximum_supply: U256,
+}
+
+/// Dto for `ControlledFoundry`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlledFoundryDto {
+    /// The id of the foundry
+    pub foundry_id: FoundryId,
+    /// The id of the native token minted by the foundry
+    pub token_id: TokenIdDto,
+    /// The id of the alias controlling the foundry
+    pub alias_id: AliasId,
+    /// The id of the foundry output
+    pub output_id: OutputId,
+    /// Amount of minted tokens minus the amount of melted tokens
+    pub circulating_supply: U256Dto,
+    /// Amount of minted tokens
+    pub minted_tokens: U256Dto,
+    /// Amount of melted tokens
+    pub melted_tokens: U256Dto,
+    /// Maximum supply of tokens
+    pub maximum_supply: U256Dto,
+}
+
+impl From<&ControlledFoundry> for ControlledFoundryDto {
+    fn from(value: &ControlledFoundry) -> Self {
+        Self {
+            foundry_id: value.foundry_id,
+            token_id: TokenIdDto::from(&value.token_id),
+            alias_id: value.alias_id,
+            output_id: value.output_id,
+            circulating_supply: U256Dto::from(&value.circulating_supply),
+            minted_tokens: U256Dto::from(&value.minted_tokens),
+            melted_tokens: U256Dto::from(&value.melted_tokens),
+            maximum_supply: U256Dto::from(&value.maximum_supply),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Returns the foundries whose controlling alias output is owned by the account, so their native token supply
+    /// can be increased or decreased, ordered by foundry id.
+    pub async fn controlled_foundries(&self) -> crate::wallet::Result<Vec<ControlledFoundry>> {
+        let account = self.read().await;
+        let alias_ids = account
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output) => Some(alias_output.alias_id_non_null(&output_data.output_id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut foundries = account
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Foundry(foundry_output) if alias_ids.contains(foundry_output.alias_address().alias_id()) => {
+                    let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+                    Some(ControlledFoundry {
+                        foundry_id: foundry_output.id(),
+                        token_id: foundry_output.token_id(),
+                        alias_id: *foundry_output.alias_address().alias_id(),
+                        output_id: output_data.output_id,
+                        circulating_supply: token_scheme.circulating_supply(),
+                        minted_tokens: token_scheme.minted_tokens(),
+                        melted_tokens: token_scheme.melted_tokens(),
+                        maximum_supply: token_scheme.maximum_supply(),
+                    })
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        drop(account);
+        foundries.sort_by_key(|foundry| foundry.foundry_id);
+
+        Ok(foundries)
+    }
+
+    /// Function to hand over the control of a foundry to another address, by setting it as state controller and
+    /// governor of the controlling alias. The alias controls all of its foundries, so they are all transferred.
+    /// ```ignore
+    /// let transaction = account_handle
+    ///     .transfer_foundry_control(
+    ///         foundry_id,
+    ///         "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn transfer_foundry_control(
+        &self,
+        foundry_id: FoundryId,
+        bech32_address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_transfer_foundry_control(foundry_id, bech32_address, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    pub(crate) async fn prepare_transfer_foundry_control(
+        &self,
+        foundry_id: FoundryId,
+        bech32_address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_transfer_foundry_control");
+        let alias_id = self.controlling_alias_id(foundry_id, &bech32_address).await?;
+
+        self.prepare_transition_alias_governance(
+            alias_id,
+            AliasGovernanceTransitionOptions {
+                state_controller_address: Some(bech32_address.clone()),
+                governor_address: Some(bech32_address),
+                metadata: None,
+            },
+            options,
+        )
+        .await
+    }
+
+    /// Function to change the governor of the alias controlling a foundry. The state controller stays the same and
+    /// can still manage the native token supply, while the new governor can later rotate the state controller.
+    /// ```ignore
+    /// let transaction = account_handle
+    ///     .change_foundry_governor(
+    ///         foundry_id,
+    ///         "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn change_foundry_governor(
+        &self,
+        foundry_id: FoundryId,
+        governor_bech32_address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_change_foundry_governor(foundry_id, governor_bech32_address, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    pub(crate) async fn prepare_change_foundry_governor(
+        &self,
+        foundry_id: FoundryId,
+        governor_bech32_address: String,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_change_foundry_governor");
+        let alias_id = self.controlling_alias_id(foundry_id, &governor_bech32_address).await?;
+
+        self.prepare_transition_alias_governance(
+            alias_id,
+            AliasGovernanceTransitionOptions {
+                state_controller_address: None,
+                governor_address: Some(governor_bech32_address),
+                metadata: None,
+            },
+            options,
+        )
+        .await
+    }
+
+    /// Get the id of the alias controlling a foundry of the account and check that the new address doesn't lock the
+    /// alias forever.
+    async fn controlling_alias_id(
+        &self,
+        foundry_id: FoundryId,
+        bech32_address: &str,
+    ) -> crate::wallet::Result<AliasId> {
+        let alias_id = *foundry_id.alias_address().alias_id();
+
+        if !self
+            .controlled_foundries()
+            .await?
+            .iter()
+            .any(|foundry| foundry.foundry_id == foundry_id)
+        {
+            return Err(Error::FoundryNotControlled(foundry_id));
+        }
+
+        // An alias that controls itself can never be unlocked again
+        if Address::try_from_bech32(bech32_address)? == Address::Alias(AliasAddress::new(alias_id)) {
+            return Err(Error::InvalidAliasTransition(format!(
+                "alias {alias_id} can't be controlled by its own address"
+            )));
+        }
+
+        Ok(alias_id)
+    }
+}
@@ -14,10 +14,13 @@ use crate::{
         },
         DtoError,
     },
-    wallet::account::{
-        handle::AccountHandle,
-        types::{Transaction, TransactionDto},
-        TransactionOptions,
+    wallet::{
+        account::{
+            handle::AccountHandle,
+            types::{Irc30Metadata, Transaction, TransactionDto},
+            TransactionOptions,
+        },
+        Error,
     },
 };
 
@@ -53,13 +56,24 @@ pub struct NativeTokenOptionsDto {
     /// Foundry metadata, hex encoded bytes
     #[serde(rename = "foundryMetadata")]
     pub foundry_metadata: Option<String>,
+    /// IRC30 metadata to use as foundry metadata, can't be combined with `foundryMetadata`.
+    #[serde(rename = "irc30Metadata", default, skip_serializing_if = "Option::is_none")]
+    pub irc_30_metadata: Option<Irc30Metadata>,
+}
+
+impl NativeTokenOptions {
+    /// Sets the foundry metadata to the validated IRC30 metadata.
+    pub fn with_irc_30_metadata(mut self, irc_30_metadata: &Irc30Metadata) -> crate::wallet::Result<Self> {
+        self.foundry_metadata.replace(irc_30_metadata.to_bytes()?);
+        Ok(self)
+    }
 }
 
 impl TryFrom<&NativeTokenOptionsDto> for NativeTokenOptions {
     type Error = crate::wallet::Error;
 
     fn try_from(value: &NativeTokenOptionsDto) -> crate::wallet::Result<Self> {
-        Ok(Self {
+        let native_token_options = Self {
             alias_id: match &value.alias_id {
                 Some(alias_id) => Some(AliasId::try_from(alias_id)?),
                 None => None,
@@ -74,7 +88,15 @@ impl TryFrom<&NativeTokenOptionsDto> for NativeTokenOptions {
                 }
                 None => None,
             },
-        })
+        };
+
+        match &value.irc_30_metadata {
+            Some(_) if native_token_options.foundry_metadata.is_some() => Err(Error::InvalidIrc30Metadata(
+                "can't be combined with foundry metadata".to_string(),
+            )),
+            Some(irc_30_metadata) => native_token_options.with_irc_30_metadata(irc_30_metadata),
+            None => Ok(native_token_options),
+        }
     }
 }
 
@@ -15,7 +15,9 @@ use crate::{
         DtoError,
     },
     wallet::{
-        account::{handle::AccountHandle, operations::transaction::Transaction, TransactionOptions},
+        account::{
+            handle::AccountHandle, operations::transaction::Transaction, types::Irc27Metadata, TransactionOptions,
+        },
         Error,
     },
 };
@@ -56,13 +58,24 @@ pub struct NftOptionsDto {
     /// Immutable NFT metadata, hex encoded bytes.
     #[serde(rename = "immutableMetadata")]
     pub immutable_metadata: Option<String>,
+    /// IRC27 metadata to use as immutable NFT metadata, can't be combined with `immutableMetadata`.
+    #[serde(rename = "irc27Metadata", default, skip_serializing_if = "Option::is_none")]
+    pub irc_27_metadata: Option<Irc27Metadata>,
+}
+
+impl NftOptions {
+    /// Sets the immutable metadata to the validated IRC27 metadata.
+    pub fn with_irc_27_metadata(mut self, irc_27_metadata: &Irc27Metadata) -> crate::wallet::Result<Self> {
+        self.immutable_metadata.replace(irc_27_metadata.to_bytes()?);
+        Ok(self)
+    }
 }
 
 impl TryFrom<&NftOptionsDto> for NftOptions {
     type Error = crate::wallet::Error;
 
     fn try_from(value: &NftOptionsDto) -> crate::wallet::Result<Self> {
-        Ok(Self {
+        let nft_options = Self {
             address: value.address.clone(),
             sender: value.sender.clone(),
             metadata: match &value.metadata {
@@ -80,7 +93,15 @@ impl TryFrom<&NftOptionsDto> for NftOptions {
                 }
                 None => None,
             },
-        })
+        };
+
+        match &value.irc_27_metadata {
+            Some(_) if nft_options.immutable_metadata.is_some() => Err(Error::InvalidIrc27Metadata(
+                "can't be combined with immutable metadata".to_string(),
+            )),
+            Some(irc_27_metadata) => nft_options.with_irc_27_metadata(irc_27_metadata),
+            None => Ok(nft_options),
+        }
     }
 }
 
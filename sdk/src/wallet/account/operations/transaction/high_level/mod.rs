@@ -1,11 +1,16 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod alias_transition;
 pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
+pub(crate) mod foundry_control;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
 pub(crate) mod send_amount;
 pub(crate) mod send_micro_transaction;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod sub_wallet;
+pub(crate) mod swap;
+pub(crate) mod vesting;
@@ -0,0 +1,404 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Address,
+        output::{
+            dto::NativeTokenDto,
+            unlock_condition::{AddressUnlockCondition, UnlockCondition},
+            BasicOutputBuilder, NativeToken, NativeTokensBuilder, NftId, Output, OutputId,
+        },
+    },
+    wallet::{
+        account::{
+            handle::AccountHandle,
+            operations::transaction::{high_level::send_amount::AddressWithAmount, RemainderValueStrategy},
+            types::{
+                address::{AccountAddress, AddressWrapper},
+                Transaction,
+            },
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// The balance of the outputs owned by the address of an alias or NFT output of the account, which can be spent like
+/// a separate wallet with `send_from_sub_wallet()`
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SubWalletBalance {
+    /// Bech32 encoded address of the alias or NFT
+    pub address: String,
+    /// The id of the alias or NFT output, which is transitioned to unlock the outputs of the sub-wallet
+    pub owner_output_id: OutputId,
+    /// Amount of the basic outputs owned by the address
+    pub total: u64,
+    /// Amount of the basic outputs owned by the address without further unlock conditions, which can be spent
+    pub available: u64,
+    /// Native tokens of the basic outputs owned by the address
+    pub native_tokens: Vec<NativeToken>,
+    /// NFTs owned by the address
+    pub nfts: Vec<NftId>,
+    /// The ids of all outputs owned by the address
+    pub output_ids: Vec<OutputId>,
+}
+
+/// Dto for `SubWalletBalance`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubWalletBalanceDto {
+    /// Bech32 encoded address of the alias or NFT
+    pub address: String,
+    /// The id of the alias or NFT output
+    pub owner_output_id: OutputId,
+    /// Amount of the basic outputs owned by the address
+    pub total: String,
+    /// Amount of the basic outputs owned by the address which can be spent
+    pub available: String,
+    /// Native tokens of the basic outputs owned by the address
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// NFTs owned by the address
+    pub nfts: Vec<NftId>,
+    /// The ids of all outputs owned by the address
+    pub output_ids: Vec<OutputId>,
+}
+
+impl From<&SubWalletBalance> for SubWalletBalanceDto {
+    fn from(value: &SubWalletBalance) -> Self {
+        Self {
+            address: value.address.clone(),
+            owner_output_id: value.owner_output_id,
+            total: value.total.to_string(),
+            available: value.available.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            nfts: value.nfts.clone(),
+            output_ids: value.output_ids.clone(),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Returns the balances of the addresses of all alias and NFT outputs of the account. Outputs owned by these
+    /// addresses are only known if they were synced with `SyncOptions::alias` or `SyncOptions::nft`.
+    pub async fn sub_wallets(&self) -> crate::wallet::Result<Vec<SubWalletBalance>> {
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let account = self.read().await;
+
+        let mut sub_wallets = account
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output) => Some((
+                    Address::Alias(alias_output.alias_address(&output_data.output_id)),
+                    output_data.output_id,
+                )),
+                Output::Nft(nft_output) => Some((
+                    Address::Nft(nft_output.nft_address(&output_data.output_id)),
+                    output_data.output_id,
+                )),
+                _ => None,
+            })
+            .map(|(address, owner_output_id)| sub_wallet_balance(&account, &address, owner_output_id, &bech32_hrp))
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+        drop(account);
+        sub_wallets.sort_by_key(|sub_wallet| sub_wallet.owner_output_id);
+
+        Ok(sub_wallets)
+    }
+
+    /// Returns the balance of the address of an alias or NFT output of the account.
+    pub async fn sub_wallet_balance(&self, bech32_address: &str) -> crate::wallet::Result<SubWalletBalance> {
+        let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(bech32_address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+
+        let account = self.read().await;
+        sub_wallet_owner(&account, &address)
+            .and_then(|owner_output_id| sub_wallet_balance(&account, &address, owner_output_id, &bech32_hrp))
+    }
+
+    /// Function to send outputs with funds of the address of an alias or NFT output of the account. The inputs are
+    /// only taken from that address, all its available basic outputs are consolidated and the remainder stays on it.
+    /// The alias output gets a state transition and the NFT output is transitioned unchanged to unlock the inputs.
+    /// ```ignore
+    /// let outputs = vec![BasicOutputBuilder::new_with_amount(1_000_000)?
+    ///     .add_unlock_condition(AddressUnlockCondition::new(address))
+    ///     .finish_output(token_supply)?];
+    ///
+    /// let transaction = account_handle
+    ///     .send_from_sub_wallet(alias_bech32_address, outputs, None)
+    ///     .await?;
+    /// ```
+    pub async fn send_from_sub_wallet(
+        &self,
+        bech32_address: String,
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self
+            .prepare_send_from_sub_wallet(bech32_address, outputs, options)
+            .await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to send amounts with funds of the address of an alias or NFT output of the account, see
+    /// [AccountHandle.send_from_sub_wallet()](crate::account::handle::AccountHandle.send_from_sub_wallet).
+    /// ```ignore
+    /// let outputs = vec![AddressWithAmount {
+    ///     address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///     amount: 1_000_000,
+    /// }];
+    ///
+    /// let transaction = account_handle
+    ///     .send_amount_from_sub_wallet(alias_bech32_address, outputs, None)
+    ///     .await?;
+    /// ```
+    pub async fn send_amount_from_sub_wallet(
+        &self,
+        bech32_address: String,
+        addresses_with_amount: Vec<AddressWithAmount>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let token_supply = self.client.get_token_supply().await?;
+
+        let mut outputs = Vec::new();
+        for address_with_amount in addresses_with_amount {
+            let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(address_with_amount.address)?;
+            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+            outputs.push(
+                BasicOutputBuilder::new_with_amount(address_with_amount.amount)?
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)?,
+            )
+        }
+
+        self.send_from_sub_wallet(bech32_address, outputs, options).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [AccountHandle.send_from_sub_wallet()](crate::account::handle::AccountHandle.send_from_sub_wallet)
+    pub(crate) async fn prepare_send_from_sub_wallet(
+        &self,
+        bech32_address: String,
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_from_sub_wallet");
+        let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(&bech32_address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+
+        let inputs = sub_wallet_inputs(&*self.read().await, &address)?;
+
+        let remainder_address = AccountAddress {
+            address: AddressWrapper::new(address, bech32_hrp),
+            key_index: 0,
+            internal: false,
+            used: true,
+            label: None,
+        };
+        let options = TransactionOptions {
+            custom_inputs: Some(inputs.clone()),
+            remainder_value_strategy: RemainderValueStrategy::CustomAddress(remainder_address),
+            ..options.unwrap_or_default()
+        };
+
+        let prepared_transaction = self.prepare_transaction(outputs, Some(options)).await?;
+
+        // Input selection adds inputs of the account if the sub-wallet can't cover the outputs
+        let allowed_inputs = inputs.into_iter().collect::<HashSet<_>>();
+        if prepared_transaction
+            .inputs_data
+            .iter()
+            .any(|input| !allowed_inputs.contains(input.output_id()))
+        {
+            self.unlock_inputs(prepared_transaction.inputs_data).await?;
+            return Err(Error::InvalidSubWallet(format!(
+                "the outputs of {bech32_address} don't cover the transaction"
+            )));
+        }
+
+        Ok(prepared_transaction)
+    }
+}
+
+/// Get the id of the alias or NFT output of the account with the address.
+fn sub_wallet_owner(account: &Account, address: &Address) -> crate::wallet::Result<OutputId> {
+    account
+        .unspent_outputs()
+        .values()
+        .find_map(|output_data| match (&output_data.output, address) {
+            (Output::Alias(alias_output), Address::Alias(alias_address))
+                if alias_output.alias_id_non_null(&output_data.output_id) == *alias_address.alias_id() =>
+            {
+                Some(output_data.output_id)
+            }
+            (Output::Nft(nft_output), Address::Nft(nft_address))
+                if nft_output.nft_id_non_null(&output_data.output_id) == *nft_address.nft_id() =>
+            {
+                Some(output_data.output_id)
+            }
+            _ => None,
+        })
+        .ok_or_else(|| {
+            Error::InvalidSubWallet(
+                "the address isn't the address of an alias or NFT output of the account".to_string(),
+            )
+        })
+}
+
+/// Get the id of the alias or NFT output, which has to be an input to unlock the outputs of its address, followed by
+/// the available outputs of the address.
+fn sub_wallet_inputs(account: &Account, address: &Address) -> crate::wallet::Result<Vec<OutputId>> {
+    let mut inputs = vec![sub_wallet_owner(account, address)?];
+    inputs.extend(available_sub_wallet_outputs(account, address));
+    Ok(inputs)
+}
+
+/// Get the ids of the basic outputs owned by the address which only have an address unlock condition, ordered by
+/// output id.
+fn available_sub_wallet_outputs(account: &Account, address: &Address) -> Vec<OutputId> {
+    let mut output_ids = account
+        .unspent_outputs()
+        .values()
+        .filter(|output_data| {
+            output_data.output.is_basic()
+                && !account.locked_outputs().contains(&output_data.output_id)
+                && matches!(
+                    output_data.output.unlock_conditions().map(|unlock_conditions| unlock_conditions.as_ref()),
+                    Some([UnlockCondition::Address(unlock_condition)]) if unlock_condition.address() == address
+                )
+        })
+        .map(|output_data| output_data.output_id)
+        .collect::<Vec<_>>();
+    output_ids.sort();
+    output_ids
+}
+
+fn sub_wallet_balance(
+    account: &Account,
+    address: &Address,
+    owner_output_id: OutputId,
+    bech32_hrp: &str,
+) -> crate::wallet::Result<SubWalletBalance> {
+    let available_outputs = available_sub_wallet_outputs(account, address);
+
+    let mut total = 0;
+    let mut available = 0;
+    let mut native_tokens = NativeTokensBuilder::new();
+    let mut nfts = Vec::new();
+    let mut output_ids = Vec::new();
+
+    for output_data in account.unspent_outputs().values() {
+        let owned = matches!(
+            output_data.output.unlock_conditions().and_then(|unlock_conditions| unlock_conditions.address()),
+            Some(unlock_condition) if unlock_condition.address() == address
+        );
+        if !owned {
+            continue;
+        }
+
+        match &output_data.output {
+            Output::Basic(basic_output) => {
+                total += basic_output.amount();
+                if available_outputs.contains(&output_data.output_id) {
+                    available += basic_output.amount();
+                }
+                native_tokens.add_native_tokens(basic_output.native_tokens().clone())?;
+            }
+            Output::Nft(nft_output) => nfts.push(nft_output.nft_id_non_null(&output_data.output_id)),
+            _ => {}
+        }
+        output_ids.push(output_data.output_id);
+    }
+    nfts.sort();
+    output_ids.sort();
+
+    Ok(SubWalletBalance {
+        address: address.to_bech32(bech32_hrp),
+        owner_output_id,
+        total,
+        available,
+        native_tokens: native_tokens.finish_vec()?,
+        nfts,
+        output_ids,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{AliasAddress, Ed25519Address},
+            output::{
+                unlock_condition::{
+                    GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition, TimelockUnlockCondition,
+                },
+                AliasId, AliasOutputBuilder, NftOutputBuilder,
+            },
+            protocol::protocol_parameters,
+        },
+        wallet::account::test_utils::{account, output_data, output_id},
+    };
+
+    #[test]
+    fn sub_wallet_balance_of_alias_address() {
+        let token_supply = protocol_parameters().token_supply();
+        let ed25519_address = Address::from(Ed25519Address::new([1; 32]));
+        let output_data =
+            |output_index: u16, output: Output| output_data(output_index, output, ed25519_address, (10, 1000));
+
+        let alias_output = AliasOutputBuilder::new_with_amount(100_000, AliasId::null())
+            .unwrap()
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(ed25519_address))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(ed25519_address))
+            .finish_output(token_supply)
+            .unwrap();
+        let alias_output_id = output_data(0, alias_output.clone()).output_id;
+        let alias_address = Address::Alias(AliasAddress::new(AliasId::from(&alias_output_id)));
+        let basic_output = |amount, address, timelock: Option<u32>| {
+            let mut builder = BasicOutputBuilder::new_with_amount(amount)
+                .unwrap()
+                .add_unlock_condition(AddressUnlockCondition::new(address));
+            if let Some(timelock) = timelock {
+                builder = builder.add_unlock_condition(TimelockUnlockCondition::new(timelock).unwrap());
+            }
+            builder.finish_output(token_supply).unwrap()
+        };
+        let nft_output = NftOutputBuilder::new_with_amount(100_000, NftId::null())
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(alias_address))
+            .finish_output(token_supply)
+            .unwrap();
+
+        let outputs = [
+            output_data(0, alias_output),
+            output_data(1, basic_output(1_000_000, alias_address, None)),
+            output_data(2, basic_output(2_000_000, alias_address, Some(u32::MAX))),
+            output_data(3, nft_output),
+            output_data(4, basic_output(5_000_000, ed25519_address, None)),
+        ];
+        let nft_id = NftId::from(&outputs[3].output_id);
+        let account = account(outputs);
+
+        assert_eq!(sub_wallet_owner(&account, &alias_address).unwrap(), alias_output_id);
+        assert!(sub_wallet_owner(&account, &ed25519_address).is_err());
+
+        let balance = sub_wallet_balance(&account, &alias_address, alias_output_id, "rms").unwrap();
+        assert_eq!(balance.address, alias_address.to_bech32("rms"));
+        assert_eq!(balance.total, 3_000_000);
+        // The timelocked output can't be spent yet
+        assert_eq!(balance.available, 1_000_000);
+        assert_eq!(balance.nfts, vec![nft_id]);
+        assert_eq!(balance.output_ids.len(), 3);
+        assert_eq!(
+            available_sub_wallet_outputs(&account, &alias_address),
+            vec![output_id(1)]
+        );
+    }
+}
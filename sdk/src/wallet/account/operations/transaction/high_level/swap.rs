@@ -0,0 +1,468 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Address,
+        dto::U256Dto,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
+            },
+            BasicOutputBuilder, NativeToken, Output, OutputId, TokenId,
+        },
+        payload::transaction::TransactionEssence,
+        DtoError,
+    },
+    wallet::{
+        account::{
+            constants::DEFAULT_EXPIRATION_TIME,
+            handle::AccountHandle,
+            operations::transaction::high_level::minimum_storage_deposit::minimum_storage_deposit_basic_native_tokens,
+            types::{Transaction, TransactionDto},
+            TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// Terms of a swap offer for `create_swap_offer()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapOfferOptions {
+    /// Bech32 encoded address of the counterparty, the only one who can accept the offer
+    #[serde(rename = "counterpartyAddress")]
+    pub counterparty_address: String,
+    /// The offered native token
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    /// The offered amount of the native token
+    pub amount: U256,
+    /// Expiration in seconds, after which the offered native tokens return to the maker if the offer wasn't accepted.
+    /// Default is 1 day
+    pub expiration: Option<u32>,
+}
+
+/// Dto for `SwapOfferOptions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SwapOfferOptionsDto {
+    /// Bech32 encoded address of the counterparty
+    #[serde(rename = "counterpartyAddress")]
+    pub counterparty_address: String,
+    /// The offered native token
+    #[serde(rename = "tokenId")]
+    pub token_id: TokenId,
+    /// The offered amount of the native token
+    pub amount: U256Dto,
+    /// Expiration in seconds
+    pub expiration: Option<u32>,
+}
+
+impl TryFrom<&SwapOfferOptionsDto> for SwapOfferOptions {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: &SwapOfferOptionsDto) -> crate::wallet::Result<Self> {
+        Ok(Self {
+            counterparty_address: value.counterparty_address.clone(),
+            token_id: value.token_id,
+            amount: U256::try_from(&value.amount).map_err(|_| DtoError::InvalidField("amount"))?,
+            expiration: value.expiration,
+        })
+    }
+}
+
+/// A swap offer, which the maker hands to the counterparty to verify and accept it with `accept_swap_offer()`.
+///
+/// The offer is an output with the native tokens, locked to the counterparty with a storage deposit return and an
+/// expiration unlock condition to the maker. The protocol only enforces that the storage deposit is returned, nothing
+/// on-chain makes the counterparty pay for the native tokens: the offer is trust-based, the price is agreed on and
+/// paid outside of it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapOffer {
+    /// The id of the output with the offered native tokens
+    pub output_id: OutputId,
+    /// Bech32 encoded address of the maker, which gets the storage deposit back
+    pub maker_address: String,
+    /// Bech32 encoded address of the counterparty
+    pub counterparty_address: String,
+    /// The offered native token
+    pub token_id: TokenId,
+    /// The offered amount of the native token
+    pub amount: U256,
+    /// Unix timestamp in seconds after which the offered native tokens return to the maker
+    pub expiration_time: u32,
+}
+
+/// Dto for `SwapOffer`, the JSON format in which offers are exchanged
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapOfferDto {
+    /// The id of the output with the offered native tokens
+    pub output_id: OutputId,
+    /// Bech32 encoded address of the maker
+    pub maker_address: String,
+    /// Bech32 encoded address of the counterparty
+    pub counterparty_address: String,
+    /// The offered native token
+    pub token_id: TokenId,
+    /// The offered amount of the native token
+    pub amount: U256Dto,
+    /// Unix timestamp in seconds after which the offered native tokens return to the maker
+    pub expiration_time: u32,
+}
+
+impl From<&SwapOffer> for SwapOfferDto {
+    fn from(value: &SwapOffer) -> Self {
+        Self {
+            output_id: value.output_id,
+            maker_address: value.maker_address.clone(),
+            counterparty_address: value.counterparty_address.clone(),
+            token_id: value.token_id,
+            amount: U256Dto::from(&value.amount),
+            expiration_time: value.expiration_time,
+        }
+    }
+}
+
+impl TryFrom<&SwapOfferDto> for SwapOffer {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: &SwapOfferDto) -> crate::wallet::Result<Self> {
+        Ok(Self {
+            output_id: value.output_id,
+            maker_address: value.maker_address.clone(),
+            counterparty_address: value.counterparty_address.clone(),
+            token_id: value.token_id,
+            amount: U256::try_from(&value.amount).map_err(|_| DtoError::InvalidField("amount"))?,
+            expiration_time: value.expiration_time,
+        })
+    }
+}
+
+/// The result of a `create_swap_offer()` transaction
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapOfferTransaction {
+    pub offer: SwapOffer,
+    pub transaction: Transaction,
+}
+
+/// Dto for `SwapOfferTransaction`
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SwapOfferTransactionDto {
+    pub offer: SwapOfferDto,
+    pub transaction: TransactionDto,
+}
+
+impl From<&SwapOfferTransaction> for SwapOfferTransactionDto {
+    fn from(value: &SwapOfferTransaction) -> Self {
+        Self {
+            offer: SwapOfferDto::from(&value.offer),
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Function to offer native tokens to a counterparty. The tokens are sent in an output with a
+    /// [StorageDepositReturnUnlockCondition] and an [ExpirationUnlockCondition] to the first address of the account, so
+    /// they return to it if the counterparty doesn't accept the offer in time. The offer is trust-based, the
+    /// counterparty can accept it without paying anything in return, see [`SwapOffer`].
+    /// ```ignore
+    /// let swap_offer_options = SwapOfferOptions {
+    ///     counterparty_address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///     token_id: TokenId::from_str("08e68f7616cd4948efebc6a77c4f93aed770ac53860100000000000000000000000000000000")?,
+    ///     amount: U256::from(50),
+    ///     expiration: None,
+    /// };
+    ///
+    /// let swap_offer_transaction = account_handle.create_swap_offer(swap_offer_options, None).await?;
+    /// // Hand the offer to the counterparty
+    /// println!("{}", serde_json::to_string(&SwapOfferDto::from(&swap_offer_transaction.offer))?);
+    /// ```
+    pub async fn create_swap_offer(
+        &self,
+        swap_offer_options: SwapOfferOptions,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<SwapOfferTransaction> {
+        log::debug!("[TRANSACTION] create_swap_offer");
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let current_time = self.client.get_time_checked().await?;
+
+        let (counterparty_address, counterparty_bech32_hrp) =
+            Address::try_from_bech32_with_hrp(&swap_offer_options.counterparty_address)?;
+        self.client.bech32_hrp_matches(&counterparty_bech32_hrp).await?;
+
+        let account_addresses = self.addresses().await?;
+        let maker_address = account_addresses
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .inner;
+
+        let storage_deposit = minimum_storage_deposit_basic_native_tokens(
+            &rent_structure,
+            &counterparty_address,
+            &maker_address,
+            Some(vec![(swap_offer_options.token_id, swap_offer_options.amount)]),
+            token_supply,
+        )?;
+        let expiration_time = current_time
+            .checked_add(swap_offer_options.expiration.unwrap_or(DEFAULT_EXPIRATION_TIME))
+            .ok_or_else(|| Error::InvalidSwapOffer("expiration is too far in the future".to_string()))?;
+
+        let offer_output = BasicOutputBuilder::new_with_amount(storage_deposit)?
+            .add_native_token(NativeToken::new(
+                swap_offer_options.token_id,
+                swap_offer_options.amount,
+            )?)
+            .add_unlock_condition(AddressUnlockCondition::new(counterparty_address))
+            // The counterparty only gets the native tokens, the full storage deposit has to be returned
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                maker_address,
+                storage_deposit,
+                token_supply,
+            )?)
+            .add_unlock_condition(ExpirationUnlockCondition::new(maker_address, expiration_time)?)
+            .finish_output(token_supply)?;
+
+        let transaction = self.send(vec![offer_output.clone()], options).await?;
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let output_index = essence
+            .outputs()
+            .iter()
+            .position(|output| output == &offer_output)
+            .ok_or_else(|| {
+                Error::InvalidSwapOffer(format!(
+                    "the offer output is missing in the sent transaction {}",
+                    transaction.transaction_id
+                ))
+            })?;
+
+        Ok(SwapOfferTransaction {
+            offer: SwapOffer {
+                output_id: OutputId::new(transaction.transaction_id, output_index as u16)?,
+                maker_address: maker_address.to_bech32(&bech32_hrp),
+                counterparty_address: swap_offer_options.counterparty_address,
+                token_id: swap_offer_options.token_id,
+                amount: swap_offer_options.amount,
+                expiration_time,
+            },
+            transaction,
+        })
+    }
+
+    /// Checks that the output of a swap offer is unspent, addressed to this account and holds the offered native
+    /// tokens under the terms of the offer.
+    pub async fn verify_swap_offer(&self, offer: &SwapOffer) -> crate::wallet::Result<()> {
+        let token_supply = self.client.get_token_supply().await?;
+        let current_time = self.client.get_time_checked().await?;
+
+        let (counterparty_address, bech32_hrp) = Address::try_from_bech32_with_hrp(&offer.counterparty_address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        if !self
+            .addresses()
+            .await?
+            .iter()
+            .any(|address| address.address.inner == counterparty_address)
+        {
+            return Err(Error::InvalidSwapOffer(format!(
+                "counterparty address {} isn't an address of the account",
+                offer.counterparty_address
+            )));
+        }
+
+        let output_response = self.client.get_output(&offer.output_id).await?;
+        if output_response.metadata.is_spent {
+            return Err(Error::InvalidSwapOffer(format!(
+                "output {} is already spent",
+                offer.output_id
+            )));
+        }
+        let output = Output::try_from_dto(&output_response.output, token_supply)?;
+
+        validate_swap_offer_output(offer, &output, current_time)
+    }
+
+    /// Function to accept a swap offer. The offered native tokens are claimed and the storage deposit is returned to
+    /// the maker in the same transaction. Any payment agreed on with the maker has to be sent separately.
+    /// ```ignore
+    /// let offer = SwapOffer::try_from(&serde_json::from_str::<SwapOfferDto>(&offer_json)?)?;
+    ///
+    /// let transaction = account_handle.accept_swap_offer(offer, None).await?;
+    /// ```
+    pub async fn accept_swap_offer(
+        &self,
+        offer: SwapOffer,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_accept_swap_offer(offer, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [AccountHandle.accept_swap_offer()](crate::account::handle::AccountHandle.accept_swap_offer)
+    pub(crate) async fn prepare_accept_swap_offer(
+        &self,
+        offer: SwapOffer,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_accept_swap_offer");
+        let token_supply = self.client.get_token_supply().await?;
+
+        self.verify_swap_offer(&offer).await?;
+
+        let offer_output = self
+            .read()
+            .await
+            .unspent_outputs()
+            .get(&offer.output_id)
+            .map(|output_data| output_data.output.clone())
+            .ok_or_else(|| {
+                Error::InvalidSwapOffer(format!(
+                    "output {} isn't known to the account, sync it first",
+                    offer.output_id
+                ))
+            })?;
+        let storage_deposit_return = offer_output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            .map(|storage_deposit_return| storage_deposit_return.amount())
+            .unwrap_or_default();
+
+        // The storage deposit return to the maker
+        let (maker_address, _) = Address::try_from_bech32_with_hrp(&offer.maker_address)?;
+        let return_output = BasicOutputBuilder::new_with_amount(storage_deposit_return)?
+            .add_unlock_condition(AddressUnlockCondition::new(maker_address))
+            .finish_output(token_supply)?;
+
+        // The offered native tokens end up in the remainder
+        let options = match options {
+            Some(mut options) => {
+                options
+                    .mandatory_inputs
+                    .get_or_insert_with(Vec::new)
+                    .push(offer.output_id);
+                options
+            }
+            None => TransactionOptions {
+                mandatory_inputs: Some(vec![offer.output_id]),
+                ..Default::default()
+            },
+        };
+
+        self.prepare_transaction(vec![return_output], Some(options)).await
+    }
+}
+
+/// Checks that the output locks the offered native tokens to the counterparty and returns its storage deposit and,
+/// after the expiration, the native tokens to the maker.
+pub(crate) fn validate_swap_offer_output(
+    offer: &SwapOffer,
+    output: &Output,
+    current_time: u32,
+) -> crate::wallet::Result<()> {
+    let invalid = |reason: &str| Err(Error::InvalidSwapOffer(format!("output {} {reason}", offer.output_id)));
+
+    let (maker_address, _) = Address::try_from_bech32_with_hrp(&offer.maker_address)?;
+    let (counterparty_address, _) = Address::try_from_bech32_with_hrp(&offer.counterparty_address)?;
+
+    let basic_output = match output {
+        Output::Basic(basic_output) => basic_output,
+        _ => return invalid("isn't a basic output"),
+    };
+    let unlock_conditions = basic_output.unlock_conditions();
+
+    if unlock_conditions.len() != 3 || unlock_conditions.address().map(|uc| uc.address()) != Some(&counterparty_address)
+    {
+        return invalid("has to be locked to the counterparty with a storage deposit return and an expiration");
+    }
+    match unlock_conditions.storage_deposit_return() {
+        Some(sdr) if sdr.return_address() == &maker_address && sdr.amount() == basic_output.amount() => {}
+        _ => return invalid("has to return its full amount to the maker"),
+    }
+    match unlock_conditions.expiration() {
+        Some(expiration) if expiration.return_address() == &maker_address => {
+            if expiration.timestamp() != offer.expiration_time {
+                return invalid("expires at another time than offered");
+            }
+            if expiration.timestamp() <= current_time {
+                return invalid("is expired");
+            }
+        }
+        _ => return invalid("has to expire to the maker"),
+    }
+    if basic_output
+        .native_tokens()
+        .iter()
+        .find(|native_token| native_token.token_id() == &offer.token_id)
+        .map(|native_token| native_token.amount())
+        != Some(offer.amount)
+    {
+        return invalid("doesn't hold the offered native tokens");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::types::block::{address::Ed25519Address, protocol::protocol_parameters};
+
+    const OUTPUT_ID: &str = "0x1e857d380f813d8035e487b6dfd2ff4740b6775273ba1b576f01381ba2a1a44c0000";
+    const TOKEN_ID: &str = "0x08e68f7616cd4948efebc6a77c4f93aed770ac53860100000000000000000000000000000000";
+
+    #[test]
+    fn validate_offer_output() {
+        let token_supply = protocol_parameters().token_supply();
+        let maker_address = Address::Ed25519(Ed25519Address::new([1; 32]));
+        let counterparty_address = Address::Ed25519(Ed25519Address::new([2; 32]));
+        let token_id = TokenId::from_str(TOKEN_ID).unwrap();
+        let offer = SwapOffer {
+            output_id: OutputId::from_str(OUTPUT_ID).unwrap(),
+            maker_address: maker_address.to_bech32("rms"),
+            counterparty_address: counterparty_address.to_bech32("rms"),
+            token_id,
+            amount: U256::from(50),
+            expiration_time: 2_000,
+        };
+        let offer_output = |return_amount: u64, expiration_time: u32, token_amount: u64| {
+            BasicOutputBuilder::new_with_amount(100_000)
+                .unwrap()
+                .add_native_token(NativeToken::new(token_id, U256::from(token_amount)).unwrap())
+                .add_unlock_condition(AddressUnlockCondition::new(counterparty_address))
+                .add_unlock_condition(
+                    StorageDepositReturnUnlockCondition::new(maker_address, return_amount, token_supply).unwrap(),
+                )
+                .add_unlock_condition(ExpirationUnlockCondition::new(maker_address, expiration_time).unwrap())
+                .finish_output(token_supply)
+                .unwrap()
+        };
+
+        assert!(validate_swap_offer_output(&offer, &offer_output(100_000, 2_000, 50), 1_000).is_ok());
+        // Expired, partial storage deposit return, other expiration time and other token amount
+        assert!(validate_swap_offer_output(&offer, &offer_output(100_000, 2_000, 50), 2_000).is_err());
+        assert!(validate_swap_offer_output(&offer, &offer_output(50_000, 2_000, 50), 1_000).is_err());
+        assert!(validate_swap_offer_output(&offer, &offer_output(100_000, 3_000, 50), 1_000).is_err());
+        assert!(validate_swap_offer_output(&offer, &offer_output(100_000, 2_000, 40), 1_000).is_err());
+
+        let plain_output = BasicOutputBuilder::new_with_amount(100_000)
+            .unwrap()
+            .add_native_token(NativeToken::new(token_id, U256::from(50)).unwrap())
+            .add_unlock_condition(AddressUnlockCondition::new(counterparty_address))
+            .finish_output(token_supply)
+            .unwrap();
+        assert!(validate_swap_offer_output(&offer, &plain_output, 1_000).is_err());
+    }
+}
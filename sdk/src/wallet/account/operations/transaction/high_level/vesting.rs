@@ -0,0 +1,299 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+            BasicOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::{
+        account::{handle::AccountHandle, operations::transaction::Transaction, TransactionOptions},
+        Error,
+    },
+};
+
+/// The maximum number of tranches sent in a transaction, one output is left for the remainder.
+pub(crate) const MAX_TRANCHES_PER_TRANSACTION: u16 = OUTPUT_COUNT_MAX - 1;
+
+/// A vesting schedule of one recipient for `send_vesting()`, the tranches are timelocked outputs which unlock at
+/// `start_time + cliff`, `start_time + cliff + period`, ...
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingSchedule {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Amount of every tranche, has to cover the storage deposit of a timelocked output
+    pub amount_per_tranche: u64,
+    /// Number of tranches, at most the maximum output count of a transaction minus one for the remainder
+    pub tranches: u32,
+    /// Unix timestamp in seconds from which the cliff is counted. Default is the current time
+    pub start_time: Option<u32>,
+    /// Seconds from the start time until the first tranche unlocks
+    pub cliff: u32,
+    /// Seconds between two tranches
+    pub period: u32,
+}
+
+/// Dto for `VestingSchedule`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingScheduleDto {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Amount of every tranche
+    pub amount_per_tranche: String,
+    /// Number of tranches
+    pub tranches: u32,
+    /// Unix timestamp in seconds from which the cliff is counted
+    pub start_time: Option<u32>,
+    /// Seconds from the start time until the first tranche unlocks
+    pub cliff: u32,
+    /// Seconds between two tranches
+    pub period: u32,
+}
+
+impl TryFrom<&VestingScheduleDto> for VestingSchedule {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: &VestingScheduleDto) -> crate::wallet::Result<Self> {
+        Ok(Self {
+            address: value.address.clone(),
+            amount_per_tranche: u64::from_str(&value.amount_per_tranche)
+                .map_err(|_| crate::client::Error::InvalidAmount(value.amount_per_tranche.clone()))?,
+            tranches: value.tranches,
+            start_time: value.start_time,
+            cliff: value.cliff,
+            period: value.period,
+        })
+    }
+}
+
+impl VestingSchedule {
+    /// Returns the unix timestamps at which the tranches unlock, `current_time` is used if no start time is set.
+    pub fn unlock_times(&self, current_time: u32) -> crate::wallet::Result<Vec<u32>> {
+        if self.tranches == 0 {
+            return Err(Error::InvalidVestingSchedule(
+                "a schedule needs at least one tranche".to_string(),
+            ));
+        }
+        if self.tranches > u32::from(MAX_TRANCHES_PER_TRANSACTION) {
+            return Err(Error::InvalidVestingSchedule(format!(
+                "{} tranches exceed the maximum of {MAX_TRANCHES_PER_TRANSACTION} tranches in a transaction",
+                self.tranches
+            )));
+        }
+        if self.tranches > 1 && self.period == 0 {
+            return Err(Error::InvalidVestingSchedule(
+                "the period between tranches can't be 0".to_string(),
+            ));
+        }
+
+        let first_unlock_time = self
+            .start_time
+            .unwrap_or(current_time)
+            .checked_add(self.cliff)
+            .filter(|first_unlock_time| *first_unlock_time > current_time)
+            .ok_or_else(|| {
+                Error::InvalidVestingSchedule("the first tranche has to unlock in the future".to_string())
+            })?;
+
+        (0..self.tranches)
+            .map(|tranche| {
+                tranche
+                    .checked_mul(self.period)
+                    .and_then(|offset| first_unlock_time.checked_add(offset))
+                    .ok_or_else(|| {
+                        Error::InvalidVestingSchedule("the last tranche unlocks after the year 2106".to_string())
+                    })
+            })
+            .collect()
+    }
+}
+
+/// A timelocked output of the account which isn't unlocked yet, for `pending_vesting_tranches()`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingTranche {
+    /// The id of the timelocked output
+    pub output_id: OutputId,
+    /// The amount of the output
+    pub amount: u64,
+    /// Unix timestamp in seconds at which the output unlocks
+    pub unlock_time: u32,
+}
+
+/// Dto for `VestingTranche`
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingTrancheDto {
+    /// The id of the timelocked output
+    pub output_id: OutputId,
+    /// The amount of the output
+    pub amount: String,
+    /// Unix timestamp in seconds at which the output unlocks
+    pub unlock_time: u32,
+}
+
+impl From<&VestingTranche> for VestingTrancheDto {
+    fn from(value: &VestingTranche) -> Self {
+        Self {
+            output_id: value.output_id,
+            amount: value.amount.to_string(),
+            unlock_time: value.unlock_time,
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Function to create vesting schedules, every tranche is sent as basic output with a timelock unlock condition.
+    /// The tranches are sent in batches of up to [`MAX_TRANCHES_PER_TRANSACTION`] outputs, one transaction per
+    /// batch. If a transaction fails, the ones sent before it stay sent.
+    /// ```ignore
+    /// let schedules = vec![VestingSchedule {
+    ///     address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///     amount_per_tranche: 1_000_000,
+    ///     tranches: 12,
+    ///     start_time: None,
+    ///     // A year
+    ///     cliff: 31_536_000,
+    ///     // A month
+    ///     period: 2_628_000,
+    /// }];
+    ///
+    /// let transactions = account_handle.send_vesting(schedules, None).await?;
+    /// ```
+    pub async fn send_vesting(
+        &self,
+        schedules: Vec<VestingSchedule>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        let outputs = self.vesting_outputs(schedules).await?;
+
+        let mut transactions = Vec::new();
+        for outputs in outputs.chunks(usize::from(MAX_TRANCHES_PER_TRANSACTION)) {
+            let prepared_transaction = self.prepare_transaction(outputs.to_vec(), options.clone()).await?;
+            transactions.push(self.sign_and_submit_transaction(prepared_transaction).await?);
+        }
+
+        Ok(transactions)
+    }
+
+    /// Returns the timelocked outputs of the tranches of the schedules, after validating all of them.
+    async fn vesting_outputs(&self, schedules: Vec<VestingSchedule>) -> crate::wallet::Result<Vec<Output>> {
+        log::debug!("[TRANSACTION] vesting_outputs");
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let current_time = self.client.get_time_checked().await?;
+
+        let mut outputs = Vec::new();
+        for schedule in schedules {
+            let (address, bech32_hrp) = Address::try_from_bech32_with_hrp(&schedule.address)?;
+            self.client.bech32_hrp_matches(&bech32_hrp).await?;
+
+            for unlock_time in schedule.unlock_times(current_time)? {
+                let output_builder = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure.clone())?
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_unlock_condition(TimelockUnlockCondition::new(unlock_time)?);
+
+                // The recipient can't return a storage deposit before the timelock expires, so every tranche has to
+                // cover it
+                let storage_deposit = output_builder.clone().finish(token_supply)?.amount();
+                if schedule.amount_per_tranche < storage_deposit {
+                    return Err(Error::InvalidVestingSchedule(format!(
+                        "amount per tranche {} is below the required storage deposit {storage_deposit}",
+                        schedule.amount_per_tranche
+                    )));
+                }
+
+                outputs.push(
+                    output_builder
+                        .with_amount(schedule.amount_per_tranche)?
+                        .finish_output(token_supply)?,
+                );
+            }
+        }
+
+        Ok(outputs)
+    }
+
+    /// Returns the unspent outputs of the account which are still timelocked, ordered by unlock time.
+    pub async fn pending_vesting_tranches(&self) -> crate::wallet::Result<Vec<VestingTranche>> {
+        let current_time = self.client.get_time_checked().await?;
+
+        let mut tranches = self
+            .read()
+            .await
+            .unspent_outputs()
+            .values()
+            .filter_map(|output_data| {
+                let timelock = match &output_data.output {
+                    Output::Basic(basic_output) => basic_output.unlock_conditions().timelock()?,
+                    _ => return None,
+                };
+
+                (timelock.timestamp() > current_time).then_some(VestingTranche {
+                    output_id: output_data.output_id,
+                    amount: output_data.output.amount(),
+                    unlock_time: timelock.timestamp(),
+                })
+            })
+            .collect::<Vec<_>>();
+        tranches.sort_by_key(|tranche| (tranche.unlock_time, tranche.output_id));
+
+        Ok(tranches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(tranches: u32, start_time: Option<u32>, cliff: u32, period: u32) -> VestingSchedule {
+        VestingSchedule {
+            address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+            amount_per_tranche: 1_000_000,
+            tranches,
+            start_time,
+            cliff,
+            period,
+        }
+    }
+
+    #[test]
+    fn unlock_times() {
+        assert_eq!(
+            schedule(3, None, 100, 10).unlock_times(1_000).unwrap(),
+            [1_100, 1_110, 1_120]
+        );
+        assert_eq!(
+            schedule(2, Some(2_000), 0, 50).unlock_times(1_000).unwrap(),
+            [2_000, 2_050]
+        );
+        assert_eq!(schedule(1, None, 1, 0).unlock_times(1_000).unwrap(), [1_001]);
+
+        // No tranches, no period, already unlocked and overflowing schedules
+        assert!(schedule(0, None, 100, 10).unlock_times(1_000).is_err());
+        assert!(schedule(2, None, 100, 0).unlock_times(1_000).is_err());
+        assert!(schedule(1, Some(500), 100, 10).unlock_times(1_000).is_err());
+        assert!(schedule(3, None, 100, u32::MAX / 2).unlock_times(1_000).is_err());
+        // More tranches than fit in a transaction next to the remainder
+        assert_eq!(
+            schedule(u32::from(MAX_TRANCHES_PER_TRANSACTION), None, 100, 10)
+                .unlock_times(1_000)
+                .unwrap()
+                .len(),
+            usize::from(MAX_TRANCHES_PER_TRANSACTION)
+        );
+        assert!(schedule(u32::from(OUTPUT_COUNT_MAX), None, 100, 10)
+            .unlock_times(1_000)
+            .is_err());
+        assert!(schedule(u32::MAX, None, 100, 1).unlock_times(1_000).is_err());
+    }
+}
@@ -88,6 +88,19 @@ impl AccountHandle {
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] finish_transaction");
 
+        // The storage deposits of the outputs were computed by the caller, if the protocol parameters changed in the
+        // meantime the outputs need to be built again, the transaction can't fix them
+        if let Some(protocol_parameters) = self.client.synced_protocol_parameters()? {
+            for output in &outputs {
+                output
+                    .verify_storage_deposit(
+                        protocol_parameters.rent_structure().clone(),
+                        protocol_parameters.token_supply(),
+                    )
+                    .map_err(|e| crate::wallet::Error::ProtocolParametersChanged(Box::new(e.into())))?;
+            }
+        }
+
         let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
 
         self.sign_and_submit_transaction(prepared_transaction_data).await
@@ -25,6 +25,13 @@ impl AccountHandle {
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        // The protocol parameters could have changed since the transaction was prepared, an offline client doesn't know
+        // them and can't verify it
+        if let Some(protocol_parameters) = self.client.synced_protocol_parameters()? {
+            prepared_transaction_data
+                .verify_protocol_parameters(&protocol_parameters)
+                .map_err(|e| crate::wallet::Error::ProtocolParametersChanged(Box::new(e)))?;
+        }
         #[cfg(feature = "events")]
         self.event_emitter.lock().await.emit(
             self.read().await.index,
@@ -0,0 +1,565 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        input::Input,
+        output::{NftId, Output, OutputId, TokenId},
+        payload::transaction::{TransactionEssence, TransactionId},
+        BlockId,
+    },
+    wallet::account::{
+        handle::AccountHandle,
+        types::{InclusionState, Transaction},
+        Account,
+    },
+};
+
+/// The format of a transaction history export.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionHistoryFormat {
+    /// Comma separated values with a header row.
+    Csv,
+    /// A JSON array of [`AssetMovementDto`]s.
+    Json,
+}
+
+/// The direction of an asset movement, seen from the account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MovementDirection {
+    /// The asset was received by the account.
+    Incoming,
+    /// The asset was sent by the account.
+    Outgoing,
+}
+
+/// The asset of an asset movement.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum MovedAsset {
+    /// The base coin.
+    BaseCoin,
+    /// A native token.
+    #[serde(rename_all = "camelCase")]
+    NativeToken { token_id: TokenId },
+    /// An nft.
+    #[serde(rename_all = "camelCase")]
+    Nft { nft_id: NftId },
+}
+
+/// The movement of a single asset in a confirmed transaction, one row of the transaction history.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssetMovement {
+    /// The transaction which moved the asset.
+    pub transaction_id: TransactionId,
+    /// The block which contained the transaction.
+    pub block_id: Option<BlockId>,
+    /// The timestamp of the milestone which confirmed the transaction, if it's known.
+    pub milestone_timestamp: Option<u32>,
+    /// The creation time of the transaction in milliseconds.
+    pub timestamp: u128,
+    /// If the account received or sent the asset.
+    pub direction: MovementDirection,
+    /// The moved asset.
+    pub asset: MovedAsset,
+    /// The net amount the account received or sent, 1 for nfts.
+    pub amount: U256,
+    /// The Bech32 addresses of the senders for incoming movements and of the recipients for outgoing movements.
+    pub counterparties: Vec<String>,
+    /// The storage deposit the account sent along with outputs that need to return it, part of the amount of the base
+    /// coin.
+    pub storage_deposit_locked: u64,
+    /// The storage deposit returned in the transaction, by the account for outputs it claimed or to the account for
+    /// outputs it sent.
+    pub storage_deposit_returned: u64,
+    /// The note of the transaction.
+    pub note: Option<String>,
+}
+
+/// Dto for [`AssetMovement`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetMovementDto {
+    /// The transaction which moved the asset.
+    pub transaction_id: TransactionId,
+    /// The block which contained the transaction.
+    pub block_id: Option<BlockId>,
+    /// The timestamp of the milestone which confirmed the transaction, if it's known.
+    pub milestone_timestamp: Option<u32>,
+    /// The creation time of the transaction in milliseconds.
+    pub timestamp: String,
+    /// If the account received or sent the asset.
+    pub direction: MovementDirection,
+    /// The moved asset.
+    pub asset: MovedAsset,
+    /// The net amount the account received or sent, 1 for nfts.
+    pub amount: String,
+    /// The Bech32 addresses of the counterparties.
+    pub counterparties: Vec<String>,
+    /// The storage deposit the account sent along with outputs that need to return it.
+    pub storage_deposit_locked: String,
+    /// The storage deposit returned in the transaction.
+    pub storage_deposit_returned: String,
+    /// The note of the transaction.
+    pub note: Option<String>,
+}
+
+impl From<&AssetMovement> for AssetMovementDto {
+    fn from(value: &AssetMovement) -> Self {
+        Self {
+            transaction_id: value.transaction_id,
+            block_id: value.block_id,
+            milestone_timestamp: value.milestone_timestamp,
+            timestamp: value.timestamp.to_string(),
+            direction: value.direction,
+            asset: value.asset.clone(),
+            amount: value.amount.to_string(),
+            counterparties: value.counterparties.clone(),
+            storage_deposit_locked: value.storage_deposit_locked.to_string(),
+            storage_deposit_returned: value.storage_deposit_returned.to_string(),
+            note: value.note.clone(),
+        }
+    }
+}
+
+impl AccountHandle {
+    /// Get the asset movements of the confirmed incoming and outgoing transactions of the account, one per moved base
+    /// coin amount, native token and nft, ordered by time. Amounts are netted per transaction, so a transaction
+    /// sending funds back to the account only counts with the amount that left it.
+    pub async fn transaction_history(&self) -> crate::wallet::Result<Vec<AssetMovement>> {
+        log::debug!("[transaction_history]");
+        let network_id = self.client.get_network_id().await?;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+
+        asset_movements(&*self.read().await, network_id, &bech32_hrp)
+    }
+
+    /// Export the transaction history of the account as CSV or JSON, for accounting.
+    pub async fn export_transaction_history(&self, format: TransactionHistoryFormat) -> crate::wallet::Result<String> {
+        let movements = self.transaction_history().await?;
+
+        Ok(match format {
+            TransactionHistoryFormat::Csv => to_csv(&movements),
+            TransactionHistoryFormat::Json => {
+                serde_json::to_string(&movements.iter().map(AssetMovementDto::from).collect::<Vec<_>>())?
+            }
+        })
+    }
+}
+
+pub(crate) fn asset_movements(
+    account: &Account,
+    network_id: u64,
+    bech32_hrp: &str,
+) -> crate::wallet::Result<Vec<AssetMovement>> {
+    let mut transactions = account.transactions.values().collect::<Vec<_>>();
+    // Transactions sent to the account itself are also incoming transactions
+    transactions.extend(
+        account
+            .incoming_transactions
+            .values()
+            .filter(|transaction| !account.transactions.contains_key(&transaction.transaction_id)),
+    );
+    transactions.retain(|transaction| {
+        transaction.network_id == network_id && transaction.inclusion_state == InclusionState::Confirmed
+    });
+
+    let mut movements = Vec::new();
+    for transaction in transactions {
+        movements.extend(transaction_movements(account, transaction, bech32_hrp)?);
+    }
+    movements.sort_by_key(|movement| (movement.milestone_timestamp, movement.timestamp));
+
+    Ok(movements)
+}
+
+// The assets the account spent and received in a transaction
+#[derive(Default)]
+struct AccountAssets {
+    base_coin: u64,
+    native_tokens: HashMap<TokenId, U256>,
+    nfts: Vec<NftId>,
+}
+
+impl AccountAssets {
+    fn add(&mut self, output: &Output, output_id: &OutputId) {
+        self.base_coin += output.amount();
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                *self.native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+            }
+        }
+        if let Output::Nft(nft_output) = output {
+            self.nfts.push(nft_output.nft_id_non_null(output_id));
+        }
+    }
+}
+
+fn transaction_movements(
+    account: &Account,
+    transaction: &Transaction,
+    bech32_hrp: &str,
+) -> crate::wallet::Result<Vec<AssetMovement>> {
+    let account_addresses = account
+        .public_addresses
+        .iter()
+        .chain(account.internal_addresses.iter())
+        .map(|a| a.address.inner)
+        .collect::<Vec<_>>();
+    let milestone_timestamp = milestone_timestamp(account, transaction);
+    let time = milestone_timestamp.unwrap_or((transaction.timestamp / 1000) as u32);
+    let owner = |output: &Output, output_id: &OutputId| {
+        output
+            .required_and_unlocked_address(time, output_id, None)
+            .ok()
+            .map(|(address, _)| address)
+    };
+
+    let mut spent = AccountAssets::default();
+    let mut received = AccountAssets::default();
+    let mut senders = Vec::new();
+    let mut recipients = Vec::new();
+    let mut storage_deposit_locked = 0;
+    let mut storage_deposit_returned = 0;
+
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    for input in essence.inputs() {
+        let Input::Utxo(input) = input else {
+            continue;
+        };
+        let output = match account.outputs.get(input.output_id()) {
+            Some(output_data) => output_data.output.clone(),
+            None => match transaction
+                .inputs
+                .iter()
+                .find(|i| i.metadata.output_id().ok().as_ref() == Some(input.output_id()))
+            {
+                Some(input) => Output::try_from_dto_unverified(&input.output)?,
+                // Inputs of incoming transactions which the node already pruned
+                None => continue,
+            },
+        };
+        let Some(address) = owner(&output, input.output_id()) else {
+            continue;
+        };
+        let storage_deposit_return = output.unlock_conditions().and_then(|u| u.storage_deposit_return());
+
+        if account_addresses.contains(&address) {
+            spent.add(&output, input.output_id());
+            if let Some(sdr) = storage_deposit_return {
+                if !account_addresses.contains(sdr.return_address()) {
+                    storage_deposit_returned += sdr.amount();
+                }
+            }
+        } else {
+            push_unique(&mut senders, address.to_bech32(bech32_hrp));
+            if let Some(sdr) = storage_deposit_return {
+                if account_addresses.contains(sdr.return_address()) {
+                    storage_deposit_returned += sdr.amount();
+                }
+            }
+        }
+    }
+
+    for (index, output) in essence.outputs().iter().enumerate() {
+        let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+        let Some(address) = owner(output, &output_id) else {
+            continue;
+        };
+
+        if account_addresses.contains(&address) {
+            received.add(output, &output_id);
+        } else {
+            push_unique(&mut recipients, address.to_bech32(bech32_hrp));
+            if let Some(sdr) = output.unlock_conditions().and_then(|u| u.storage_deposit_return()) {
+                if account_addresses.contains(sdr.return_address()) {
+                    storage_deposit_locked += sdr.amount();
+                }
+            }
+        }
+    }
+
+    let movement = |direction, asset, amount| AssetMovement {
+        transaction_id: transaction.transaction_id,
+        block_id: transaction.block_id,
+        milestone_timestamp,
+        timestamp: transaction.timestamp,
+        direction,
+        asset,
+        amount,
+        counterparties: match direction {
+            MovementDirection::Incoming => senders.clone(),
+            MovementDirection::Outgoing => recipients.clone(),
+        },
+        storage_deposit_locked: 0,
+        storage_deposit_returned: 0,
+        note: transaction.note.clone(),
+    };
+    let mut movements = Vec::new();
+
+    if let Some((direction, amount)) = net_movement(U256::from(spent.base_coin), U256::from(received.base_coin)) {
+        movements.push(AssetMovement {
+            storage_deposit_locked,
+            storage_deposit_returned,
+            ..movement(direction, MovedAsset::BaseCoin, amount)
+        });
+    }
+
+    let mut token_ids = spent
+        .native_tokens
+        .keys()
+        .chain(received.native_tokens.keys())
+        .collect::<Vec<_>>();
+    token_ids.sort();
+    token_ids.dedup();
+    for token_id in token_ids {
+        let spent_amount = spent.native_tokens.get(token_id).copied().unwrap_or_default();
+        let received_amount = received.native_tokens.get(token_id).copied().unwrap_or_default();
+        if let Some((direction, amount)) = net_movement(spent_amount, received_amount) {
+            movements.push(movement(
+                direction,
+                MovedAsset::NativeToken { token_id: *token_id },
+                amount,
+            ));
+        }
+    }
+
+    for nft_id in &received.nfts {
+        if !spent.nfts.contains(nft_id) {
+            movements.push(movement(
+                MovementDirection::Incoming,
+                MovedAsset::Nft { nft_id: *nft_id },
+                U256::one(),
+            ));
+        }
+    }
+    for nft_id in &spent.nfts {
+        if !received.nfts.contains(nft_id) {
+            movements.push(movement(
+                MovementDirection::Outgoing,
+                MovedAsset::Nft { nft_id: *nft_id },
+                U256::one(),
+            ));
+        }
+    }
+
+    Ok(movements)
+}
+
+// Get the timestamp of the milestone which confirmed the transaction, from its outputs or inputs
+fn milestone_timestamp(account: &Account, transaction: &Transaction) -> Option<u32> {
+    account
+        .outputs
+        .values()
+        .find(|output_data| output_data.output_id.transaction_id() == &transaction.transaction_id)
+        .map(|output_data| output_data.metadata.milestone_timestamp_booked)
+        .or_else(|| {
+            transaction
+                .inputs
+                .iter()
+                .find_map(|input| input.metadata.milestone_timestamp_spent)
+        })
+        .or_else(|| {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            essence.inputs().iter().find_map(|input| match input {
+                Input::Utxo(input) => account
+                    .outputs
+                    .get(input.output_id())
+                    .and_then(|output_data| output_data.metadata.milestone_timestamp_spent),
+                _ => None,
+            })
+        })
+}
+
+fn net_movement(spent: U256, received: U256) -> Option<(MovementDirection, U256)> {
+    match received.cmp(&spent) {
+        std::cmp::Ordering::Greater => Some((MovementDirection::Incoming, received - spent)),
+        std::cmp::Ordering::Less => Some((MovementDirection::Outgoing, spent - received)),
+        std::cmp::Ordering::Equal => None,
+    }
+}
+
+fn push_unique(addresses: &mut Vec<String>, address: String) {
+    if !addresses.contains(&address) {
+        addresses.push(address);
+    }
+}
+
+const CSV_HEADER: &str = "transactionId,blockId,milestoneTimestamp,timestamp,direction,asset,assetId,amount,\
+                          counterparties,storageDepositLocked,storageDepositReturned,note";
+
+fn to_csv(movements: &[AssetMovement]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+
+    for movement in movements {
+        let (asset, asset_id) = match &movement.asset {
+            MovedAsset::BaseCoin => ("baseCoin", String::new()),
+            MovedAsset::NativeToken { token_id } => ("nativeToken", token_id.to_string()),
+            MovedAsset::Nft { nft_id } => ("nft", nft_id.to_string()),
+        };
+        let direction = match movement.direction {
+            MovementDirection::Incoming => "incoming",
+            MovementDirection::Outgoing => "outgoing",
+        };
+        let fields = [
+            movement.transaction_id.to_string(),
+            movement
+                .block_id
+                .map(|block_id| block_id.to_string())
+                .unwrap_or_default(),
+            movement
+                .milestone_timestamp
+                .map(|timestamp| timestamp.to_string())
+                .unwrap_or_default(),
+            movement.timestamp.to_string(),
+            direction.to_string(),
+            asset.to_string(),
+            asset_id,
+            movement.amount.to_string(),
+            movement.counterparties.join(" "),
+            movement.storage_deposit_locked.to_string(),
+            movement.storage_deposit_returned.to_string(),
+            movement.note.clone().unwrap_or_default(),
+        ];
+        csv.push_str(
+            &fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+// Quote a field if it contains a separator, quote or line break, as described in RFC 4180
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[test]
+fn transaction_history_of_outgoing_transaction() {
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            input::UtxoInput,
+            output::{
+                unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+                BasicOutputBuilder, InputsCommitment,
+            },
+            payload::{transaction::RegularTransactionEssence, TransactionPayload},
+            protocol::protocol_parameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{SignatureUnlock, Unlock, Unlocks},
+        },
+        wallet::account::{
+            test_utils::{account, output_data},
+            types::{AccountAddress, AddressWrapper},
+        },
+    };
+
+    let protocol_parameters = protocol_parameters();
+    let token_supply = protocol_parameters.token_supply();
+    let account_address = Address::from(Ed25519Address::new([1; 32]));
+    let recipient_address = Address::from(Ed25519Address::new([2; 32]));
+    let basic_output = |amount, address| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+    };
+
+    let mut input = output_data(
+        0,
+        basic_output(3_000_000, account_address)
+            .finish_output(token_supply)
+            .unwrap(),
+        account_address,
+        (10, 1000),
+    );
+    input.is_spent = true;
+    input.metadata.is_spent = true;
+    input.metadata.milestone_index_spent = Some(20);
+    input.metadata.milestone_timestamp_spent = Some(2000);
+    input.network_id = protocol_parameters.network_id();
+    let input_id = input.output_id;
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+            .with_inputs(vec![Input::Utxo(UtxoInput::from(input_id))])
+            .add_output(
+                basic_output(1_000_000, recipient_address)
+                    .add_unlock_condition(
+                        StorageDepositReturnUnlockCondition::new(account_address, 250_000, token_supply).unwrap(),
+                    )
+                    .finish_output(token_supply)
+                    .unwrap(),
+            )
+            .add_output(
+                basic_output(2_000_000, account_address)
+                    .finish_output(token_supply)
+                    .unwrap(),
+            )
+            .finish(&protocol_parameters)
+            .unwrap(),
+    );
+    let unlocks = Unlocks::new(vec![Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(
+        Ed25519Signature::new([0; 32], [0; 64]),
+    )))])
+    .unwrap();
+    let payload = TransactionPayload::new(essence, unlocks).unwrap();
+    let transaction_id = payload.id();
+
+    let transaction = Transaction {
+        payload,
+        block_id: Some(BlockId::new([5; 32])),
+        inclusion_state: InclusionState::Confirmed,
+        timestamp: 1_999_000,
+        transaction_id,
+        network_id: protocol_parameters.network_id(),
+        incoming: false,
+        note: Some("rent, \"June\"".to_string()),
+        inputs: Vec::new(),
+    };
+
+    let mut account = account([input]);
+    account.public_addresses = vec![AccountAddress {
+        address: AddressWrapper::new(account_address, "rms".to_string()),
+        key_index: 0,
+        internal: false,
+        used: true,
+        label: None,
+    }];
+    account.transactions = HashMap::from([(transaction_id, transaction)]);
+
+    let movements = asset_movements(&account, protocol_parameters.network_id(), "rms").unwrap();
+    // The remainder isn't a movement
+    assert_eq!(movements.len(), 1);
+    let movement = &movements[0];
+    assert_eq!(movement.direction, MovementDirection::Outgoing);
+    assert_eq!(movement.asset, MovedAsset::BaseCoin);
+    assert_eq!(movement.amount, U256::from(1_000_000));
+    assert_eq!(movement.counterparties, vec![recipient_address.to_bech32("rms")]);
+    assert_eq!(movement.storage_deposit_locked, 250_000);
+    assert_eq!(movement.storage_deposit_returned, 0);
+    // Taken from the spent input, since the outputs of the transaction aren't stored
+    assert_eq!(movement.milestone_timestamp, Some(2000));
+
+    let csv = to_csv(&movements);
+    let row = csv.lines().nth(1).unwrap();
+    assert!(row.contains(",outgoing,baseCoin,,1000000,"));
+    assert!(row.ends_with(",250000,0,\"rent, \"\"June\"\"\""));
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{dto::OutputMetadataDto, Output, OutputId},
+        payload::transaction::TransactionId,
+    },
+    wallet::account::{types::OutputData, Account},
+};
+
+/// The transaction which created the outputs of [`output_data`].
+pub(crate) const TRANSACTION_ID: &str = "0x24a1f46bdb6b2bf38f1c59f73cdd4ae5b418804bb231d76d06fbf246498d5883";
+/// The network id of the outputs of [`output_data`].
+pub(crate) const NETWORK_ID: u64 = 1;
+
+/// Returns the id of the output with the index created by [`TRANSACTION_ID`].
+pub(crate) fn output_id(output_index: u16) -> OutputId {
+    OutputId::new(TransactionId::from_str(TRANSACTION_ID).unwrap(), output_index).unwrap()
+}
+
+/// Returns an unspent output created by [`TRANSACTION_ID`], booked at the milestone index and timestamp.
+pub(crate) fn output_data(output_index: u16, output: Output, address: Address, booked: (u32, u32)) -> OutputData {
+    OutputData {
+        output_id: output_id(output_index),
+        metadata: OutputMetadataDto {
+            block_id: "0x0000000000000000000000000000000000000000000000000000000000000000".to_string(),
+            transaction_id: TRANSACTION_ID.to_string(),
+            output_index,
+            is_spent: false,
+            milestone_index_spent: None,
+            milestone_timestamp_spent: None,
+            transaction_id_spent: None,
+            milestone_index_booked: booked.0,
+            milestone_timestamp_booked: booked.1,
+            ledger_index: 100,
+        },
+        output,
+        is_spent: false,
+        address,
+        network_id: NETWORK_ID,
+        remainder: false,
+        chain: None,
+    }
+}
+
+/// Returns an account without addresses and transactions, which has the outputs and their unspent ones as unspent
+/// outputs.
+pub(crate) fn account(outputs: impl IntoIterator<Item = OutputData>) -> Account {
+    let outputs = outputs
+        .into_iter()
+        .map(|output| (output.output_id, output))
+        .collect::<HashMap<_, _>>();
+    let unspent_outputs = outputs
+        .iter()
+        .filter(|(_, output)| !output.is_spent)
+        .map(|(output_id, output)| (*output_id, output.clone()))
+        .collect();
+
+    Account {
+        index: 0,
+        coin_type: 4218,
+        alias: "0".to_string(),
+        public_addresses: Vec::new(),
+        internal_addresses: Vec::new(),
+        addresses_with_unspent_outputs: Vec::new(),
+        outputs,
+        locked_outputs: HashSet::new(),
+        unspent_outputs,
+        transactions: HashMap::new(),
+        pending_transactions: HashSet::new(),
+        incoming_transactions: HashMap::new(),
+        inaccessible_incoming_transactions: HashSet::new(),
+        native_token_foundries: HashMap::new(),
+        output_labels: HashMap::new(),
+        client_options: None,
+    }
+}
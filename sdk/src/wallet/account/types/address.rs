@@ -25,6 +25,9 @@ pub struct AccountAddress {
     // do we want this field? Could be useful if we don't store spent output ids and because of that wouldn't know if
     // an address was used or not just by looking at it
     pub(crate) used: bool,
+    /// A label set by the user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) label: Option<String>,
 }
 
 /// An account address with unspent output_ids for unspent outputs.
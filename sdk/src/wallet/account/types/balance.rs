@@ -6,9 +6,14 @@ use std::collections::HashMap;
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
+use super::Irc30Metadata;
 use crate::types::block::{
     dto::U256Dto,
-    output::{dto::TokenIdDto, feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+    output::{
+        dto::{NativeTokenDto, TokenIdDto},
+        feature::MetadataFeature,
+        AliasId, FoundryId, NativeToken, NftId, OutputId, TokenId,
+    },
 };
 
 /// The balance of an account, returned from [`crate::account::handle::AccountHandle::sync()`] and
@@ -177,6 +182,9 @@ pub struct NativeTokensBalance {
     pub token_id: TokenId,
     /// Token foundry immutable metadata
     pub metadata: Option<MetadataFeature>,
+    /// Token foundry immutable metadata, if it follows the IRC30 standard
+    #[serde(rename = "irc30Metadata", default, skip_serializing_if = "Option::is_none")]
+    pub irc_30_metadata: Option<Irc30Metadata>,
     /// Total amount
     pub total: U256,
     /// Balance that can currently be spent
@@ -188,12 +196,29 @@ impl Default for NativeTokensBalance {
         Self {
             token_id: TokenId::null(),
             metadata: None,
+            irc_30_metadata: None,
             total: U256::from(0u8),
             available: U256::from(0u8),
         }
     }
 }
 
+impl NativeTokensBalance {
+    /// The total amount formatted with the decimals of the IRC30 metadata.
+    pub fn formatted_total(&self) -> Option<String> {
+        self.irc_30_metadata
+            .as_ref()
+            .map(|metadata| metadata.format_amount(self.total))
+    }
+
+    /// The available amount formatted with the decimals of the IRC30 metadata.
+    pub fn formatted_available(&self) -> Option<String> {
+        self.irc_30_metadata
+            .as_ref()
+            .map(|metadata| metadata.format_amount(self.available))
+    }
+}
+
 /// Base coin fields for [`AccountBalanceDto`]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct NativeTokensBalanceDto {
@@ -202,10 +227,19 @@ pub struct NativeTokensBalanceDto {
     pub token_id: TokenIdDto,
     /// Token foundry immutable metadata
     pub metadata: Option<String>,
+    /// Token foundry immutable metadata, if it follows the IRC30 standard
+    #[serde(rename = "irc30Metadata", default, skip_serializing_if = "Option::is_none")]
+    pub irc_30_metadata: Option<Irc30Metadata>,
     /// Total amount
     pub total: U256Dto,
     /// Balance that can currently be spent
     pub available: U256Dto,
+    /// Total amount formatted with the decimals of the IRC30 metadata
+    #[serde(rename = "formattedTotal", default, skip_serializing_if = "Option::is_none")]
+    pub formatted_total: Option<String>,
+    /// Balance that can currently be spent formatted with the decimals of the IRC30 metadata
+    #[serde(rename = "formattedAvailable", default, skip_serializing_if = "Option::is_none")]
+    pub formatted_available: Option<String>,
 }
 
 impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
@@ -213,8 +247,69 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
         Self {
             token_id: TokenIdDto::from(&value.token_id),
             metadata: value.metadata.as_ref().map(|m| prefix_hex::encode(m.data())),
+            irc_30_metadata: value.irc_30_metadata.clone(),
             total: U256Dto::from(&value.total),
             available: U256Dto::from(&value.available),
+            formatted_total: value.formatted_total(),
+            formatted_available: value.formatted_available(),
+        }
+    }
+}
+
+/// A point in the history of an account, used to query its balance at that time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BalancePoint {
+    /// A milestone timestamp, in seconds since the Unix epoch.
+    Timestamp(u32),
+    /// A milestone index.
+    MilestoneIndex(u32),
+}
+
+/// The balance an account held at a [`BalancePoint`], returned from
+/// [`crate::account::handle::AccountHandle::balance_at()`] and
+/// [`crate::account::handle::AccountHandle::balance_history()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalBalance {
+    /// The point of the balance
+    pub point: BalancePoint,
+    /// Amount of the base coin, without amounts which had to be returned to other addresses
+    #[serde(rename = "baseCoin")]
+    pub base_coin: u64,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeToken>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+}
+
+/// Dto for the balance an account held at a [`BalancePoint`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HistoricalBalanceDto {
+    /// The point of the balance
+    pub point: BalancePoint,
+    /// Amount of the base coin, without amounts which had to be returned to other addresses
+    #[serde(rename = "baseCoin")]
+    pub base_coin: String,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+}
+
+impl From<&HistoricalBalance> for HistoricalBalanceDto {
+    fn from(value: &HistoricalBalance) -> Self {
+        Self {
+            point: value.point,
+            base_coin: value.base_coin.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            nfts: value.nfts.clone(),
+            aliases: value.aliases.clone(),
         }
     }
 }
@@ -0,0 +1,294 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{feature::MetadataFeature, NftId, OutputId},
+    },
+    wallet::Error,
+};
+
+/// The `standard` field of IRC27 metadata.
+pub const IRC_27_STANDARD: &str = "IRC27";
+/// The `version` field of IRC27 metadata this implementation writes.
+pub const IRC_27_VERSION: &str = "v1.0";
+
+/// NFT metadata following the [IRC27](https://github.com/iotaledger/tips/blob/main/tips/TIP-0027/tip-0027.md)
+/// standard, stored as JSON in the immutable metadata feature of an NFT output.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc27Metadata {
+    /// The metadata standard, always `IRC27`.
+    pub standard: String,
+    /// The version of the standard.
+    pub version: String,
+    /// The MIME type of the NFT media, e.g. `image/png`.
+    #[serde(rename = "type")]
+    pub media_type: String,
+    /// URI of the NFT media.
+    pub uri: String,
+    /// Name of the NFT.
+    pub name: String,
+    /// Name of the collection the NFT belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_name: Option<String>,
+    /// Royalty shares between 0 and 1 by bech32 encoded address, they sum up to at most 1.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub royalties: BTreeMap<String, f64>,
+    /// Name of the issuer of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_name: Option<String>,
+    /// Description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Attributes of the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Irc27Attribute>,
+}
+
+/// An attribute of IRC27 metadata.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    /// The name of the trait.
+    pub trait_type: String,
+    /// The value of the trait, a string, number or boolean.
+    pub value: serde_json::Value,
+}
+
+impl Irc27Metadata {
+    /// Creates IRC27 metadata with the required fields, the optional ones can be added with the `with_*` and `add_*`
+    /// methods.
+    pub fn new(media_type: impl Into<String>, uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            standard: IRC_27_STANDARD.to_string(),
+            version: IRC_27_VERSION.to_string(),
+            media_type: media_type.into(),
+            uri: uri.into(),
+            name: name.into(),
+            collection_name: None,
+            royalties: BTreeMap::new(),
+            issuer_name: None,
+            description: None,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Sets the collection name.
+    pub fn with_collection_name(mut self, collection_name: impl Into<String>) -> Self {
+        self.collection_name.replace(collection_name.into());
+        self
+    }
+
+    /// Adds a royalty share for a bech32 encoded address.
+    pub fn add_royalty(mut self, bech32_address: impl Into<String>, share: f64) -> Self {
+        self.royalties.insert(bech32_address.into(), share);
+        self
+    }
+
+    /// Sets the issuer name.
+    pub fn with_issuer_name(mut self, issuer_name: impl Into<String>) -> Self {
+        self.issuer_name.replace(issuer_name.into());
+        self
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+        self
+    }
+
+    /// Adds an attribute.
+    pub fn add_attribute(mut self, trait_type: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        self.attributes.push(Irc27Attribute {
+            trait_type: trait_type.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Checks the metadata against the IRC27 JSON schema, the error lists every violation.
+    pub fn validate(&self) -> crate::wallet::Result<()> {
+        let mut violations = Vec::new();
+
+        if self.standard != IRC_27_STANDARD {
+            violations.push(format!("standard must be {IRC_27_STANDARD}, found {}", self.standard));
+        }
+        if !is_version(&self.version) {
+            violations.push(format!(
+                "version must look like {IRC_27_VERSION}, found {}",
+                self.version
+            ));
+        }
+        if !is_media_type(&self.media_type) {
+            violations.push(format!("type must be a MIME type, found {}", self.media_type));
+        }
+        if !is_uri(&self.uri) {
+            violations.push(format!("uri must be an URI, found {}", self.uri));
+        }
+        if self.name.is_empty() {
+            violations.push("name can't be empty".to_string());
+        }
+        for (address, share) in &self.royalties {
+            if Address::try_from_bech32(address).is_err() {
+                violations.push(format!("royalty address {address} isn't a bech32 address"));
+            }
+            if !(0.0..=1.0).contains(share) {
+                violations.push(format!(
+                    "royalty share of {address} must be between 0 and 1, found {share}"
+                ));
+            }
+        }
+        if self.royalties.values().sum::<f64>() > 1.0 {
+            violations.push("royalty shares sum up to more than 1".to_string());
+        }
+        for attribute in &self.attributes {
+            if attribute.trait_type.is_empty() {
+                violations.push("attribute trait_type can't be empty".to_string());
+            }
+            if !(attribute.value.is_string() || attribute.value.is_number() || attribute.value.is_boolean()) {
+                violations.push(format!(
+                    "value of attribute {} must be a string, number or boolean",
+                    attribute.trait_type
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidIrc27Metadata(violations.join("; ")))
+        }
+    }
+
+    /// Validates the metadata and serializes it to the JSON bytes of a metadata feature.
+    pub fn to_bytes(&self) -> crate::wallet::Result<Vec<u8>> {
+        self.validate()?;
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Parses and validates IRC27 metadata from the JSON bytes of a metadata feature. Unknown fields are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> crate::wallet::Result<Self> {
+        let metadata: Self =
+            serde_json::from_slice(bytes).map_err(|e| Error::InvalidIrc27Metadata(format!("invalid JSON: {e}")))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+}
+
+impl TryFrom<&Irc27Metadata> for MetadataFeature {
+    type Error = Error;
+
+    fn try_from(value: &Irc27Metadata) -> crate::wallet::Result<Self> {
+        Ok(Self::new(value.to_bytes()?)?)
+    }
+}
+
+/// The IRC27 metadata of an NFT owned by the account, or why it couldn't be read.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NftIrc27Metadata {
+    /// The id of the NFT.
+    pub nft_id: NftId,
+    /// The id of the output holding the NFT.
+    pub output_id: OutputId,
+    /// The metadata, if it's valid IRC27 metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Irc27Metadata>,
+    /// Why the immutable metadata isn't valid IRC27 metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn is_version(version: &str) -> bool {
+    version
+        .strip_prefix('v')
+        .and_then(|version| version.split_once('.'))
+        .filter(|(major, minor)| is_number(major) && is_number(minor))
+        .is_some()
+}
+
+fn is_number(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_media_type(media_type: &str) -> bool {
+    let is_token = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || "!#$&-^_.+".contains(c));
+
+    media_type
+        .split_once('/')
+        .filter(|(kind, subtype)| is_token(kind) && is_token(subtype))
+        .is_some()
+}
+
+pub(super) fn is_uri(uri: &str) -> bool {
+    uri.split_once(':')
+        .filter(|(scheme, rest)| {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme.chars().all(|c| c.is_ascii_alphanumeric() || "+-.".contains(c))
+                && !rest.is_empty()
+                && !rest.contains(char::is_whitespace)
+        })
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+
+    fn metadata() -> Irc27Metadata {
+        Irc27Metadata::new("image/png", "https://mywebsite.com/my-nft-files-1.png", "My NFT #0001")
+            .with_collection_name("My Collection of Art")
+            .add_royalty(ADDRESS, 0.025)
+            .with_issuer_name("My Artist Name")
+            .with_description("A little information about my NFT collection")
+            .add_attribute("Background", "Purple")
+            .add_attribute("Rarity", 7)
+    }
+
+    #[test]
+    fn serialize_and_parse() {
+        let bytes = metadata().to_bytes().unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["standard"], "IRC27");
+        assert_eq!(json["type"], "image/png");
+        assert_eq!(json["collectionName"], "My Collection of Art");
+        assert_eq!(json["attributes"][0]["trait_type"], "Background");
+
+        assert_eq!(Irc27Metadata::from_bytes(&bytes).unwrap(), metadata());
+        assert!(MetadataFeature::try_from(&metadata()).is_ok());
+    }
+
+    #[test]
+    fn parse_is_tolerant() {
+        let metadata = Irc27Metadata::from_bytes(
+            br#"{"standard":"IRC27","version":"v1.0","type":"video/mp4","uri":"ipfs://QmTest","name":"NFT","extra":1}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.media_type, "video/mp4");
+        assert!(metadata.royalties.is_empty());
+
+        assert!(Irc27Metadata::from_bytes(b"not json").is_err());
+        assert!(Irc27Metadata::from_bytes(br#"{"standard":"IRC27"}"#).is_err());
+    }
+
+    #[test]
+    fn validate_reports_every_violation() {
+        let mut invalid = metadata().add_royalty("rms1invalid", 1.5);
+        invalid.media_type = "png".to_string();
+        invalid.uri = "no uri".to_string();
+
+        match invalid.validate() {
+            Err(Error::InvalidIrc27Metadata(violations)) => {
+                assert_eq!(violations.split("; ").count(), 5, "{violations}");
+            }
+            other => panic!("unexpected result {other:?}"),
+        }
+    }
+}
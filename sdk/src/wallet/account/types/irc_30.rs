@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use super::irc_27::is_uri;
+use crate::{types::block::output::feature::MetadataFeature, wallet::Error};
+
+/// The `standard` field of IRC30 metadata.
+pub const IRC_30_STANDARD: &str = "IRC30";
+/// The largest number of decimals an U256 amount can have.
+const MAX_DECIMALS: u32 = 77;
+
+/// Native token metadata following the IRC30 standard.
+///
+/// It's stored as JSON in the immutable metadata feature of the foundry output of the native token, see
+/// <https://github.com/iotaledger/tips/blob/main/tips/TIP-0030/tip-0030.md>.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc30Metadata {
+    /// The metadata standard, always `IRC30`.
+    pub standard: String,
+    /// Name of the token.
+    pub name: String,
+    /// Symbol of the token, e.g. `MYT`.
+    pub symbol: String,
+    /// Number of decimals of the token, the smallest unit is 10^-decimals tokens.
+    pub decimals: u32,
+    /// Description of the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// URL of the token project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// URL of the token logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// Inline logo of the token, e.g. an SVG.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+}
+
+impl Irc30Metadata {
+    /// Creates IRC30 metadata with the required fields, the optional ones can be added with the `with_*` methods.
+    pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u32) -> Self {
+        Self {
+            standard: IRC_30_STANDARD.to_string(),
+            name: name.into(),
+            symbol: symbol.into(),
+            decimals,
+            description: None,
+            url: None,
+            logo_url: None,
+            logo: None,
+        }
+    }
+
+    /// Sets the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+        self
+    }
+
+    /// Sets the URL of the token project.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url.replace(url.into());
+        self
+    }
+
+    /// Sets the URL of the token logo.
+    pub fn with_logo_url(mut self, logo_url: impl Into<String>) -> Self {
+        self.logo_url.replace(logo_url.into());
+        self
+    }
+
+    /// Sets the inline logo.
+    pub fn with_logo(mut self, logo: impl Into<String>) -> Self {
+        self.logo.replace(logo.into());
+        self
+    }
+
+    /// Checks the metadata against the IRC30 JSON schema, the error lists every violation.
+    pub fn validate(&self) -> crate::wallet::Result<()> {
+        let mut violations = Vec::new();
+
+        if self.standard != IRC_30_STANDARD {
+            violations.push(format!("standard must be {IRC_30_STANDARD}, found {}", self.standard));
+        }
+        if self.name.is_empty() {
+            violations.push("name can't be empty".to_string());
+        }
+        if self.symbol.is_empty() {
+            violations.push("symbol can't be empty".to_string());
+        }
+        if self.decimals > MAX_DECIMALS {
+            violations.push(format!(
+                "decimals can't be larger than {MAX_DECIMALS}, found {}",
+                self.decimals
+            ));
+        }
+        for (field, url) in [("url", &self.url), ("logoUrl", &self.logo_url)] {
+            if let Some(url) = url {
+                if !is_uri(url) {
+                    violations.push(format!("{field} must be an URL, found {url}"));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidIrc30Metadata(violations.join("; ")))
+        }
+    }
+
+    /// Validates the metadata and serializes it to the JSON bytes of a metadata feature.
+    pub fn to_bytes(&self) -> crate::wallet::Result<Vec<u8>> {
+        self.validate()?;
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// Parses and validates IRC30 metadata from the JSON bytes of a metadata feature. Unknown fields are ignored.
+    pub fn from_bytes(bytes: &[u8]) -> crate::wallet::Result<Self> {
+        let metadata: Self =
+            serde_json::from_slice(bytes).map_err(|e| Error::InvalidIrc30Metadata(format!("invalid JSON: {e}")))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+
+    /// Formats an amount of the smallest unit with the decimals of the token, e.g. `1234500` with 4 decimals is
+    /// `123.45`.
+    pub fn format_amount(&self, amount: U256) -> String {
+        let digits = amount.to_string();
+        let decimals = self.decimals as usize;
+        if decimals == 0 {
+            return digits;
+        }
+
+        let digits = format!("{digits:0>width$}", width = decimals + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - decimals);
+        let fraction = fraction.trim_end_matches('0');
+        if fraction.is_empty() {
+            integer.to_string()
+        } else {
+            format!("{integer}.{fraction}")
+        }
+    }
+}
+
+impl TryFrom<&Irc30Metadata> for MetadataFeature {
+    type Error = Error;
+
+    fn try_from(value: &Irc30Metadata) -> crate::wallet::Result<Self> {
+        Ok(Self::new(value.to_bytes()?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> Irc30Metadata {
+        Irc30Metadata::new("My Token", "MYT", 4)
+            .with_description("A token for testing")
+            .with_url("https://mytoken.com")
+            .with_logo_url("https://mytoken.com/logo.png")
+    }
+
+    #[test]
+    fn serialize_and_parse() {
+        let bytes = metadata().to_bytes().unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(json["standard"], "IRC30");
+        assert_eq!(json["logoUrl"], "https://mytoken.com/logo.png");
+        assert!(json.get("logo").is_none());
+
+        assert_eq!(Irc30Metadata::from_bytes(&bytes).unwrap(), metadata());
+        assert!(Irc30Metadata::from_bytes(
+            br#"{"standard":"IRC30","name":"Token","symbol":"TKN","decimals":0,"extra":1}"#
+        )
+        .is_ok());
+        assert!(Irc30Metadata::from_bytes(br#"{"standard":"IRC27","name":"","symbol":"TKN","decimals":0}"#).is_err());
+    }
+
+    #[test]
+    fn format_amount() {
+        let metadata = metadata();
+        assert_eq!(metadata.format_amount(U256::from(1_234_500u64)), "123.45");
+        assert_eq!(metadata.format_amount(U256::from(10_000u64)), "1");
+        assert_eq!(metadata.format_amount(U256::from(5u64)), "0.0005");
+        assert_eq!(metadata.format_amount(U256::zero()), "0");
+        assert_eq!(
+            Irc30Metadata::new("My Token", "MYT", 0).format_amount(U256::from(42u64)),
+            "42"
+        );
+    }
+}
@@ -6,8 +6,11 @@ pub(crate) mod address;
 /// Custom de/serialization for [`address::AddressWrapper`]
 pub(crate) mod address_serde;
 pub(crate) mod balance;
+pub(crate) mod irc_27;
+pub(crate) mod irc_30;
 #[cfg(feature = "participation")]
 pub mod participation;
+pub(crate) mod transaction_kind;
 
 use std::str::FromStr;
 
@@ -17,8 +20,14 @@ use serde::{Deserialize, Deserializer, Serialize};
 pub use self::{
     address::{AccountAddress, AddressWithUnspentOutputs, AddressWrapper},
     balance::{
-        AccountBalance, AccountBalanceDto, BaseCoinBalance, BaseCoinBalanceDto, NativeTokensBalance,
-        NativeTokensBalanceDto, RequiredStorageDeposit,
+        AccountBalance, AccountBalanceDto, BalancePoint, BaseCoinBalance, BaseCoinBalanceDto, HistoricalBalance,
+        HistoricalBalanceDto, NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
+    },
+    irc_27::{Irc27Attribute, Irc27Metadata, NftIrc27Metadata, IRC_27_STANDARD, IRC_27_VERSION},
+    irc_30::{Irc30Metadata, IRC_30_STANDARD},
+    transaction_kind::{
+        TransactionAssets, TransactionAssetsDto, TransactionClassification, TransactionClassificationDto,
+        TransactionKind,
     },
 };
 use crate::{
@@ -188,6 +197,8 @@ pub struct TransactionDto {
     pub incoming: bool,
     pub note: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The kind of the transaction and the assets involved
+    pub classification: TransactionClassificationDto,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -202,6 +213,7 @@ impl From<&Transaction> for TransactionDto {
             incoming: value.incoming,
             note: value.note.clone(),
             inputs: value.inputs.clone(),
+            classification: TransactionClassificationDto::from(&value.classification()),
         }
     }
 }
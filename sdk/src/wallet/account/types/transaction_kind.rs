@@ -0,0 +1,654 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::{
+        api::plugins::participation::types::PARTICIPATION_TAG,
+        block::{
+            address::Address,
+            output::{
+                dto::NativeTokenDto, AliasId, FoundryId, NativeToken, NftId, Output, OutputId, TokenId, TokenScheme,
+            },
+            payload::{transaction::TransactionEssence, Payload},
+        },
+    },
+    wallet::account::types::Transaction,
+};
+
+/// The kind of a transaction, decoded from its inputs and outputs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionKind {
+    /// Base coins or native tokens sent to other addresses.
+    Send,
+    /// Base coins or native tokens received from other addresses.
+    Receive,
+    /// Nfts sent to or received from other addresses.
+    NftTransfer,
+    /// Native tokens minted by a foundry.
+    MintNativeTokens,
+    /// Native tokens melted by a foundry.
+    MeltNativeTokens,
+    /// Native tokens burned without melting them.
+    BurnNativeTokens,
+    /// Nfts minted.
+    MintNfts,
+    /// Nfts burned.
+    BurnNfts,
+    /// Alias outputs created.
+    CreateAlias,
+    /// Alias outputs destroyed.
+    DestroyAlias,
+    /// A state transition of an alias output.
+    AliasStateTransition,
+    /// A governance transition of an alias output.
+    AliasGovernanceTransition,
+    /// Foundry outputs created.
+    CreateFoundry,
+    /// Foundry outputs destroyed.
+    DestroyFoundry,
+    /// Outputs merged into fewer outputs of the same address.
+    Consolidation,
+    /// Outputs with a storage deposit return, expiration or timelock unlock condition claimed.
+    Claim,
+    /// Voting power or votes of a participation changed.
+    Participation,
+    /// None of the other kinds.
+    Other,
+}
+
+/// The assets involved in a transaction, depending on its [`TransactionKind`].
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAssets {
+    /// Amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: u64,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeToken>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+    /// Foundries
+    pub foundries: Vec<FoundryId>,
+}
+
+/// Dto for [`TransactionAssets`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionAssetsDto {
+    /// Amount of the base coin
+    #[serde(rename = "baseCoin")]
+    pub base_coin: String,
+    /// Native tokens
+    #[serde(rename = "nativeTokens")]
+    pub native_tokens: Vec<NativeTokenDto>,
+    /// Nfts
+    pub nfts: Vec<NftId>,
+    /// Aliases
+    pub aliases: Vec<AliasId>,
+    /// Foundries
+    pub foundries: Vec<FoundryId>,
+}
+
+impl From<&TransactionAssets> for TransactionAssetsDto {
+    fn from(value: &TransactionAssets) -> Self {
+        Self {
+            base_coin: value.base_coin.to_string(),
+            native_tokens: value.native_tokens.iter().map(NativeTokenDto::from).collect(),
+            nfts: value.nfts.clone(),
+            aliases: value.aliases.clone(),
+            foundries: value.foundries.clone(),
+        }
+    }
+}
+
+/// The kind of a transaction and the assets involved, returned from [`Transaction::classification()`].
+///
+/// The assets are:
+/// - for [`TransactionKind::Send`], [`TransactionKind::Receive`] and [`TransactionKind::NftTransfer`], the assets moved
+///   to addresses which didn't unlock an input;
+/// - for minting, melting and burning, the minted, melted or burned native tokens and nfts and the foundries;
+/// - for alias and foundry transitions, the aliases and foundries;
+/// - for [`TransactionKind::Claim`], the claimed outputs;
+/// - for [`TransactionKind::Consolidation`] and [`TransactionKind::Participation`], the amount of the created outputs.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionClassification {
+    /// The kind of the transaction
+    pub kind: TransactionKind,
+    /// The assets involved
+    pub assets: TransactionAssets,
+}
+
+/// Dto for [`TransactionClassification`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionClassificationDto {
+    /// The kind of the transaction
+    pub kind: TransactionKind,
+    /// The assets involved
+    pub assets: TransactionAssetsDto,
+}
+
+impl From<&TransactionClassification> for TransactionClassificationDto {
+    fn from(value: &TransactionClassification) -> Self {
+        Self {
+            kind: value.kind,
+            assets: TransactionAssetsDto::from(&value.assets),
+        }
+    }
+}
+
+// Sums of the assets of outputs
+#[derive(Default)]
+struct AssetSums {
+    base_coin: u64,
+    native_tokens: HashMap<TokenId, U256>,
+    nfts: Vec<NftId>,
+}
+
+impl AssetSums {
+    fn add(&mut self, output: &Output, output_id: &OutputId) {
+        self.base_coin += output.amount();
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                *self.native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+            }
+        }
+        if let Output::Nft(nft_output) = output {
+            self.nfts.push(nft_output.nft_id_non_null(output_id));
+        }
+    }
+
+    fn finish(self) -> TransactionAssets {
+        TransactionAssets {
+            base_coin: self.base_coin,
+            native_tokens: native_tokens(self.native_tokens),
+            nfts: self.nfts,
+            ..Default::default()
+        }
+    }
+}
+
+impl Transaction {
+    /// Classifies the transaction by decoding its inputs and outputs. Transactions with unknown inputs, like incoming
+    /// transactions whose inputs got pruned by the node before they were requested, can only be classified as
+    /// [`TransactionKind::Send`] or [`TransactionKind::Receive`].
+    pub fn classification(&self) -> TransactionClassification {
+        let time = (self.timestamp / 1000) as u32;
+        let TransactionEssence::Regular(essence) = self.payload.essence();
+
+        let inputs = self
+            .inputs
+            .iter()
+            .filter_map(|input| {
+                Some((
+                    input.metadata.output_id().ok()?,
+                    Output::try_from_dto_unverified(&input.output).ok()?,
+                ))
+            })
+            .collect::<Vec<_>>();
+        let outputs = essence
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| Some((OutputId::new(self.transaction_id, index as u16).ok()?, output)))
+            .collect::<Vec<_>>();
+
+        // The addresses which unlocked inputs, assets sent to them stay with the sender
+        let mut input_addresses = Vec::new();
+        for (output_id, output) in &inputs {
+            if let Ok((required_address, unlocked_address)) =
+                output.required_and_unlocked_address(time, output_id, None)
+            {
+                input_addresses.push(required_address);
+                input_addresses.extend(unlocked_address);
+            }
+        }
+        let owner = |output: &Output, output_id: &OutputId| -> Option<Address> {
+            output
+                .required_and_unlocked_address(time, output_id, None)
+                .ok()
+                .map(|(address, _)| address)
+        };
+
+        let mut sent = AssetSums::default();
+        for (output_id, output) in &outputs {
+            if !matches!(owner(output, output_id), Some(address) if input_addresses.contains(&address)) {
+                sent.add(output, output_id);
+            }
+        }
+        let sent_kind = if self.incoming {
+            TransactionKind::Receive
+        } else {
+            TransactionKind::Send
+        };
+
+        if inputs.len() < essence.inputs().len() {
+            return classification(sent_kind, sent.finish());
+        }
+
+        // Foundries
+        let foundry_supplies = |outputs: &[(OutputId, &Output)]| {
+            outputs
+                .iter()
+                .filter_map(|(_, output)| match output {
+                    Output::Foundry(foundry) => {
+                        let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+                        Some((
+                            foundry.id(),
+                            (token_scheme.minted_tokens(), token_scheme.melted_tokens()),
+                        ))
+                    }
+                    _ => None,
+                })
+                .collect::<HashMap<_, _>>()
+        };
+        let input_refs = inputs.iter().map(|(id, output)| (*id, output)).collect::<Vec<_>>();
+        let input_foundries = foundry_supplies(&input_refs);
+        let output_foundries = foundry_supplies(&outputs);
+
+        let mut minted = HashMap::new();
+        let mut melted = HashMap::new();
+        for (foundry_id, (minted_tokens, melted_tokens)) in &output_foundries {
+            let (previously_minted, previously_melted) = input_foundries.get(foundry_id).copied().unwrap_or_default();
+            let token_id = TokenId::from(*foundry_id);
+            if *minted_tokens > previously_minted {
+                minted.insert(token_id, minted_tokens - previously_minted);
+            }
+            if *melted_tokens > previously_melted {
+                melted.insert(token_id, melted_tokens - previously_melted);
+            }
+        }
+        let created_foundries = difference(output_foundries.keys(), input_foundries.keys());
+        let destroyed_foundries = difference(input_foundries.keys(), output_foundries.keys());
+
+        if !created_foundries.is_empty() {
+            return classification(
+                TransactionKind::CreateFoundry,
+                TransactionAssets {
+                    native_tokens: native_tokens(minted),
+                    foundries: created_foundries,
+                    ..Default::default()
+                },
+            );
+        }
+        if !destroyed_foundries.is_empty() {
+            return classification(
+                TransactionKind::DestroyFoundry,
+                TransactionAssets {
+                    foundries: destroyed_foundries,
+                    ..Default::default()
+                },
+            );
+        }
+        if !minted.is_empty() {
+            return classification(
+                TransactionKind::MintNativeTokens,
+                TransactionAssets {
+                    foundries: minted.keys().map(|token_id| FoundryId::from(*token_id)).collect(),
+                    native_tokens: native_tokens(minted),
+                    ..Default::default()
+                },
+            );
+        }
+        if !melted.is_empty() {
+            return classification(
+                TransactionKind::MeltNativeTokens,
+                TransactionAssets {
+                    foundries: melted.keys().map(|token_id| FoundryId::from(*token_id)).collect(),
+                    native_tokens: native_tokens(melted),
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Native tokens which are neither in the outputs nor melted got burned
+        let mut input_sums = AssetSums::default();
+        for (output_id, output) in &inputs {
+            input_sums.add(output, output_id);
+        }
+        let mut output_sums = AssetSums::default();
+        for (output_id, output) in &outputs {
+            output_sums.add(output, output_id);
+        }
+        let burned = input_sums
+            .native_tokens
+            .iter()
+            .filter_map(|(token_id, input_amount)| {
+                let output_amount = output_sums.native_tokens.get(token_id).copied().unwrap_or_default();
+                (*input_amount > output_amount).then(|| (*token_id, input_amount - output_amount))
+            })
+            .collect::<HashMap<_, _>>();
+        if !burned.is_empty() {
+            return classification(
+                TransactionKind::BurnNativeTokens,
+                TransactionAssets {
+                    native_tokens: native_tokens(burned),
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Aliases
+        let alias_ids = |outputs: &[(OutputId, &Output)]| {
+            outputs
+                .iter()
+                .filter_map(|(output_id, output)| match output {
+                    Output::Alias(alias) => Some((alias.alias_id_non_null(output_id), alias.state_index())),
+                    _ => None,
+                })
+                .collect::<HashMap<_, _>>()
+        };
+        let input_aliases = alias_ids(&input_refs);
+        let output_aliases = alias_ids(&outputs);
+        let created_aliases = difference(output_aliases.keys(), input_aliases.keys());
+        let destroyed_aliases = difference(input_aliases.keys(), output_aliases.keys());
+
+        if !created_aliases.is_empty() {
+            return classification(
+                TransactionKind::CreateAlias,
+                TransactionAssets {
+                    aliases: created_aliases,
+                    ..Default::default()
+                },
+            );
+        }
+        if !destroyed_aliases.is_empty() {
+            return classification(
+                TransactionKind::DestroyAlias,
+                TransactionAssets {
+                    aliases: destroyed_aliases,
+                    ..Default::default()
+                },
+            );
+        }
+
+        // Nfts
+        let minted_nfts = outputs
+            .iter()
+            .filter_map(|(output_id, output)| match output {
+                Output::Nft(nft) if nft.nft_id().is_null() => Some(nft.nft_id_non_null(output_id)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let burned_nfts = difference(input_sums.nfts.iter(), output_sums.nfts.iter());
+
+        if !minted_nfts.is_empty() {
+            return classification(
+                TransactionKind::MintNfts,
+                TransactionAssets {
+                    nfts: minted_nfts,
+                    ..Default::default()
+                },
+            );
+        }
+        if !burned_nfts.is_empty() {
+            return classification(
+                TransactionKind::BurnNfts,
+                TransactionAssets {
+                    nfts: burned_nfts,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let is_participation_tag = |tag: &[u8]| tag == PARTICIPATION_TAG.as_bytes();
+        let participation_outputs = outputs
+            .iter()
+            .filter(|(_, output)| {
+                matches!(
+                    output.features().and_then(|features| features.tag()),
+                    Some(tag) if is_participation_tag(tag.tag())
+                )
+            })
+            .collect::<Vec<_>>();
+        let has_participation_payload = matches!(
+            essence.payload(),
+            Some(Payload::TaggedData(tagged_data)) if is_participation_tag(tagged_data.tag())
+        );
+        if has_participation_payload || !participation_outputs.is_empty() {
+            return classification(
+                TransactionKind::Participation,
+                TransactionAssets {
+                    base_coin: participation_outputs.iter().map(|(_, output)| output.amount()).sum(),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let mut claimed = AssetSums::default();
+        for (output_id, output) in &inputs {
+            if let Some(unlock_conditions) = output.unlock_conditions() {
+                if unlock_conditions.storage_deposit_return().is_some()
+                    || unlock_conditions.expiration().is_some()
+                    || unlock_conditions.timelock().is_some()
+                {
+                    claimed.add(output, output_id);
+                }
+            }
+        }
+        if claimed.base_coin > 0 {
+            return classification(TransactionKind::Claim, claimed.finish());
+        }
+
+        if !sent.nfts.is_empty() {
+            return classification(
+                TransactionKind::NftTransfer,
+                TransactionAssets {
+                    nfts: sent.nfts,
+                    ..Default::default()
+                },
+            );
+        }
+        if sent.base_coin > 0 {
+            return classification(sent_kind, sent.finish());
+        }
+
+        for (alias_id, state_index) in &output_aliases {
+            if let Some(previous_state_index) = input_aliases.get(alias_id) {
+                let kind = if state_index == previous_state_index {
+                    TransactionKind::AliasGovernanceTransition
+                } else {
+                    TransactionKind::AliasStateTransition
+                };
+                return classification(
+                    kind,
+                    TransactionAssets {
+                        aliases: vec![*alias_id],
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        let only_basic_outputs =
+            inputs.iter().all(|(_, output)| output.is_basic()) && outputs.iter().all(|(_, output)| output.is_basic());
+        if only_basic_outputs && outputs.len() < inputs.len() {
+            return classification(
+                TransactionKind::Consolidation,
+                TransactionAssets {
+                    base_coin: output_sums.base_coin,
+                    ..Default::default()
+                },
+            );
+        }
+
+        classification(TransactionKind::Other, TransactionAssets::default())
+    }
+}
+
+fn classification(kind: TransactionKind, assets: TransactionAssets) -> TransactionClassification {
+    TransactionClassification { kind, assets }
+}
+
+// The elements of `a` which aren't in `b`
+fn difference<'a, T: 'a + Copy + PartialEq>(
+    a: impl Iterator<Item = &'a T>,
+    b: impl Iterator<Item = &'a T> + Clone,
+) -> Vec<T> {
+    a.filter(|element| !b.clone().any(|other| other == *element))
+        .copied()
+        .collect()
+}
+
+fn native_tokens(amounts: HashMap<TokenId, U256>) -> Vec<NativeToken> {
+    let mut native_tokens = amounts
+        .into_iter()
+        .filter_map(|(token_id, amount)| NativeToken::new(token_id, amount).ok())
+        .collect::<Vec<_>>();
+    native_tokens.sort_by_key(|native_token| *native_token.token_id());
+    native_tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::{
+            api::core::response::OutputWithMetadataResponse,
+            block::{
+                address::Ed25519Address,
+                input::{Input, UtxoInput},
+                output::{
+                    dto::{OutputDto, OutputMetadataDto},
+                    unlock_condition::AddressUnlockCondition,
+                    BasicOutputBuilder, InputsCommitment, NftOutputBuilder,
+                },
+                payload::{
+                    transaction::{RegularTransactionEssence, TransactionId},
+                    TransactionPayload,
+                },
+                protocol::protocol_parameters,
+                signature::{Ed25519Signature, Signature},
+                unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+                BlockId,
+            },
+        },
+        wallet::account::types::InclusionState,
+    };
+
+    const SENDER: [u8; 32] = [1; 32];
+    const RECIPIENT: [u8; 32] = [2; 32];
+
+    fn basic_output(amount: u64, address: [u8; 32]) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::new(address))))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap()
+    }
+
+    fn transaction(inputs: Vec<Output>, outputs: Vec<Output>, incoming: bool) -> Transaction {
+        let protocol_parameters = protocol_parameters();
+        let inputs = inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, output)| {
+                let output_id = OutputId::new(TransactionId::new([3; 32]), index as u16).unwrap();
+                (
+                    output_id,
+                    OutputWithMetadataResponse {
+                        metadata: OutputMetadataDto {
+                            block_id: BlockId::new([4; 32]).to_string(),
+                            transaction_id: output_id.transaction_id().to_string(),
+                            output_index: output_id.index(),
+                            is_spent: true,
+                            milestone_index_spent: None,
+                            milestone_timestamp_spent: None,
+                            transaction_id_spent: None,
+                            milestone_index_booked: 1,
+                            milestone_timestamp_booked: 1,
+                            ledger_index: 1,
+                        },
+                        output: OutputDto::from(&output),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let unlocks = (0..inputs.len())
+            .map(|index| {
+                if index == 0 {
+                    Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(Ed25519Signature::new(
+                        [0; 32], [0; 64],
+                    ))))
+                } else {
+                    Unlock::Reference(ReferenceUnlock::new(0).unwrap())
+                }
+            })
+            .collect::<Vec<_>>();
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs(
+                    inputs
+                        .iter()
+                        .map(|(output_id, _)| Input::Utxo(UtxoInput::from(*output_id)))
+                        .collect(),
+                )
+                .with_outputs(outputs)
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+        let payload = TransactionPayload::new(essence, Unlocks::new(unlocks).unwrap()).unwrap();
+
+        Transaction {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            inclusion_state: InclusionState::Confirmed,
+            timestamp: 0,
+            network_id: protocol_parameters.network_id(),
+            incoming,
+            note: None,
+            inputs: inputs.into_iter().map(|(_, input)| input).collect(),
+        }
+    }
+
+    #[test]
+    fn send_and_receive() {
+        let inputs = vec![basic_output(3_000_000, SENDER)];
+        let outputs = vec![basic_output(1_000_000, RECIPIENT), basic_output(2_000_000, SENDER)];
+
+        let classification = transaction(inputs.clone(), outputs.clone(), false).classification();
+        assert_eq!(classification.kind, TransactionKind::Send);
+        assert_eq!(classification.assets.base_coin, 1_000_000);
+
+        let classification = transaction(inputs, outputs, true).classification();
+        assert_eq!(classification.kind, TransactionKind::Receive);
+        assert_eq!(classification.assets.base_coin, 1_000_000);
+    }
+
+    #[test]
+    fn consolidation() {
+        let inputs = vec![basic_output(1_000_000, SENDER), basic_output(2_000_000, SENDER)];
+        let outputs = vec![basic_output(3_000_000, SENDER)];
+
+        let classification = transaction(inputs, outputs, false).classification();
+        assert_eq!(classification.kind, TransactionKind::Consolidation);
+        assert_eq!(classification.assets.base_coin, 3_000_000);
+    }
+
+    #[test]
+    fn mint_nfts() {
+        let nft = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .unwrap()
+            .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::new(SENDER))))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+        let inputs = vec![basic_output(3_000_000, SENDER)];
+        let outputs = vec![nft, basic_output(2_000_000, SENDER)];
+
+        let transaction = transaction(inputs, outputs, false);
+        let classification = transaction.classification();
+        assert_eq!(classification.kind, TransactionKind::MintNfts);
+        assert_eq!(
+            classification.assets.nfts,
+            vec![NftId::from(&OutputId::new(transaction.transaction_id, 0).unwrap())]
+        );
+    }
+}
@@ -5,12 +5,15 @@ use std::collections::HashMap;
 
 use crate::{
     client::Client,
-    types::block::output::{dto::OutputMetadataDto, OutputId},
+    types::block::{
+        address::Address,
+        output::{dto::OutputMetadataDto, OutputId},
+    },
     wallet::account::{
         handle::AccountHandle,
         operations::syncing::options::SyncOptions,
         types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
-        AccountAddress,
+        Account, AccountAddress,
     },
 };
 #[cfg(feature = "events")]
@@ -32,6 +35,43 @@ impl AccountHandle {
         Ok(())
     }
 
+    /// Set or remove the label of an address of the account, the label is stored with the account and can be used to
+    /// filter outputs with [`FilterOptions`](crate::wallet::account::FilterOptions).
+    pub async fn set_address_label(&self, bech32_address: &str, label: Option<String>) -> crate::wallet::Result<()> {
+        let address = Address::try_from_bech32(bech32_address)?;
+        let mut account = self.write().await;
+        let Account {
+            public_addresses,
+            internal_addresses,
+            ..
+        } = &mut *account;
+        let account_address = public_addresses
+            .iter_mut()
+            .chain(internal_addresses.iter_mut())
+            .find(|a| a.address.inner == address)
+            .ok_or_else(|| crate::wallet::Error::AddressNotFoundInAccount(bech32_address.to_string()))?;
+        account_address.label = label;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
+    /// Set or remove the label of an output of the account, the label is stored with the account and can be used to
+    /// filter outputs with [`FilterOptions`](crate::wallet::account::FilterOptions).
+    pub async fn set_output_label(&self, output_id: OutputId, label: Option<String>) -> crate::wallet::Result<()> {
+        let mut account = self.write().await;
+        if !account.outputs.contains_key(&output_id) {
+            return Err(crate::wallet::Error::OutputNotFoundInAccount(output_id.to_string()));
+        }
+        match label {
+            Some(label) => account.output_labels.insert(output_id, label),
+            None => account.output_labels.remove(&output_id),
+        };
+        #[cfg(feature = "storage")]
+        self.save(Some(&account)).await?;
+        Ok(())
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -168,6 +208,12 @@ impl AccountHandle {
             }
         }
 
+        // Labels are removed together with their outputs
+        let Account {
+            outputs, output_labels, ..
+        } = &mut *account;
+        output_labels.retain(|output_id, _| outputs.contains_key(output_id));
+
         #[cfg(feature = "storage")]
         {
             log::debug!("[SYNC] storing account {} with new synced data", account.alias());
@@ -2,6 +2,13 @@
 // SPDX-License-Identifier: Apache-2.0
 
 package org.iota.types;
+
+import org.iota.types.ids.AliasId;
+import org.iota.types.ids.FoundryId;
+import org.iota.types.ids.NftId;
+import org.iota.types.ids.OutputId;
+import org.iota.types.ids.TokenId;
+
 public class FilterOptions extends AbstractObject {
 
     /// Filter all outputs where the booked milestone index is below the specified timestamp
@@ -10,6 +17,40 @@ public class FilterOptions extends AbstractObject {
     private Integer upperBoundBookedTimestamp;
     /// Filter all outputs for the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6)
     private Integer[] outputTypes;
+    /// Filter all outputs holding the native token
+    private TokenId nativeTokenId;
+    /// Filter the alias output with the alias id
+    private AliasId aliasId;
+    /// Filter the foundry output with the foundry id
+    private FoundryId foundryId;
+    /// Filter the nft output with the nft id
+    private NftId nftId;
+    /// Filter all outputs of the bech32 address, as account address of the output or in an address unlock condition
+    private String address;
+    /// Filter all outputs with or without a timelock unlock condition
+    private Boolean hasTimelock;
+    /// Filter all outputs with or without an expiration unlock condition
+    private Boolean hasExpiration;
+    /// Filter all outputs with or without a storage deposit return unlock condition
+    private Boolean hasStorageDepositReturn;
+    /// Filter all outputs with an amount below the minimum
+    private Long minAmount;
+    /// Filter all outputs with an amount above the maximum
+    private Long maxAmount;
+    /// Filter all outputs with the hex encoded tag feature
+    private String tag;
+    /// Filter all outputs with a metadata feature containing the hex encoded bytes
+    private String metadata;
+    /// Filter all spent or unspent outputs
+    private Boolean isSpent;
+    /// Filter all outputs with the provided label, set on the output or on the address of the output
+    private String label;
+    /// Order of the outputs: bookedTimestampAscending (default), bookedTimestampDescending, amountAscending or amountDescending
+    private String order;
+    /// Only return the outputs after this output in the order, the last output of the previous page
+    private OutputId cursor;
+    /// The maximum number of returned outputs
+    private Integer limit;
 
     public FilterOptions withLowerBoundBookedTimestamp(Integer lowerBoundBookedTimestamp) {
         this.lowerBoundBookedTimestamp = lowerBoundBookedTimestamp;
@@ -26,6 +67,91 @@ public class FilterOptions extends AbstractObject {
         return this;
     }
 
+    public FilterOptions withNativeTokenId(TokenId nativeTokenId) {
+        this.nativeTokenId = nativeTokenId;
+        return this;
+    }
+
+    public FilterOptions withAliasId(AliasId aliasId) {
+        this.aliasId = aliasId;
+        return this;
+    }
+
+    public FilterOptions withFoundryId(FoundryId foundryId) {
+        this.foundryId = foundryId;
+        return this;
+    }
+
+    public FilterOptions withNftId(NftId nftId) {
+        this.nftId = nftId;
+        return this;
+    }
+
+    public FilterOptions withAddress(String address) {
+        this.address = address;
+        return this;
+    }
+
+    public FilterOptions withHasTimelock(Boolean hasTimelock) {
+        this.hasTimelock = hasTimelock;
+        return this;
+    }
+
+    public FilterOptions withHasExpiration(Boolean hasExpiration) {
+        this.hasExpiration = hasExpiration;
+        return this;
+    }
+
+    public FilterOptions withHasStorageDepositReturn(Boolean hasStorageDepositReturn) {
+        this.hasStorageDepositReturn = hasStorageDepositReturn;
+        return this;
+    }
+
+    public FilterOptions withMinAmount(Long minAmount) {
+        this.minAmount = minAmount;
+        return this;
+    }
+
+    public FilterOptions withMaxAmount(Long maxAmount) {
+        this.maxAmount = maxAmount;
+        return this;
+    }
+
+    public FilterOptions withTag(String tag) {
+        this.tag = tag;
+        return this;
+    }
+
+    public FilterOptions withMetadata(String metadata) {
+        this.metadata = metadata;
+        return this;
+    }
+
+    public FilterOptions withIsSpent(Boolean isSpent) {
+        this.isSpent = isSpent;
+        return this;
+    }
+
+    public FilterOptions withLabel(String label) {
+        this.label = label;
+        return this;
+    }
+
+    public FilterOptions withOrder(String order) {
+        this.order = order;
+        return this;
+    }
+
+    public FilterOptions withCursor(OutputId cursor) {
+        this.cursor = cursor;
+        return this;
+    }
+
+    public FilterOptions withLimit(Integer limit) {
+        this.limit = limit;
+        return this;
+    }
+
     public Integer getLowerBoundBookedTimestamp() {
         return lowerBoundBookedTimestamp;
     }
@@ -37,4 +163,72 @@ public class FilterOptions extends AbstractObject {
     public Integer[] getOutputTypes() {
         return outputTypes;
     }
+
+    public String getLabel() {
+        return label;
+    }
+
+    public TokenId getNativeTokenId() {
+        return nativeTokenId;
+    }
+
+    public AliasId getAliasId() {
+        return aliasId;
+    }
+
+    public FoundryId getFoundryId() {
+        return foundryId;
+    }
+
+    public NftId getNftId() {
+        return nftId;
+    }
+
+    public String getAddress() {
+        return address;
+    }
+
+    public Boolean getHasTimelock() {
+        return hasTimelock;
+    }
+
+    public Boolean getHasExpiration() {
+        return hasExpiration;
+    }
+
+    public Boolean getHasStorageDepositReturn() {
+        return hasStorageDepositReturn;
+    }
+
+    public Long getMinAmount() {
+        return minAmount;
+    }
+
+    public Long getMaxAmount() {
+        return maxAmount;
+    }
+
+    public String getTag() {
+        return tag;
+    }
+
+    public String getMetadata() {
+        return metadata;
+    }
+
+    public Boolean getIsSpent() {
+        return isSpent;
+    }
+
+    public String getOrder() {
+        return order;
+    }
+
+    public OutputId getCursor() {
+        return cursor;
+    }
+
+    public Integer getLimit() {
+        return limit;
+    }
 }
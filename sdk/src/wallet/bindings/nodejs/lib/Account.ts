@@ -5,6 +5,9 @@ import type { MessageHandler } from './MessageHandler';
 import type {
     AccountBalance,
     AccountMetadata,
+    BalancePoint,
+    HistoricalBalance,
+    TransactionHistoryFormat,
     SyncOptions,
     AccountMeta,
     Address,
@@ -14,11 +17,16 @@ import type {
     AddressNftId,
     AddressGenerationOptions,
     AddressWithUnspentOutputs,
+    AliasGovernanceTransitionOptions,
+    ControlledFoundry,
     AliasOutputOptions,
+    AliasStateTransitionOptions,
     FilterOptions,
     IncreaseNativeTokenSupplyOptions,
+    Irc30Metadata,
     MintTokenTransaction,
     NativeTokenOptions,
+    NftIrc27Metadata,
     NftOptions,
     Node,
     OutputData,
@@ -26,14 +34,25 @@ import type {
     OutputsToClaim,
     PreparedTransactionData,
     Transaction,
+    TransactionClassification,
+    SubWalletBalance,
+    SwapOffer,
+    SwapOfferOptions,
+    SwapOfferTransaction,
     TransactionOptions,
+    VestingSchedule,
+    VestingTranche,
+    AccountStakingRewards,
+    Participation,
     ParticipationOverview,
+    ParticipationEventHistory,
     ParticipationEventId,
     ParticipationEventStatus,
     ParticipationEventType,
     ParticipationEventWithNodes,
     ParticipationEventRegistrationOptions,
     ParticipationEventMap,
+    VotingPlan,
 } from '../types';
 import type { SignedTransactionEssence } from '../types/signedTransactionEssence';
 import type {
@@ -345,6 +364,132 @@ export class Account {
         return JSON.parse(resp).payload;
     }
 
+    /**
+     * Hand over the control of a foundry by setting an address as state controller and governor
+     * of its controlling alias. All foundries of the alias are transferred.
+     * @param foundryId The FoundryId.
+     * @param address The Bech32 address which gets the control.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The transaction.
+     */
+    async transferFoundryControl(
+        foundryId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'transferFoundryControl',
+                data: {
+                    foundryId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Change the governor of the alias controlling a foundry, the state controller stays the same.
+     * @param foundryId The FoundryId.
+     * @param governorAddress The Bech32 address of the new governor.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The transaction.
+     */
+    async changeFoundryGovernor(
+        foundryId: string,
+        governorAddress: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'changeFoundryGovernor',
+                data: {
+                    foundryId,
+                    governorAddress,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * List the foundries controlled by alias outputs of the account.
+     * @returns The foundries with their token supply.
+     */
+    async controlledFoundries(): Promise<ControlledFoundry[]> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'controlledFoundries',
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * State transition of an alias output, which increments its state index.
+     * It has to be signed by the state controller.
+     * @param aliasId The AliasId.
+     * @param stateTransitionOptions The new state metadata or amount.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The transaction.
+     */
+    async transitionAliasState(
+        aliasId: string,
+        stateTransitionOptions: AliasStateTransitionOptions,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'transitionAliasState',
+                data: {
+                    aliasId,
+                    stateTransitionOptions,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Governance transition of an alias output, which keeps its state index. It can rotate the
+     * state controller and governor addresses or update the metadata feature.
+     * It has to be signed by the governor.
+     * @param aliasId The AliasId.
+     * @param governanceTransitionOptions The new addresses or metadata.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The transaction.
+     */
+    async transitionAliasGovernance(
+        aliasId: string,
+        governanceTransitionOptions: AliasGovernanceTransitionOptions,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'transitionAliasGovernance',
+                data: {
+                    aliasId,
+                    governanceTransitionOptions,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
     /**
      * Generate a new unused address.
      * @param options Options for address generation.
@@ -395,6 +540,65 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the balance the account held at a milestone timestamp or index.
+     * @param point The milestone timestamp in seconds or milestone index.
+     * @returns The balance at that point.
+     */
+    async getBalanceAt(point: BalancePoint): Promise<HistoricalBalance> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getBalanceAt',
+                data: { point },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the balances the account held from `start` to `end`.
+     * @param start The first milestone timestamp or index.
+     * @param end The last milestone timestamp or index, always included.
+     * @param step The seconds or milestones between two balances.
+     * @returns The balances in chronological order.
+     */
+    async getBalanceHistory(
+        start: BalancePoint,
+        end: BalancePoint,
+        step: number,
+    ): Promise<HistoricalBalance[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getBalanceHistory',
+                data: { start, end, step },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Export the asset movements of the confirmed transactions of the account, for accounting.
+     * @param format The format of the export, `csv` or `json`.
+     * @returns The exported transaction history.
+     */
+    async exportTransactionHistory(
+        format: TransactionHistoryFormat,
+    ): Promise<string> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'exportTransactionHistory',
+                data: { format },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the data for an output.
      * @param outputId The output to get.
@@ -489,6 +693,27 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the IRC30 metadata of a native token. The foundry is taken from the account cache,
+     * or requested from the node and cached if it isn't known yet.
+     * @param tokenId The native token ID.
+     * @returns The IRC30 metadata, or null if the foundry has no metadata.
+     */
+    async getNativeTokenMetadata(
+        tokenId: string,
+    ): Promise<Irc30Metadata | null> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getNativeTokenMetadata',
+                data: {
+                    tokenId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get outputs with additional unlock conditions.
      * @param outputs The type of outputs to claim.
@@ -527,6 +752,26 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the kind and the assets involved of a transaction stored in the account.
+     * @param transactionId The ID of the transaction to classify.
+     * @returns The classification, if the transaction is stored in the account.
+     */
+    async getTransactionClassification(
+        transactionId: string,
+    ): Promise<TransactionClassification | undefined> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getTransactionClassification',
+                data: {
+                    transactionId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the transaction with inputs of an incoming transaction stored in the account
      * List might not be complete, if the node pruned the data already
@@ -595,6 +840,21 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the IRC27 metadata of the unspent NFTs of the account. NFTs whose immutable metadata
+     * isn't valid IRC27 metadata are returned with the reason in `error`.
+     * @returns The NFTs with their IRC27 metadata.
+     */
+    async irc27Nfts(): Promise<NftIrc27Metadata[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'irc27Nfts',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * List all the pending transactions of the account.
      * @returns The transactions.
@@ -950,6 +1210,71 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Offer native tokens to a counterparty.
+     * The native tokens return to the account if the offer isn't accepted before the expiration.
+     * The offer is trust-based, the counterparty can accept it without paying anything in return.
+     * @param swapOfferOptions The offered native tokens.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The offer, which is handed to the counterparty, and the transaction.
+     */
+    async createSwapOffer(
+        swapOfferOptions: SwapOfferOptions,
+        transactionOptions?: TransactionOptions,
+    ): Promise<SwapOfferTransaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'createSwapOffer',
+                data: {
+                    swapOfferOptions,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Verify that a swap offer matches its output and is addressed to the account.
+     * Throws if it doesn't.
+     * @param offer The swap offer.
+     */
+    async verifySwapOffer(offer: SwapOffer): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'verifySwapOffer',
+            data: { offer },
+        });
+    }
+
+    /**
+     * Accept a swap offer, which claims the native tokens and returns the storage deposit to the maker.
+     * Any payment agreed on with the maker has to be sent separately.
+     * @param offer The swap offer.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async acceptSwapOffer(
+        offer: SwapOffer,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'acceptSwapOffer',
+                data: {
+                    offer,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Send native tokens.
      * @param addressesNativeTokens Addresses amounts and native tokens.
@@ -1025,6 +1350,137 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send outputs with funds of the address of an alias or NFT output of the account.
+     * The inputs are only taken from that address and the remainder stays on it.
+     * @param address The Bech32 address of the alias or NFT.
+     * @param outputs The outputs to send.
+     * @param transactionOptions The options to define a tagged data payload or a note,
+     * the inputs and remainder are set by the sub-wallet.
+     * @returns The sent transaction.
+     */
+    async sendFromSubWallet(
+        address: string,
+        outputs: OutputTypes[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendFromSubWallet',
+                data: {
+                    address,
+                    outputs,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send amounts with funds of the address of an alias or NFT output of the account.
+     * @param address The Bech32 address of the alias or NFT.
+     * @param addressesWithAmount Addresses with amounts.
+     * @param transactionOptions The options to define a tagged data payload or a note,
+     * the inputs and remainder are set by the sub-wallet.
+     * @returns The sent transaction.
+     */
+    async sendAmountFromSubWallet(
+        address: string,
+        addressesWithAmount: AddressWithAmount[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendAmountFromSubWallet',
+                data: {
+                    address,
+                    addressesWithAmount,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the balances of the addresses of all alias and NFT outputs of the account.
+     * @returns The sub-wallet balances.
+     */
+    async subWallets(): Promise<SubWalletBalance[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'subWallets',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the balance of the address of an alias or NFT output of the account.
+     * @param address The Bech32 address of the alias or NFT.
+     * @returns The sub-wallet balance.
+     */
+    async subWalletBalance(address: string): Promise<SubWalletBalance> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'subWalletBalance',
+                data: { address },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send vesting schedules, every tranche is sent as timelocked output.
+     * The tranches are sent in batches of up to 127 outputs, one transaction
+     * per batch.
+     * @param schedules The vesting schedules of the recipients.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transactions.
+     */
+    async sendVesting(
+        schedules: VestingSchedule[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendVesting',
+                data: {
+                    schedules,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * List the outputs of the account which are still timelocked, ordered by unlock time.
+     * @returns The pending vesting tranches.
+     */
+    async pendingVestingTranches(): Promise<VestingTranche[]> {
+        const response = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'pendingVestingTranches',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Set the alias for the account
      * @param alias The account alias to set.
@@ -1038,6 +1494,36 @@ export class Account {
         });
     }
 
+    /**
+     * Set or remove the label of an address of the account.
+     * @param address The bech32 address to label.
+     * @param label The label, the label is removed if not provided.
+     */
+    async setAddressLabel(address: string, label?: string): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'setAddressLabel',
+            data: {
+                address,
+                label,
+            },
+        });
+    }
+
+    /**
+     * Set or remove the label of an output of the account.
+     * @param outputId The output to label.
+     * @param label The label, the label is removed if not provided.
+     */
+    async setOutputLabel(outputId: string, label?: string): Promise<void> {
+        await this.messageHandler.callAccountMethod(this.meta.index, {
+            name: 'setOutputLabel',
+            data: {
+                outputId,
+                label,
+            },
+        });
+    }
+
     /**
      * Sign a prepared transaction, useful for offline signing.
      * @param preparedTransactionData The prepared transaction data to sign.
@@ -1129,6 +1615,129 @@ export class Account {
         return JSON.parse(resp).payload;
     }
 
+    /**
+     * Vote for multiple events in a single transaction. The votes are added
+     * to the voting plan and cast again when the voting output gets re-created.
+     * @param votes The events to vote for, with their answers.
+     * @returns The sent transaction.
+     */
+    async voteForEvents(votes: Participation[]): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'voteForEvents',
+                data: {
+                    votes,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Get the voting plan of the account.
+     * @returns The votes the account keeps cast.
+     */
+    async getVotingPlan(): Promise<VotingPlan> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getVotingPlan',
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Remove ended events from the voting plan and cast its votes that
+     * aren't in the voting output yet.
+     * @returns The sent transaction, or undefined if the votes are up to date.
+     */
+    async applyVotingPlan(): Promise<Transaction | undefined> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'applyVotingPlan',
+            },
+        );
+        const parsed = JSON.parse(resp);
+        return parsed.type === 'sentTransaction' ? parsed.payload : undefined;
+    }
+
+    /**
+     * Get the account's participation history for an event.
+     * @param eventId The event to get the history for.
+     * @returns The tally of the event and the participations of the account.
+     */
+    async getParticipationEventHistory(
+        eventId: ParticipationEventId,
+    ): Promise<ParticipationEventHistory> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getParticipationEventHistory',
+                data: {
+                    eventId,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Start staking for an event with the voting power of the account.
+     * @param eventId The staking event to join.
+     * @returns The sent transaction.
+     */
+    async joinStakingEvent(
+        eventId: ParticipationEventId,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'joinStakingEvent',
+                data: {
+                    eventId,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Stop staking for an event.
+     * @param eventId The staking event to leave.
+     * @returns The sent transaction.
+     */
+    async leaveStakingEvent(
+        eventId: ParticipationEventId,
+    ): Promise<Transaction> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'leaveStakingEvent',
+                data: {
+                    eventId,
+                },
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
+    /**
+     * Get the staking rewards the addresses of the account accrued.
+     * @returns The rewards per address and summed up per staking event.
+     */
+    async getStakingRewards(): Promise<AccountStakingRewards> {
+        const resp = await this.messageHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getStakingRewards',
+            },
+        );
+        return JSON.parse(resp).payload;
+    }
+
     async getVotingPower(): Promise<string> {
         const resp = await this.messageHandler.callAccountMethod(
             this.meta.index,
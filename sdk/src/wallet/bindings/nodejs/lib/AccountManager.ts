@@ -8,12 +8,15 @@ import type {
     AccountId,
     AccountManagerOptions,
     Auth,
+    BackupContent,
     ClientOptions,
+    Contact,
     CreateAccountPayload,
     EventType,
     GenerateAddressOptions,
     LedgerNanoStatus,
     NodeInfoWrapper,
+    RestoreBackupOptions,
     SyncOptions,
     WalletEvent,
 } from '../types';
@@ -103,6 +106,20 @@ export class AccountManager {
         });
     }
 
+    /**
+     * Export the wallet and account metadata and the address book to a file
+     * encrypted with the password, independently of the secret manager.
+     */
+    async exportMetadata(destination: string, password: string): Promise<void> {
+        await this.messageHandler.sendMessage({
+            cmd: 'exportMetadata',
+            payload: {
+                destination,
+                password,
+            },
+        });
+    }
+
     /**
      * Generate a random BIP39 mnemonic.
      */
@@ -273,6 +290,37 @@ export class AccountManager {
         return accounts;
     }
 
+    /**
+     * Get the contacts of the address book, sorted by name.
+     */
+    async getAddressBook(): Promise<Contact[]> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'getAddressBook',
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Add a contact to the address book, a contact with the same name is replaced.
+     */
+    async setContact(contact: Contact): Promise<void> {
+        await this.messageHandler.sendMessage({
+            cmd: 'setContact',
+            payload: { contact },
+        });
+    }
+
+    /**
+     * Remove a contact from the address book, returns whether a contact was removed.
+     */
+    async removeContact(name: string): Promise<boolean> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'removeContact',
+            payload: { name },
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Delete the latest account.
      */
@@ -305,6 +353,89 @@ export class AccountManager {
         });
     }
 
+    /**
+     * Import the accounts of a metadata export into the existing accounts and
+     * merge its contacts into the address book.
+     */
+    async importMetadata(
+        source: string,
+        password: string,
+        accounts?: AccountId[],
+    ): Promise<Account[]> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'importMetadata',
+            payload: {
+                source,
+                password,
+                accounts,
+            },
+        });
+        const importedAccounts: Account[] = [];
+
+        for (const account of JSON.parse(response).payload) {
+            importedAccounts.push(new Account(account, this.messageHandler));
+        }
+        return importedAccounts;
+    }
+
+    /**
+     * Restore a backup from a Stronghold file, only the selected accounts and
+     * optionally merged into the existing accounts. Returns the restored
+     * accounts.
+     */
+    async restoreBackupWithOptions(
+        source: string,
+        password: string,
+        options?: RestoreBackupOptions,
+    ): Promise<Account[]> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'restoreBackupWithOptions',
+            payload: {
+                source,
+                password,
+                options,
+            },
+        });
+        const accounts: Account[] = [];
+
+        for (const account of JSON.parse(response).payload) {
+            accounts.push(new Account(account, this.messageHandler));
+        }
+        return accounts;
+    }
+
+    /**
+     * Read the content of a backup, without restoring it.
+     */
+    async readBackupContent(
+        source: string,
+        password: string,
+    ): Promise<BackupContent> {
+        const response = await this.messageHandler.sendMessage({
+            cmd: 'readBackupContent',
+            payload: {
+                source,
+                password,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set the ClientOptions of a single account, binding it to another network
+     * than the account manager. Without ClientOptions the account uses the
+     * network of the account manager again.
+     */
+    async setAccountClientOptions(
+        accountId: AccountId,
+        clientOptions?: ClientOptions,
+    ): Promise<void> {
+        await this.messageHandler.sendMessage({
+            cmd: 'setAccountClientOptions',
+            payload: { accountId, clientOptions },
+        });
+    }
+
     /**
      * Set ClientOptions.
      */
@@ -363,12 +494,12 @@ export class AccountManager {
     }
 
     /**
-     * Store a mnemonic in the Stronghold snapshot.
+     * Store a mnemonic, with an optional BIP-39 passphrase, in the Stronghold snapshot.
      */
-    async storeMnemonic(mnemonic: string): Promise<void> {
+    async storeMnemonic(mnemonic: string, passphrase?: string): Promise<void> {
         await this.messageHandler.sendMessage({
             cmd: 'storeMnemonic',
-            payload: { mnemonic },
+            payload: { mnemonic, passphrase },
         });
     }
 
@@ -1,8 +1,11 @@
 import type { Address, AddressWithUnspentOutputs } from './address';
 import type { OutputData } from './output';
 import type { Transaction } from './transaction';
+import type { ClientOptions } from './network';
+import type { Irc30Metadata } from './transactionOptions';
 import type {
     HexEncodedAmount,
+    INativeToken,
     IOutputResponse,
     ITransactionPayload,
 } from '@iota/types';
@@ -50,14 +53,79 @@ export interface RequiredStorageDeposit {
     nft: string;
 }
 
+/** A timelocked output of the account which isn't unlocked yet */
+export interface VestingTranche {
+    outputId: string;
+    amount: string;
+    /** Unix timestamp in seconds at which the output unlocks */
+    unlockTime: number;
+}
+
+/** A foundry controlled by an alias output of the account */
+export interface ControlledFoundry {
+    foundryId: string;
+    /** The id of the native token minted by the foundry */
+    tokenId: string;
+    /** The id of the alias controlling the foundry */
+    aliasId: string;
+    outputId: string;
+    /** Amount of minted tokens minus the amount of melted tokens */
+    circulatingSupply: HexEncodedAmount;
+    mintedTokens: HexEncodedAmount;
+    meltedTokens: HexEncodedAmount;
+    maximumSupply: HexEncodedAmount;
+}
+
+/** The balance of the address of an alias or NFT output of the account */
+export interface SubWalletBalance {
+    /** Bech32 encoded address of the alias or NFT */
+    address: string;
+    /** The id of the alias or NFT output, which is transitioned to unlock the sub-wallet */
+    ownerOutputId: string;
+    /** Amount of the basic outputs owned by the address */
+    total: string;
+    /** Amount of the basic outputs owned by the address which can be spent */
+    available: string;
+    nativeTokens: INativeToken[];
+    /** NFTs owned by the address */
+    nfts: string[];
+    outputIds: string[];
+}
+
 /** The balance of a native token */
 export interface NativeTokenBalance {
     tokenId: string;
     metadata?: string;
+    /** The foundry metadata, if it follows the IRC30 standard */
+    irc30Metadata?: Irc30Metadata;
     total: HexEncodedAmount;
     available: HexEncodedAmount;
+    /** The total amount formatted with the IRC30 decimals */
+    formattedTotal?: string;
+    /** The available amount formatted with the IRC30 decimals */
+    formattedAvailable?: string;
+}
+
+/** A milestone timestamp in seconds or a milestone index */
+export type BalancePoint = { timestamp: number } | { milestoneIndex: number };
+
+/** The balance an account held at a milestone timestamp or index */
+export interface HistoricalBalance {
+    /** The point of the balance */
+    point: BalancePoint;
+    /** The amount of the base coin, without amounts which had to be returned to other addresses */
+    baseCoin: string;
+    /** The native tokens */
+    nativeTokens: INativeToken[];
+    /** Nft outputs */
+    nfts: string[];
+    /** Alias outputs */
+    aliases: string[];
 }
 
+/** The format of a transaction history export */
+export type TransactionHistoryFormat = 'csv' | 'json';
+
 /** Sync options for an account */
 export interface SyncOptions {
     /**
@@ -96,6 +164,8 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Apply the voting plan of an account after it synced in the background syncing, which sends transactions. Default: false. */
+    applyVotingPlan?: boolean;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -139,6 +209,8 @@ export interface AccountMeta {
     incomingTransactions: {
         [transactionId: string]: [ITransactionPayload, IOutputResponse[]];
     };
+    /** Labels set for outputs */
+    outputLabels: { [outputId: string]: string };
 }
 
 /** The account metadata */
@@ -161,6 +233,8 @@ export enum CoinType {
 export interface CreateAccountPayload {
     alias?: string;
     bech32Hrp?: string;
+    /** Client options for the account, if it's bound to another network than the account manager */
+    clientOptions?: ClientOptions;
 }
 
 /** Options to filter outputs */
@@ -171,4 +245,48 @@ export interface FilterOptions {
     upperBoundBookedTimestamp?: number;
     /** Filter all outputs for the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6) */
     outputTypes?: Uint8Array;
+    /** Filter all outputs holding the native token */
+    nativeTokenId?: string;
+    /** Filter the alias output with the alias id */
+    aliasId?: string;
+    /** Filter the foundry output with the foundry id */
+    foundryId?: string;
+    /** Filter the nft output with the nft id */
+    nftId?: string;
+    /** Filter all outputs of the bech32 address, as account address of the output or in an address unlock condition */
+    address?: string;
+    /** Filter all outputs with or without a timelock unlock condition */
+    hasTimelock?: boolean;
+    /** Filter all outputs with or without an expiration unlock condition */
+    hasExpiration?: boolean;
+    /** Filter all outputs with or without a storage deposit return unlock condition */
+    hasStorageDepositReturn?: boolean;
+    /** Filter all outputs with an amount below the minimum */
+    minAmount?: number;
+    /** Filter all outputs with an amount above the maximum */
+    maxAmount?: number;
+    /** Filter all outputs with the hex encoded tag feature */
+    tag?: string;
+    /** Filter all outputs with a metadata feature containing the hex encoded bytes */
+    metadata?: string;
+    /** Filter all spent or unspent outputs */
+    isSpent?: boolean;
+    /** Filter all outputs with the provided label, set on the output or on the address of the output */
+    label?: string;
+    /** The order of the returned outputs, from the oldest to the newest booked output by default */
+    order?: OutputOrder;
+    /**
+     * Only return the outputs after this output id in the order, the last output id of the previous page.
+     * Throws if the output isn't an output of the account.
+     */
+    cursor?: string;
+    /** The maximum number of returned outputs */
+    limit?: number;
 }
+
+/** The order of outputs returned with FilterOptions */
+export type OutputOrder =
+    | 'bookedTimestampAscending'
+    | 'bookedTimestampDescending'
+    | 'amountAscending'
+    | 'amountDescending';
@@ -1,4 +1,4 @@
-import type { CoinType } from './account';
+import type { AccountId, CoinType } from './account';
 import type { ClientOptions } from './network';
 import type { SecretManager } from './secretManager';
 
@@ -9,3 +9,55 @@ export interface AccountManagerOptions {
     coinType?: CoinType;
     secretManager?: SecretManager;
 }
+
+/** Options for restoring a backup */
+export interface RestoreBackupOptions {
+    /** Only restore these accounts of the backup, all accounts if not set */
+    accounts?: AccountId[];
+    /** Add the restored accounts to the existing accounts, keeping the client options, coin type and secret manager */
+    merge?: boolean;
+    /** Same as for `restoreBackup()`, ignored when merging */
+    ignoreIfCoinTypeMismatch?: boolean;
+}
+
+/** The manifest stored in a backup */
+export interface BackupManifest {
+    sdkVersion: string;
+    backupSchemaVersion: number;
+    snapshotVersion: number[];
+    networkName?: string;
+    coinType: CoinType;
+    accountCount: number;
+    createdAt: number;
+}
+
+/** An account of a backup */
+export interface BackupAccount {
+    index: number;
+    alias: string;
+    coinType: CoinType;
+    addressCount: number;
+    transactionCount: number;
+    /** Whether an existing account has the same index or alias */
+    conflict: boolean;
+}
+
+/** The content of a backup */
+export interface BackupContent {
+    /** Not set for backups created before manifests were added */
+    manifest?: BackupManifest;
+    coinType?: CoinType;
+    clientOptions: boolean;
+    secretManager: boolean;
+    accounts: BackupAccount[];
+}
+
+/** A named contact of the address book */
+export interface Contact {
+    /** Unique in the address book */
+    name: string;
+    /** Bech32 encoded address */
+    address: string;
+    /** The bech32 HRP the address must have */
+    expectedHrp?: string;
+}
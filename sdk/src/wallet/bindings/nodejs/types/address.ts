@@ -14,6 +14,7 @@ export interface Address {
     keyIndex: number;
     internal: boolean;
     used: boolean;
+    label?: string;
 }
 
 /** Address with a base token amount */
@@ -1,5 +1,10 @@
 import type { OutputTypes, HexEncodedAmount } from '@iota/types';
-import type { SyncOptions, FilterOptions } from '../account';
+import type {
+    BalancePoint,
+    SyncOptions,
+    FilterOptions,
+    TransactionHistoryFormat,
+} from '../account';
 import type {
     AddressWithAmount,
     AddressWithMicroAmount,
@@ -19,13 +24,19 @@ import type { OutputsToClaim } from '../output';
 import type { SignedTransactionEssence } from '../signedTransactionEssence';
 import type { PreparedTransactionData } from '../preparedTransactionData';
 import type {
+    AliasGovernanceTransitionOptions,
     AliasOutputOptions,
+    AliasStateTransitionOptions,
     IncreaseNativeTokenSupplyOptions,
     NativeTokenOptions,
     TransactionOptions,
     NftOptions,
+    SwapOfferOptions,
+    VestingSchedule,
 } from '../transactionOptions';
+import type { SwapOffer } from '../transaction';
 import type {
+    Participation,
     ParticipationEventId,
     ParticipationEventRegistrationOptions,
     ParticipationEventType,
@@ -123,6 +134,46 @@ export type __DestroyFoundryMethod__ = {
     };
 };
 
+export type __TransferFoundryControlMethod__ = {
+    name: 'transferFoundryControl';
+    data: {
+        foundryId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __ChangeFoundryGovernorMethod__ = {
+    name: 'changeFoundryGovernor';
+    data: {
+        foundryId: string;
+        governorAddress: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __ControlledFoundriesMethod__ = {
+    name: 'controlledFoundries';
+};
+
+export type __TransitionAliasStateMethod__ = {
+    name: 'transitionAliasState';
+    data: {
+        aliasId: string;
+        stateTransitionOptions: AliasStateTransitionOptions;
+        options?: TransactionOptions;
+    };
+};
+
+export type __TransitionAliasGovernanceMethod__ = {
+    name: 'transitionAliasGovernance';
+    data: {
+        aliasId: string;
+        governanceTransitionOptions: AliasGovernanceTransitionOptions;
+        options?: TransactionOptions;
+    };
+};
+
 export type __GenerateAddressesMethod__ = {
     name: 'generateAddresses';
     data: {
@@ -135,6 +186,29 @@ export type __GetBalanceMethod__ = {
     name: 'getBalance';
 };
 
+export type __GetBalanceAtMethod__ = {
+    name: 'getBalanceAt';
+    data: {
+        point: BalancePoint;
+    };
+};
+
+export type __GetBalanceHistoryMethod__ = {
+    name: 'getBalanceHistory';
+    data: {
+        start: BalancePoint;
+        end: BalancePoint;
+        step: number;
+    };
+};
+
+export type __ExportTransactionHistoryMethod__ = {
+    name: 'exportTransactionHistory';
+    data: {
+        format: TransactionHistoryFormat;
+    };
+};
+
 export type __GetIncomingTransactionDataMethod__ = {
     name: 'getIncomingTransactionData';
     data: {
@@ -156,6 +230,13 @@ export type __GetFoundryOutputMethod__ = {
     };
 };
 
+export type __GetNativeTokenMetadataMethod__ = {
+    name: 'getNativeTokenMetadata';
+    data: {
+        tokenId: string;
+    };
+};
+
 export type __GetOutputsWithAdditionalUnlockConditionsMethod__ = {
     name: 'getOutputsWithAdditionalUnlockConditions';
     data: {
@@ -170,6 +251,13 @@ export type __GetTransactionMethod__ = {
     };
 };
 
+export type __GetTransactionClassificationMethod__ = {
+    name: 'getTransactionClassification';
+    data: {
+        transactionId: string;
+    };
+};
+
 export type __AddressesMethod__ = {
     name: 'addresses';
 };
@@ -185,6 +273,10 @@ export type __OutputsMethod__ = {
     };
 };
 
+export type __Irc27NftsMethod__ = {
+    name: 'irc27Nfts';
+};
+
 export type __PendingTransactionsMethod__ = {
     name: 'pendingTransactions';
 };
@@ -301,6 +393,29 @@ export type __SendMicroTransactionMethod__ = {
     };
 };
 
+export type __CreateSwapOfferMethod__ = {
+    name: 'createSwapOffer';
+    data: {
+        swapOfferOptions: SwapOfferOptions;
+        options?: TransactionOptions;
+    };
+};
+
+export type __VerifySwapOfferMethod__ = {
+    name: 'verifySwapOffer';
+    data: {
+        offer: SwapOffer;
+    };
+};
+
+export type __AcceptSwapOfferMethod__ = {
+    name: 'acceptSwapOffer';
+    data: {
+        offer: SwapOffer;
+        options?: TransactionOptions;
+    };
+};
+
 export type __SendNativeTokensMethod__ = {
     name: 'sendNativeTokens';
     data: {
@@ -325,6 +440,47 @@ export type __SendOutputsMethod__ = {
     };
 };
 
+export type __SendFromSubWalletMethod__ = {
+    name: 'sendFromSubWallet';
+    data: {
+        address: string;
+        outputs: OutputTypes[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __SendAmountFromSubWalletMethod__ = {
+    name: 'sendAmountFromSubWallet';
+    data: {
+        address: string;
+        addressesWithAmount: AddressWithAmount[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __SubWalletsMethod__ = {
+    name: 'subWallets';
+};
+
+export type __SubWalletBalanceMethod__ = {
+    name: 'subWalletBalance';
+    data: {
+        address: string;
+    };
+};
+
+export type __SendVestingMethod__ = {
+    name: 'sendVesting';
+    data: {
+        schedules: VestingSchedule[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __PendingVestingTranchesMethod__ = {
+    name: 'pendingVestingTranches';
+};
+
 export type __SetAliasMethod__ = {
     name: 'setAlias';
     data: {
@@ -332,6 +488,22 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetAddressLabelMethod__ = {
+    name: 'setAddressLabel';
+    data: {
+        address: string;
+        label?: string;
+    };
+};
+
+export type __SetOutputLabelMethod__ = {
+    name: 'setOutputLabel';
+    data: {
+        outputId: string;
+        label?: string;
+    };
+};
+
 export type __SignTransactionEssenceMethod__ = {
     name: 'signTransactionEssence';
     data: {
@@ -368,6 +540,46 @@ export type __StopParticipatingMethod__ = {
     };
 };
 
+export type __VoteForEventsMethod__ = {
+    name: 'voteForEvents';
+    data: {
+        votes: Participation[];
+    };
+};
+
+export type __GetVotingPlanMethod__ = {
+    name: 'getVotingPlan';
+};
+
+export type __ApplyVotingPlanMethod__ = {
+    name: 'applyVotingPlan';
+};
+
+export type __GetParticipationEventHistoryMethod__ = {
+    name: 'getParticipationEventHistory';
+    data: {
+        eventId: ParticipationEventId;
+    };
+};
+
+export type __JoinStakingEventMethod__ = {
+    name: 'joinStakingEvent';
+    data: {
+        eventId: ParticipationEventId;
+    };
+};
+
+export type __LeaveStakingEventMethod__ = {
+    name: 'leaveStakingEvent';
+    data: {
+        eventId: ParticipationEventId;
+    };
+};
+
+export type __GetStakingRewardsMethod__ = {
+    name: 'getStakingRewards';
+};
+
 export type __GetVotingPowerMethod__ = {
     name: 'getVotingPower';
 };
@@ -1,6 +1,7 @@
 import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
 import type { GenerateAddressOptions } from '../address';
 import type { EventType, WalletEvent } from '../event';
+import type { Contact, RestoreBackupOptions } from '../accountManager';
 import type { Auth, ClientOptions } from '../network';
 
 export type __BackupMessage__ = {
@@ -45,6 +46,14 @@ export type __EmitTestEventMessage__ = {
     payload: { event: WalletEvent };
 };
 
+export type __ExportMetadataMessage__ = {
+    cmd: 'exportMetadata';
+    payload: {
+        destination: string;
+        password: string;
+    };
+};
+
 export type __GenerateMnemonicMessage__ = {
     cmd: 'generateMnemonic';
 };
@@ -93,6 +102,15 @@ export type __HexToBech32__ = {
     };
 };
 
+export type __ImportMetadataMessage__ = {
+    cmd: 'importMetadata';
+    payload: {
+        source: string;
+        password: string;
+        accounts?: AccountId[];
+    };
+};
+
 export type __IsStrongholdPasswordAvailableMessage__ = {
     cmd: 'isStrongholdPasswordAvailable';
 };
@@ -107,6 +125,24 @@ export type __RecoverAccountsMessage__ = {
     };
 };
 
+export type __GetAddressBookMessage__ = {
+    cmd: 'getAddressBook';
+};
+
+export type __SetContactMessage__ = {
+    cmd: 'setContact';
+    payload: {
+        contact: Contact;
+    };
+};
+
+export type __RemoveContactMessage__ = {
+    cmd: 'removeContact';
+    payload: {
+        name: string;
+    };
+};
+
 export type __RemoveLatestAccountMessage__ = {
     cmd: 'removeLatestAccount';
 };
@@ -120,6 +156,28 @@ export type __RestoreBackupMessage__ = {
     };
 };
 
+export type __RestoreBackupWithOptionsMessage__ = {
+    cmd: 'restoreBackupWithOptions';
+    payload: {
+        source: string;
+        password: string;
+        options?: RestoreBackupOptions;
+    };
+};
+
+export type __ReadBackupContentMessage__ = {
+    cmd: 'readBackupContent';
+    payload: {
+        source: string;
+        password: string;
+    };
+};
+
+export type __SetAccountClientOptionsMessage__ = {
+    cmd: 'setAccountClientOptions';
+    payload: { accountId: AccountId; clientOptions?: ClientOptions };
+};
+
 export type __SetClientOptionsMessage__ = {
     cmd: 'setClientOptions';
     payload: { clientOptions: ClientOptions };
@@ -149,7 +207,7 @@ export type __StopBackgroundSyncMessage__ = {
 
 export type __StoreMnemonicMessage__ = {
     cmd: 'storeMnemonic';
-    payload: { mnemonic: string };
+    payload: { mnemonic: string; passphrase?: string };
 };
 
 export type __VerifyMnemonicMessage__ = {
@@ -12,16 +12,27 @@ import type {
     __DecreaseNativeTokenSupplyMethod__,
     __DestroyAliasMethod__,
     __DestroyFoundryMethod__,
+    __TransferFoundryControlMethod__,
+    __ChangeFoundryGovernorMethod__,
+    __ControlledFoundriesMethod__,
+    __TransitionAliasStateMethod__,
+    __TransitionAliasGovernanceMethod__,
     __DeregisterParticipationEventMethod__,
     __GenerateAddressesMethod__,
     __GetBalanceMethod__,
+    __GetBalanceAtMethod__,
+    __GetBalanceHistoryMethod__,
+    __ExportTransactionHistoryMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
+    __GetNativeTokenMetadataMethod__,
     __GetOutputsWithAdditionalUnlockConditionsMethod__,
     __GetTransactionMethod__,
+    __GetTransactionClassificationMethod__,
     __AddressesMethod__,
     __AddressesWithUnspentOutputsMethod__,
     __OutputsMethod__,
+    __Irc27NftsMethod__,
     __PendingTransactionsMethod__,
     __IncomingTransactionsMethod__,
     __TransactionsMethod__,
@@ -38,15 +49,33 @@ import type {
     __RetryTransactionUntilIncludedMethod__,
     __SendAmountMethod__,
     __SendMicroTransactionMethod__,
+    __CreateSwapOfferMethod__,
+    __VerifySwapOfferMethod__,
+    __AcceptSwapOfferMethod__,
     __SendNativeTokensMethod__,
     __SendNftMethod__,
     __SendOutputsMethod__,
+    __SendFromSubWalletMethod__,
+    __SendAmountFromSubWalletMethod__,
+    __SubWalletsMethod__,
+    __SubWalletBalanceMethod__,
+    __SendVestingMethod__,
+    __PendingVestingTranchesMethod__,
     __SetAliasMethod__,
+    __SetAddressLabelMethod__,
+    __SetOutputLabelMethod__,
     __SignTransactionEssenceMethod__,
     __SubmitAndStoreTransactionMethod__,
     __SyncAccountMethod__,
     __GetIncomingTransactionDataMethod__,
     __VoteMethod__,
+    __VoteForEventsMethod__,
+    __GetVotingPlanMethod__,
+    __ApplyVotingPlanMethod__,
+    __GetParticipationEventHistoryMethod__,
+    __JoinStakingEventMethod__,
+    __LeaveStakingEventMethod__,
+    __GetStakingRewardsMethod__,
     __GetVotingPowerMethod__,
     __GetParticipationOverviewMethod__,
     __GetParticipationEventMethod__,
@@ -65,6 +94,7 @@ import type {
     __ClearListenersMessage__,
     __CreateAccountMessage__,
     __EmitTestEventMessage__,
+    __ExportMetadataMessage__,
     __GenerateMnemonicMessage__,
     __GetAccountMessage__,
     __GetAccountIndexesMessage__,
@@ -73,10 +103,17 @@ import type {
     __GenerateAddressMessage__,
     __GetNodeInfoMessage__,
     __HexToBech32__,
+    __ImportMetadataMessage__,
     __IsStrongholdPasswordAvailableMessage__,
     __RecoverAccountsMessage__,
+    __GetAddressBookMessage__,
+    __SetContactMessage__,
+    __RemoveContactMessage__,
     __RemoveLatestAccountMessage__,
     __RestoreBackupMessage__,
+    __RestoreBackupWithOptionsMessage__,
+    __ReadBackupContentMessage__,
+    __SetAccountClientOptionsMessage__,
     __SetClientOptionsMessage__,
     __SetStrongholdPasswordClearIntervalMessage__,
     __SetStrongholdPasswordMessage__,
@@ -100,20 +137,31 @@ export type __AccountMethod__ =
     | __DeregisterParticipationEventMethod__
     | __DestroyAliasMethod__
     | __DestroyFoundryMethod__
+    | __TransferFoundryControlMethod__
+    | __ChangeFoundryGovernorMethod__
+    | __ControlledFoundriesMethod__
+    | __TransitionAliasStateMethod__
+    | __TransitionAliasGovernanceMethod__
     | __GenerateAddressesMethod__
     | __GetBalanceMethod__
+    | __GetBalanceAtMethod__
+    | __GetBalanceHistoryMethod__
+    | __ExportTransactionHistoryMethod__
     | __GetOutputMethod__
     | __GetIncomingTransactionDataMethod__
     | __GetFoundryOutputMethod__
+    | __GetNativeTokenMetadataMethod__
     | __GetOutputsWithAdditionalUnlockConditionsMethod__
     | __GetParticipationEventMethod__
     | __GetParticipationEventIdsMethod__
     | __GetParticipationEventsMethod__
     | __GetParticipationEventStatusMethod__
     | __GetTransactionMethod__
+    | __GetTransactionClassificationMethod__
     | __AddressesMethod__
     | __AddressesWithUnspentOutputsMethod__
     | __OutputsMethod__
+    | __Irc27NftsMethod__
     | __PendingTransactionsMethod__
     | __IncomingTransactionsMethod__
     | __TransactionsMethod__
@@ -131,14 +179,32 @@ export type __AccountMethod__ =
     | __RetryTransactionUntilIncludedMethod__
     | __SendAmountMethod__
     | __SendMicroTransactionMethod__
+    | __CreateSwapOfferMethod__
+    | __VerifySwapOfferMethod__
+    | __AcceptSwapOfferMethod__
     | __SendNativeTokensMethod__
     | __SendNftMethod__
     | __SendOutputsMethod__
+    | __SendFromSubWalletMethod__
+    | __SendAmountFromSubWalletMethod__
+    | __SubWalletsMethod__
+    | __SubWalletBalanceMethod__
+    | __SendVestingMethod__
+    | __PendingVestingTranchesMethod__
     | __SetAliasMethod__
+    | __SetAddressLabelMethod__
+    | __SetOutputLabelMethod__
     | __SignTransactionEssenceMethod__
     | __SubmitAndStoreTransactionMethod__
     | __SyncAccountMethod__
     | __VoteMethod__
+    | __VoteForEventsMethod__
+    | __GetVotingPlanMethod__
+    | __ApplyVotingPlanMethod__
+    | __GetParticipationEventHistoryMethod__
+    | __JoinStakingEventMethod__
+    | __LeaveStakingEventMethod__
+    | __GetStakingRewardsMethod__
     | __StopParticipatingMethod__
     | __GetVotingPowerMethod__
     | __GetParticipationOverviewMethod__
@@ -162,6 +228,7 @@ export type __Message__ =
     | __ClearStrongholdPasswordMessage__
     | __CreateAccountMessage__
     | __EmitTestEventMessage__
+    | __ExportMetadataMessage__
     | __GenerateMnemonicMessage__
     | __GetAccountMessage__
     | __GetAccountIndexesMessage__
@@ -170,10 +237,17 @@ export type __Message__ =
     | __GenerateAddressMessage__
     | __GetNodeInfoMessage__
     | __HexToBech32__
+    | __ImportMetadataMessage__
     | __IsStrongholdPasswordAvailableMessage__
     | __RecoverAccountsMessage__
+    | __GetAddressBookMessage__
+    | __SetContactMessage__
+    | __RemoveContactMessage__
     | __RemoveLatestAccountMessage__
     | __RestoreBackupMessage__
+    | __RestoreBackupWithOptionsMessage__
+    | __ReadBackupContentMessage__
+    | __SetAccountClientOptionsMessage__
     | __SetClientOptionsMessage__
     | __SetStrongholdPasswordClearIntervalMessage__
     | __SetStrongholdPasswordMessage__
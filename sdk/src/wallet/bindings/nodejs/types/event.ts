@@ -1,5 +1,6 @@
 import type { IOutputResponse, ITransactionPayload } from '@iota/types';
 import type { OutputData } from './output';
+import type { StakingStatus } from './participation';
 
 /** Wallet event types */
 export type EventType =
@@ -8,6 +9,7 @@ export type EventType =
     | 'LedgerAddressGeneration'
     | 'NewOutput'
     | 'SpentOutput'
+    | 'StakingEnded'
     | 'TransactionInclusion'
     | 'TransactionProgress';
 
@@ -17,12 +19,19 @@ export type NewOutputEvent = {
     transactionInputs?: IOutputResponse;
 };
 
+export type StakingEndedEvent = {
+    eventId: string;
+    /** Rewards of the account's addresses for the event, keyed by bech32 address */
+    rewards: { [bech32Address: string]: StakingStatus };
+};
+
 /** Wallet events */
 export enum WalletEvent {
     ConsolidationRequired = 'ConsolidationRequired',
     LedgerAddressGeneration = 'LedgerAddressGeneration',
     NewOutput = 'NewOutput',
     SpentOutput = 'SpentOutput',
+    StakingEnded = 'StakingEnded',
     TransactionInclusion = 'TransactionInclusion',
     TransactionProgress = 'TransactionProgress',
 }
@@ -3,6 +3,8 @@ import type { OutputId } from './output';
 
 export interface ParticipationOverview {
     participations: Participations;
+    /** Staking rewards of the account's addresses, keyed by bech32 address */
+    stakingRewards: { [bech32Address: string]: AddressStakingStatus };
 }
 
 export interface Participations {
@@ -19,6 +21,42 @@ export interface TrackedParticipationOverview {
     startMilestoneIndex: number;
 }
 
+export interface StakingStatus {
+    amount: number;
+    symbol: string;
+    minimumReached: boolean;
+}
+
+export interface AddressStakingStatus {
+    /** Rewards keyed by staking event id */
+    rewards: { [eventId: string]: StakingStatus };
+    milestoneIndex: number;
+}
+
+export interface AccountStakingRewards {
+    /** Rewards of the account's addresses, keyed by bech32 address */
+    addresses: { [bech32Address: string]: AddressStakingStatus };
+    /** Rewards of all addresses summed up, keyed by staking event id */
+    total: { [eventId: string]: number };
+}
+
+export interface Participation {
+    eventId: ParticipationEventId;
+    answers: number[];
+}
+
+export interface VotingPlan {
+    votes: Participation[];
+}
+
+export interface ParticipationEventHistory {
+    eventId: ParticipationEventId;
+    status: ParticipationEventStatus;
+    participations: {
+        [outputId: OutputId]: TrackedParticipationOverview;
+    };
+}
+
 export interface ParticipationEvent {
     id: ParticipationEventId;
     data: ParticipationEventData;
@@ -9,6 +9,14 @@ export interface MnemonicSecretManager {
     mnemonic: string;
 }
 
+/** Secret manager that uses a mnemonic and a BIP-39 passphrase. */
+export interface MnemonicWithPassphraseSecretManager {
+    mnemonicWithPassphrase: {
+        mnemonic: string;
+        passphrase: string;
+    };
+}
+
 /** Secret manager that uses a seed. */
 export interface SeedSecretManager {
     hexSeed: string;
@@ -52,5 +60,6 @@ export enum LedgerDeviceType {
 export type SecretManager =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
+    | MnemonicWithPassphraseSecretManager
     | StrongholdSecretManager
     | PlaceholderSecretManager;
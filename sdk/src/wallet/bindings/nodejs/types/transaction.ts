@@ -1,4 +1,8 @@
-import type { IOutputResponse, ITransactionPayload } from '@iota/types';
+import type {
+    INativeToken,
+    IOutputResponse,
+    ITransactionPayload,
+} from '@iota/types';
 
 /** Possible InclusionStates of transactions sent with the wallet */
 export enum InclusionState {
@@ -31,6 +35,69 @@ export interface Transaction {
     /** Note that can be set when sending a transaction and is only stored locally */
     note?: string;
     inputs: IOutputResponse[];
+    /** The kind of the transaction and the assets involved */
+    classification: TransactionClassification;
+}
+
+/** The kind of a transaction, decoded from its inputs and outputs */
+export type TransactionKind =
+    | 'send'
+    | 'receive'
+    | 'nftTransfer'
+    | 'mintNativeTokens'
+    | 'meltNativeTokens'
+    | 'burnNativeTokens'
+    | 'mintNfts'
+    | 'burnNfts'
+    | 'createAlias'
+    | 'destroyAlias'
+    | 'aliasStateTransition'
+    | 'aliasGovernanceTransition'
+    | 'createFoundry'
+    | 'destroyFoundry'
+    | 'consolidation'
+    | 'claim'
+    | 'participation'
+    | 'other';
+
+/** The kind of a transaction and the assets involved */
+export interface TransactionClassification {
+    kind: TransactionKind;
+    assets: TransactionAssets;
+}
+
+/** The assets involved in a transaction */
+export interface TransactionAssets {
+    baseCoin: string;
+    nativeTokens: INativeToken[];
+    nfts: string[];
+    aliases: string[];
+    foundries: string[];
+}
+
+/**
+ * A swap offer of native tokens, which is handed to the counterparty. The offer is trust-based, nothing on-chain
+ * makes the counterparty pay for the native tokens.
+ */
+export interface SwapOffer {
+    /** The id of the output with the offered native tokens */
+    outputId: string;
+    /** Bech32 address of the maker, which gets the storage deposit back */
+    makerAddress: string;
+    /** Bech32 address of the counterparty */
+    counterpartyAddress: string;
+    tokenId: string;
+    /** The offered amount of the native token, hex encoded */
+    amount: string;
+    /** Unix timestamp in seconds after which the native tokens return to the maker */
+    expirationTime: number;
+}
+
+/** The result of a swap offer creation */
+export interface SwapOfferTransaction {
+    offer: SwapOffer;
+    /** The transaction which created the offer output */
+    transaction: Transaction;
 }
 
 /** The result of a minting operation */
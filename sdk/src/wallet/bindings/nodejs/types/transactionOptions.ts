@@ -46,6 +46,55 @@ export interface NativeTokenOptions {
     maximumSupply: HexEncodedAmount;
     /** Hex encoded bytes */
     foundryMetadata?: string;
+    /** IRC30 metadata to use as foundry metadata, can't be combined with `foundryMetadata` */
+    irc30Metadata?: Irc30Metadata;
+}
+
+/** Terms of a swap offer of native tokens */
+export interface SwapOfferOptions {
+    /** Bech32 address of the counterparty, the only one who can accept the offer */
+    counterpartyAddress: string;
+    tokenId: string;
+    /** The offered amount of the native token, hex encoded */
+    amount: HexEncodedAmount;
+    /** Expiration in seconds, after which the native tokens return if the offer wasn't accepted. Default is 1 day */
+    expiration?: number;
+}
+
+/** A vesting schedule of one recipient, every tranche is a timelocked output */
+export interface VestingSchedule {
+    /** Bech32 encoded address of the recipient */
+    address: string;
+    /** Amount of every tranche, has to cover the storage deposit */
+    amountPerTranche: string;
+    /** Number of tranches, at most 127 */
+    tranches: number;
+    /** Unix timestamp in seconds from which the cliff is counted. Default is the current time */
+    startTime?: number;
+    /** Seconds from the start time until the first tranche unlocks */
+    cliff: number;
+    /** Seconds between two tranches */
+    period: number;
+}
+
+/** Native token metadata following the IRC30 standard */
+export interface Irc30Metadata {
+    /** Always `IRC30` */
+    standard: 'IRC30';
+    /** Name of the token */
+    name: string;
+    /** Symbol of the token */
+    symbol: string;
+    /** Number of decimals of the token */
+    decimals: number;
+    /** Description of the token */
+    description?: string;
+    /** URL of the token project */
+    url?: string;
+    /** URL of the token logo */
+    logoUrl?: string;
+    /** Inline logo of the token, e.g. an SVG */
+    logo?: string;
 }
 
 /** Nft options for minting */
@@ -64,6 +113,40 @@ export interface NftOptions {
     issuer?: string;
     /** Hex encoded bytes */
     immutableMetadata?: string;
+    /** IRC27 metadata to use as immutable metadata, can't be combined with `immutableMetadata` */
+    irc27Metadata?: Irc27Metadata;
+}
+
+/** NFT metadata following the IRC27 standard */
+export interface Irc27Metadata {
+    /** Always `IRC27` */
+    standard: 'IRC27';
+    /** The version of the standard, e.g. `v1.0` */
+    version: string;
+    /** The MIME type of the NFT media, e.g. `image/png` */
+    type: string;
+    /** URI of the NFT media */
+    uri: string;
+    /** Name of the NFT */
+    name: string;
+    /** Name of the collection the NFT belongs to */
+    collectionName?: string;
+    /** Royalty shares between 0 and 1 by bech32 encoded address */
+    royalties?: { [address: string]: number };
+    /** Name of the issuer of the NFT */
+    issuerName?: string;
+    /** Description of the NFT */
+    description?: string;
+    /** Attributes of the NFT */
+    attributes?: { trait_type: string; value: string | number | boolean }[];
+}
+
+/** The IRC27 metadata of an NFT owned by the account, or why it couldn't be read */
+export interface NftIrc27Metadata {
+    nftId: string;
+    outputId: string;
+    metadata?: Irc27Metadata;
+    error?: string;
 }
 
 /** Options for the alias output creation */
@@ -79,3 +162,21 @@ export interface AliasOutputOptions {
     /** Hex encoded bytes */
     stateMetadata?: string;
 }
+
+/** Changes of an alias output for a state transition */
+export interface AliasStateTransitionOptions {
+    /** New state metadata, hex encoded bytes. An empty state metadata clears it */
+    stateMetadata?: string;
+    /** New amount of the alias output, raised to the minimum storage deposit if it's lower */
+    amount?: string;
+}
+
+/** Changes of an alias output for a governance transition */
+export interface AliasGovernanceTransitionOptions {
+    /** Bech32 encoded address of the new state controller */
+    stateControllerAddress?: string;
+    /** Bech32 encoded address of the new governor */
+    governorAddress?: string;
+    /** New metadata, hex encoded bytes. An empty metadata removes the metadata feature */
+    metadata?: string;
+}
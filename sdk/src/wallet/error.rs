@@ -25,6 +25,22 @@ pub enum Error {
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
+    /// An account of the backup has the index or alias of an existing account.
+    #[error(
+        "can't restore account {alias} with index {index}: an account with the same index or alias already exists"
+    )]
+    BackupAccountConflict {
+        /// The index of the account in the backup.
+        index: u32,
+        /// The alias of the account in the backup.
+        alias: String,
+    },
+    /// Restoring the accounts of the backup would leave a gap in the account indexes.
+    #[error("can't restore the accounts: the accounts with indexes {missing_indexes:?} would be missing")]
+    BackupAccountIndexGap {
+        /// The account indexes which are neither used by an existing nor by a restored account.
+        missing_indexes: Vec<u32>,
+    },
     /// Error from block crate.
     #[error("{0}")]
     Block(Box<crate::types::block::Error>),
@@ -49,18 +65,42 @@ pub enum Error {
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
+    /// The alias controlling the foundry isn't owned by the account
+    #[error("foundry {0} isn't controlled by an alias output of the account")]
+    FoundryNotControlled(crate::types::block::output::FoundryId),
     /// Insufficient funds to send transaction.
     #[error("insufficient funds {available}/{required} available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// Invalid state or governance transition of an alias output
+    #[error("invalid alias transition: {0}")]
+    InvalidAliasTransition(String),
+    /// Invalid range of a balance history
+    #[error("invalid balance history range: {0}")]
+    InvalidBalanceRange(String),
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
         new_coin_type: u32,
         existing_coin_type: u32,
     },
+    /// Metadata which doesn't follow the IRC27 NFT metadata standard
+    #[error("invalid IRC27 metadata: {0}")]
+    InvalidIrc27Metadata(String),
+    /// Metadata which doesn't follow the IRC30 native token metadata standard
+    #[error("invalid IRC30 metadata: {0}")]
+    InvalidIrc30Metadata(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// The address isn't the address of an alias or NFT output of the account or its outputs can't cover a transaction
+    #[error("invalid sub-wallet: {0}")]
+    InvalidSubWallet(String),
+    /// A swap offer which doesn't match its output
+    #[error("invalid swap offer: {0}")]
+    InvalidSwapOffer(String),
+    /// Invalid vesting schedule
+    #[error("invalid vesting schedule: {0}")]
+    InvalidVestingSchedule(String),
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
@@ -79,6 +119,12 @@ pub enum Error {
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
+    /// Output not found in account
+    #[error("output {0} not found in account")]
+    OutputNotFoundInAccount(String),
+    /// The outputs were built with protocol parameters which changed since, they need to be built again
+    #[error("the protocol parameters changed, the outputs need to be built again: {0}")]
+    ProtocolParametersChanged(Box<crate::client::Error>),
     // TODO more precise error
     /// Voting error
     #[cfg(feature = "participation")]
@@ -112,6 +158,16 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// The backup has been created with a newer SDK version.
+    #[error(
+        "unsupported backup schema version {found}, only versions up to {supported} are supported; the backup was created with a newer SDK"
+    )]
+    UnsupportedBackupVersion {
+        /// The backup schema version of the backup.
+        found: u8,
+        /// The latest backup schema version supported by this SDK.
+        supported: u8,
+    },
 }
 
 // Serialize type with Display error
@@ -70,6 +70,8 @@ impl EventEmitter {
         let event_type = match &event {
             WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
             WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
+            #[cfg(feature = "participation")]
+            WalletEvent::StakingEnded(_) => WalletEventType::StakingEnded,
             WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
             WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
             WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
@@ -1,9 +1,14 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "participation")]
+use std::collections::HashMap;
+
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "participation")]
+use crate::types::api::plugins::participation::types::{ParticipationEventId, StakingStatus};
 use crate::{
     client::api::PreparedTransactionDataDto,
     types::{
@@ -29,6 +34,9 @@ pub enum WalletEvent {
     LedgerAddressGeneration(AddressData),
     NewOutput(Box<NewOutputEvent>),
     SpentOutput(Box<SpentOutputEvent>),
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    StakingEnded(StakingEndedEvent),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
 }
@@ -41,6 +49,9 @@ pub enum WalletEventType {
     LedgerAddressGeneration,
     NewOutput,
     SpentOutput,
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    StakingEnded,
     TransactionInclusion,
     TransactionProgress,
 }
@@ -55,6 +66,8 @@ impl TryFrom<&str> for WalletEventType {
             "LedgerAddressGeneration" => Self::LedgerAddressGeneration,
             "NewOutput" => Self::NewOutput,
             "SpentOutput" => Self::SpentOutput,
+            #[cfg(feature = "participation")]
+            "StakingEnded" => Self::StakingEnded,
             "TransactionInclusion" => Self::TransactionInclusion,
             "TransactionProgress" => Self::TransactionProgress,
             _ => return Err(format!("invalid event type {value}")),
@@ -81,6 +94,16 @@ pub struct SpentOutputEvent {
     pub output: OutputDataDto,
 }
 
+#[cfg(feature = "participation")]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StakingEndedEvent {
+    /// The staking event that ended.
+    #[serde(rename = "eventId")]
+    pub event_id: ParticipationEventId,
+    /// The rewards of the account's addresses for the event, keyed by bech32 address.
+    pub rewards: HashMap<String, StakingStatus>,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct TransactionInclusionEvent {
     #[serde(rename = "transactionId")]
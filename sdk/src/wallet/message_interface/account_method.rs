@@ -26,16 +26,21 @@ use crate::{
                 syncing::SyncOptions,
                 transaction::{
                     high_level::{
+                        alias_transition::{AliasGovernanceTransitionOptionsDto, AliasStateTransitionOptionsDto},
                         create_alias::AliasOutputOptionsDto,
                         minting::{
                             increase_native_token_supply::IncreaseNativeTokenSupplyOptionsDto,
                             mint_native_token::NativeTokenOptionsDto, mint_nfts::NftOptionsDto,
                         },
+                        swap::{SwapOfferDto, SwapOfferOptionsDto},
+                        vesting::VestingScheduleDto,
                     },
                     prepare_output::OutputOptionsDto,
                     TransactionOptionsDto,
                 },
+                transaction_history::TransactionHistoryFormat,
             },
+            types::BalancePoint,
         },
         message_interface::dtos::{AddressWithAmountDto, AddressWithMicroAmountDto},
         AddressAndNftId, AddressNativeTokens,
@@ -44,7 +49,7 @@ use crate::{
 #[cfg(feature = "participation")]
 use crate::{
     client::node_manager::node::Node,
-    types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventType},
+    types::api::plugins::participation::types::{Participation, ParticipationEventId, ParticipationEventType},
 };
 
 /// Each public account method.
@@ -172,6 +177,46 @@ pub enum AccountMethod {
         foundry_id: FoundryId,
         options: Option<TransactionOptionsDto>,
     },
+    /// Hand over the control of a foundry by setting an address as state controller and governor of its controlling
+    /// alias, which transfers all foundries of the alias.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    TransferFoundryControl {
+        #[serde(rename = "foundryId")]
+        foundry_id: FoundryId,
+        address: String,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Change the governor of the alias controlling a foundry, the state controller stays the same.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    ChangeFoundryGovernor {
+        #[serde(rename = "foundryId")]
+        foundry_id: FoundryId,
+        #[serde(rename = "governorAddress")]
+        governor_address: String,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// List the foundries controlled by alias outputs of the account with their token supply.
+    /// Expected response: [`ControlledFoundries`](crate::message_interface::Response::ControlledFoundries)
+    ControlledFoundries,
+    /// State transition of an alias output, which increments its state index.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    TransitionAliasState {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        #[serde(rename = "stateTransitionOptions")]
+        state_transition_options: AliasStateTransitionOptionsDto,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Governance transition of an alias output, which can rotate the state controller and governor addresses or
+    /// update the metadata feature.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    TransitionAliasGovernance {
+        #[serde(rename = "aliasId")]
+        alias_id: AliasIdDto,
+        #[serde(rename = "governanceTransitionOptions")]
+        governance_transition_options: AliasGovernanceTransitionOptionsDto,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Generate new unused addresses.
     /// Expected response: [`GeneratedAddress`](crate::message_interface::Response::GeneratedAddress)
     GenerateAddresses {
@@ -190,6 +235,12 @@ pub enum AccountMethod {
         #[serde(rename = "tokenId")]
         token_id: TokenIdDto,
     },
+    /// Get the IRC30 metadata of a native token from the foundry cache of the account
+    /// Expected response: [`NativeTokenMetadata`](crate::message_interface::Response::NativeTokenMetadata)
+    GetNativeTokenMetadata {
+        #[serde(rename = "tokenId")]
+        token_id: TokenIdDto,
+    },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::message_interface::Response::OutputIds)
     GetOutputsWithAdditionalUnlockConditions {
@@ -202,6 +253,12 @@ pub enum AccountMethod {
         #[serde(rename = "transactionId")]
         transaction_id: TransactionId,
     },
+    /// Get the kind and the assets involved of a transaction stored in the account
+    /// Expected response: [`TransactionClassification`](crate::message_interface::Response::TransactionClassification)
+    GetTransactionClassification {
+        #[serde(rename = "transactionId")]
+        transaction_id: TransactionId,
+    },
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     /// Expected response: [`IncomingTransactionData`](crate::message_interface::Response::IncomingTransactionData)
@@ -228,6 +285,9 @@ pub enum AccountMethod {
         #[serde(rename = "filterOptions")]
         filter_options: Option<FilterOptions>,
     },
+    /// Returns the IRC27 metadata of the unspent NFTs of the account
+    /// Expected response: [`Irc27Nfts`](crate::message_interface::Response::Irc27Nfts)
+    Irc27Nfts,
     /// Returns all incoming transactions of the account
     /// Expected response: [`IncomingTransactionsData`](crate::message_interface::Response::IncomingTransactionsData)
     IncomingTransactions,
@@ -283,6 +343,19 @@ pub enum AccountMethod {
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::message_interface::Response::Balance)
     GetBalance,
+    /// Get the balance the account held at a milestone timestamp or index.
+    /// Expected response: [`HistoricalBalance`](crate::message_interface::Response::HistoricalBalance)
+    GetBalanceAt { point: BalancePoint },
+    /// Get the balances the account held from `start` to `end`, every `step` seconds or milestones.
+    /// Expected response: [`HistoricalBalances`](crate::message_interface::Response::HistoricalBalances)
+    GetBalanceHistory {
+        start: BalancePoint,
+        end: BalancePoint,
+        step: u32,
+    },
+    /// Export the asset movements of the confirmed transactions of the account as CSV or JSON.
+    /// Expected response: [`TransactionHistoryExport`](crate::message_interface::Response::TransactionHistoryExport)
+    ExportTransactionHistory { format: TransactionHistoryFormat },
     /// Prepare an output.
     /// Expected response: [`Output`](crate::message_interface::Response::Output)
     PrepareOutput {
@@ -337,6 +410,23 @@ pub enum AccountMethod {
         addresses_with_micro_amount: Vec<AddressWithMicroAmountDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Offer native tokens to a counterparty, the native tokens return after the expiration if the offer isn't
+    /// accepted. The offer is trust-based, nothing on-chain makes the counterparty pay for the native tokens.
+    /// Expected response: [`SwapOfferTransaction`](crate::message_interface::Response::SwapOfferTransaction)
+    CreateSwapOffer {
+        #[serde(rename = "swapOfferOptions")]
+        swap_offer_options: SwapOfferOptionsDto,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Verify that a swap offer matches its output and is addressed to the account.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    VerifySwapOffer { offer: SwapOfferDto },
+    /// Accept a swap offer, which claims the native tokens and returns the storage deposit to the maker.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    AcceptSwapOffer {
+        offer: SwapOfferDto,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send native tokens.
     /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
     SendNativeTokens {
@@ -351,15 +441,61 @@ pub enum AccountMethod {
         addresses_nft_ids: Vec<AddressAndNftId>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send vesting schedules, every tranche is a timelocked output. Many tranches are split over multiple
+    /// transactions.
+    /// Expected response: [`Transactions`](crate::message_interface::Response::Transactions)
+    SendVesting {
+        schedules: Vec<VestingScheduleDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Returns the unspent outputs of the account which are still timelocked
+    /// Expected response: [`VestingTranches`](crate::message_interface::Response::VestingTranches)
+    PendingVestingTranches,
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     SetAlias { alias: String },
+    /// Set or remove the label of an address of the account.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetAddressLabel { address: String, label: Option<String> },
+    /// Set or remove the label of an output of the account.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetOutputLabel {
+        #[serde(rename = "outputId")]
+        output_id: OutputId,
+        label: Option<String>,
+    },
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
     SendOutputs {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send outputs with funds of the address of an alias or NFT output of the account.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    SendFromSubWallet {
+        /// Bech32 encoded address of the alias or NFT
+        address: String,
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Send amount with funds of the address of an alias or NFT output of the account.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    SendAmountFromSubWallet {
+        /// Bech32 encoded address of the alias or NFT
+        address: String,
+        #[serde(rename = "addressesWithAmount")]
+        addresses_with_amount: Vec<AddressWithAmountDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Get the balances of the addresses of all alias and NFT outputs of the account.
+    /// Expected response: [`SubWallets`](crate::message_interface::Response::SubWallets)
+    SubWallets,
+    /// Get the balance of the address of an alias or NFT output of the account.
+    /// Expected response: [`SubWalletBalance`](crate::message_interface::Response::SubWalletBalance)
+    SubWalletBalance {
+        /// Bech32 encoded address of the alias or NFT
+        address: String,
+    },
     /// Sign a prepared transaction.
     /// Expected response: [`SignedTransactionData`](crate::message_interface::Response::SignedTransactionData)
     SignTransactionEssence {
@@ -395,6 +531,52 @@ pub enum AccountMethod {
         #[serde(rename = "eventId")]
         event_id: ParticipationEventId,
     },
+    /// Vote for multiple events in a single transaction and add the votes to the voting plan.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    VoteForEvents { votes: Vec<Participation> },
+    /// Get the voting plan of the account.
+    /// Expected response: [`VotingPlan`](crate::message_interface::Response::VotingPlan)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    GetVotingPlan,
+    /// Remove ended events from the voting plan and cast its votes that aren't in the voting output yet.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction) or
+    /// [`Ok`](crate::message_interface::Response::Ok) if the votes are already up to date
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    ApplyVotingPlan,
+    /// Get the account's participation history for an event, with the tally of the event.
+    /// Expected response:
+    /// [`ParticipationEventHistory`](crate::message_interface::Response::ParticipationEventHistory)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    GetParticipationEventHistory {
+        #[serde(rename = "eventId")]
+        event_id: ParticipationEventId,
+    },
+    /// Start staking for an event with the voting power of the account.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    JoinStakingEvent {
+        #[serde(rename = "eventId")]
+        event_id: ParticipationEventId,
+    },
+    /// Stop staking for an event.
+    /// Expected response: [`SentTransaction`](crate::message_interface::Response::SentTransaction)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    LeaveStakingEvent {
+        #[serde(rename = "eventId")]
+        event_id: ParticipationEventId,
+    },
+    /// Get the staking rewards the addresses of the account accrued.
+    /// Expected response: [`StakingRewards`](crate::message_interface::Response::StakingRewards)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    GetStakingRewards,
     /// Get the account's total voting power (voting or NOT voting).
     /// Expected response: [`VotingPower`](crate::message_interface::Response::VotingPower)
     #[cfg(feature = "participation")]
@@ -20,7 +20,7 @@ use crate::{
             types::{address::AddressWrapper, AccountAddress, AddressWithUnspentOutputs, TransactionDto},
             Account, OutputDataDto,
         },
-        AddressWithAmount, AddressWithMicroAmount,
+        AddressWithAmount, AddressWithMicroAmount, ClientOptions,
     },
 };
 
@@ -140,6 +140,12 @@ pub struct AccountDto {
     /// Foundries for native tokens in outputs
     #[serde(rename = "nativeTokenFoundries", default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Labels set by the user for outputs
+    #[serde(rename = "outputLabels", default)]
+    pub output_labels: HashMap<OutputId, String>,
+    /// Client options of the account, if it's bound to another network than the wallet
+    #[serde(rename = "clientOptions", default, skip_serializing_if = "Option::is_none")]
+    pub client_options: Option<Box<ClientOptions>>,
 }
 
 impl From<&Account> for AccountDto {
@@ -182,6 +188,8 @@ impl From<&Account> for AccountDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            output_labels: value.output_labels().clone(),
+            client_options: value.client_options().clone().map(Box::new),
         }
     }
 }
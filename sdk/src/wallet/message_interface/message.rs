@@ -1,15 +1,20 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt::{Debug, Formatter, Result};
-#[cfg(feature = "stronghold")]
-use std::path::PathBuf;
+use std::{
+    fmt::{Debug, Formatter, Result},
+    path::PathBuf,
+};
 
 use serde::{Deserialize, Serialize};
 
 use super::account_method::AccountMethod;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{WalletEvent, WalletEventType};
+#[cfg(feature = "storage")]
+use crate::wallet::Contact;
+#[cfg(feature = "stronghold")]
+use crate::wallet::RestoreBackupOptions;
 use crate::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions, Url},
     wallet::{
@@ -31,6 +36,9 @@ pub enum Message {
         /// The bech32 HRP.
         #[serde(rename = "bech32Hrp")]
         bech32_hrp: Option<String>,
+        /// Client options for the account only, if it should be bound to another network than the wallet.
+        #[serde(rename = "clientOptions", default)]
+        client_options: Option<Box<ClientOptions>>,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::message_interface::Response::Account)
@@ -119,6 +127,66 @@ pub enum Message {
         #[serde(rename = "ignoreIfCoinTypeMismatch")]
         ignore_if_coin_type_mismatch: Option<bool>,
     },
+    /// Restore a backup from a Stronghold file, only the selected accounts and optionally merged into the existing
+    /// accounts.
+    /// Expected response: [`Accounts`](crate::message_interface::Response::Accounts)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    RestoreBackupWithOptions {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        password: String,
+        /// Options for selecting and merging the accounts.
+        #[serde(default)]
+        options: RestoreBackupOptions,
+    },
+    /// Read the content of a backup, without restoring it.
+    /// Expected response: [`BackupContent`](crate::message_interface::Response::BackupContent)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    ReadBackupContent {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        password: String,
+    },
+    /// Export the wallet and account metadata and the address book to a file encrypted with the password,
+    /// independently of the secret manager.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    ExportMetadata {
+        /// The export destination.
+        destination: PathBuf,
+        /// The password to encrypt the export with.
+        password: String,
+    },
+    /// Import the accounts of a metadata export into the existing accounts and merge its contacts into the address
+    /// book.
+    /// Expected response: [`Accounts`](crate::message_interface::Response::Accounts)
+    ImportMetadata {
+        /// The path to the export.
+        source: PathBuf,
+        /// The password the export was encrypted with.
+        password: String,
+        /// Only import these accounts, all accounts if not provided.
+        #[serde(default)]
+        accounts: Option<Vec<AccountIdentifier>>,
+    },
+    /// Get the contacts of the address book.
+    /// Expected response: [`AddressBook`](crate::message_interface::Response::AddressBook)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    GetAddressBook,
+    /// Add a contact to the address book, a contact with the same name is replaced.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    SetContact { contact: Contact },
+    /// Remove a contact from the address book.
+    /// Expected response: [`ContactRemoved`](crate::message_interface::Response::ContactRemoved)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    RemoveContact { name: String },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     RemoveLatestAccount,
@@ -134,6 +202,14 @@ pub enum Message {
         #[serde(rename = "clientOptions")]
         client_options: Box<ClientOptions>,
     },
+    /// Updates the client options for a single account, `None` binds the account to the network of the wallet.
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    SetAccountClientOptions {
+        #[serde(rename = "accountId")]
+        account_id: AccountIdentifier,
+        #[serde(rename = "clientOptions")]
+        client_options: Option<Box<ClientOptions>>,
+    },
     /// Generate an address without storing it
     /// Expected response: [`Bech32Address`](crate::message_interface::Response::Bech32Address)
     GenerateAddress {
@@ -177,11 +253,14 @@ pub enum Message {
         #[serde(rename = "intervalInMilliseconds")]
         interval_in_milliseconds: Option<u64>,
     },
-    /// Store a mnemonic into the Stronghold vault.
+    /// Store a mnemonic, with an optional BIP-39 passphrase, into the Stronghold vault.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     #[cfg(feature = "stronghold")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
-    StoreMnemonic { mnemonic: String },
+    StoreMnemonic {
+        mnemonic: String,
+        passphrase: Option<String>,
+    },
     /// Start background syncing.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     StartBackgroundSync {
@@ -191,6 +270,18 @@ pub enum Message {
         #[serde(rename = "intervalInMilliseconds")]
         interval_in_milliseconds: Option<u64>,
     },
+    /// Start syncing the accounts from MQTT events, with a background syncing as fallback.
+    /// Stopped with [`StopBackgroundSync`](crate::message_interface::Message::StopBackgroundSync).
+    /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
+    #[cfg(feature = "mqtt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
+    StartMqttSync {
+        /// Sync options
+        options: Option<SyncOptions>,
+        /// Fallback interval in milliseconds
+        #[serde(rename = "fallbackIntervalInMilliseconds")]
+        fallback_interval_in_milliseconds: Option<u64>,
+    },
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::message_interface::Response::Ok)
     StopBackgroundSync,
@@ -236,9 +327,14 @@ pub enum Message {
 impl Debug for Message {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            Self::CreateAccount { alias, bech32_hrp } => {
-                write!(f, "CreateAccount{{ alias: {alias:?}, bech32_hrp: {bech32_hrp:?} }}")
-            }
+            Self::CreateAccount {
+                alias,
+                bech32_hrp,
+                client_options,
+            } => write!(
+                f,
+                "CreateAccount{{ alias: {alias:?}, bech32_hrp: {bech32_hrp:?}, client_options: {client_options:?} }}"
+            ),
             Self::GetAccountIndexes => write!(f, "GetAccountIndexes"),
             Self::GetAccount { account_id } => write!(f, "GetAccount{{ account_id: {account_id:?} }}"),
             Self::GetAccounts => write!(f, "GetAccounts"),
@@ -282,11 +378,49 @@ impl Debug for Message {
                 f,
                 "RestoreBackup{{ source: {source:?}, password: <ommited>, ignore_if_coin_type_mismatch: {ignore_if_coin_type_mismatch:?} }}"
             ),
+            #[cfg(feature = "stronghold")]
+            Self::RestoreBackupWithOptions {
+                source,
+                password: _,
+                options,
+            } => write!(
+                f,
+                "RestoreBackupWithOptions{{ source: {source:?}, password: <ommited>, options: {options:?} }}"
+            ),
+            #[cfg(feature = "stronghold")]
+            Self::ReadBackupContent { source, password: _ } => {
+                write!(f, "ReadBackupContent{{ source: {source:?}, password: <ommited> }}")
+            }
+            Self::ExportMetadata {
+                destination,
+                password: _,
+            } => write!(f, "ExportMetadata{{ destination: {destination:?}, password: <ommited> }}"),
+            Self::ImportMetadata {
+                source,
+                password: _,
+                accounts,
+            } => write!(
+                f,
+                "ImportMetadata{{ source: {source:?}, password: <ommited>, accounts: {accounts:?} }}"
+            ),
+            #[cfg(feature = "storage")]
+            Self::GetAddressBook => write!(f, "GetAddressBook"),
+            #[cfg(feature = "storage")]
+            Self::SetContact { contact } => write!(f, "SetContact{{ contact: {contact:?} }}"),
+            #[cfg(feature = "storage")]
+            Self::RemoveContact { name } => write!(f, "RemoveContact{{ name: {name:?} }}"),
             Self::GenerateMnemonic => write!(f, "GenerateMnemonic"),
             Self::VerifyMnemonic { mnemonic: _ } => write!(f, "VerifyMnemonic{{ mnemonic: <omitted> }}"),
             Self::SetClientOptions { client_options } => {
                 write!(f, "SetClientOptions{{ client_options: {client_options:?} }}")
             }
+            Self::SetAccountClientOptions {
+                account_id,
+                client_options,
+            } => write!(
+                f,
+                "SetAccountClientOptions{{ account_id: {account_id:?}, client_options: {client_options:?} }}"
+            ),
             #[cfg(feature = "ledger_nano")]
             Self::GetLedgerNanoStatus => write!(f, "GetLedgerNanoStatus"),
             Self::GenerateAddress {
@@ -314,7 +448,7 @@ impl Debug for Message {
                 )
             }
             #[cfg(feature = "stronghold")]
-            Self::StoreMnemonic { mnemonic: _ } => write!(f, "StoreMnemonic{{ mnemonic: <omitted> }}"),
+            Self::StoreMnemonic { .. } => write!(f, "StoreMnemonic{{ mnemonic: <omitted>, passphrase: <omitted> }}"),
             Self::StartBackgroundSync {
                 options,
                 interval_in_milliseconds,
@@ -322,6 +456,14 @@ impl Debug for Message {
                 f,
                 "StartBackgroundSync{{ options: {options:?}, interval: {interval_in_milliseconds:?} }}"
             ),
+            #[cfg(feature = "mqtt")]
+            Self::StartMqttSync {
+                options,
+                fallback_interval_in_milliseconds,
+            } => write!(
+                f,
+                "StartMqttSync{{ options: {options:?}, fallback_interval: {fallback_interval_in_milliseconds:?} }}"
+            ),
             Self::StopBackgroundSync => write!(f, "StopBackgroundSync"),
             #[cfg(feature = "events")]
             Self::EmitTestEvent { event } => write!(f, "EmitTestEvent{{ event: {event:?} }}"),
@@ -34,19 +34,30 @@ use crate::{
     wallet::{
         account::{
             operations::transaction::{
-                high_level::{create_alias::AliasOutputOptions, minting::mint_native_token::MintTokenTransactionDto},
+                high_level::{
+                    alias_transition::{AliasGovernanceTransitionOptions, AliasStateTransitionOptions},
+                    create_alias::AliasOutputOptions,
+                    foundry_control::ControlledFoundryDto,
+                    minting::mint_native_token::MintTokenTransactionDto,
+                    sub_wallet::SubWalletBalanceDto,
+                    swap::{SwapOffer, SwapOfferOptions, SwapOfferTransactionDto},
+                    vesting::{VestingSchedule, VestingTrancheDto},
+                },
                 prepare_output::OutputOptions,
                 TransactionOptions,
             },
-            types::{AccountBalanceDto, AccountIdentifier, TransactionDto},
+            types::{
+                AccountBalanceDto, AccountIdentifier, HistoricalBalanceDto, TransactionClassificationDto,
+                TransactionDto,
+            },
             OutputDataDto,
         },
         message_interface::{
             account_method::AccountMethod, dtos::AccountDto, message::Message, response::Response,
             AddressWithUnspentOutputsDto,
         },
-        AddressWithAmount, AddressWithMicroAmount, IncreaseNativeTokenSupplyOptions, NativeTokenOptions, NftOptions,
-        Result, Wallet,
+        AddressWithAmount, AddressWithMicroAmount, ClientOptions, IncreaseNativeTokenSupplyOptions, NativeTokenOptions,
+        NftOptions, Result, Wallet,
     },
 };
 
@@ -129,8 +140,16 @@ impl WalletMessageHandler {
         log::debug!("Message: {:?}", message);
 
         let response: Result<Response> = match message {
-            Message::CreateAccount { alias, bech32_hrp } => {
-                convert_async_panics(|| async { self.create_account(alias, bech32_hrp).await }).await
+            Message::CreateAccount {
+                alias,
+                bech32_hrp,
+                client_options,
+            } => {
+                convert_async_panics(|| async {
+                    self.create_account(alias, bech32_hrp, client_options.map(|options| *options))
+                        .await
+                })
+                .await
             }
             Message::GetAccount { account_id } => {
                 convert_async_panics(|| async { self.get_account(&account_id).await }).await
@@ -205,6 +224,30 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            #[cfg(feature = "storage")]
+            Message::GetAddressBook => {
+                convert_async_panics(|| async {
+                    let contacts = self.wallet.address_book().await?;
+                    Ok(Response::AddressBook(contacts))
+                })
+                .await
+            }
+            #[cfg(feature = "storage")]
+            Message::SetContact { contact } => {
+                convert_async_panics(|| async {
+                    self.wallet.set_contact(contact).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            #[cfg(feature = "storage")]
+            Message::RemoveContact { name } => {
+                convert_async_panics(|| async {
+                    let removed = self.wallet.remove_contact(&name).await?;
+                    Ok(Response::ContactRemoved(removed))
+                })
+                .await
+            }
             Message::RemoveLatestAccount => {
                 convert_async_panics(|| async {
                     self.wallet.remove_latest_account().await?;
@@ -224,6 +267,60 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            #[cfg(feature = "stronghold")]
+            Message::RestoreBackupWithOptions {
+                source,
+                password,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let account_handles = self
+                        .wallet
+                        .restore_backup_with_options(source.to_path_buf(), password, options)
+                        .await?;
+                    let mut accounts = Vec::new();
+                    for account_handle in account_handles {
+                        let account = account_handle.read().await;
+                        accounts.push(AccountDto::from(&*account));
+                    }
+                    Ok(Response::Accounts(accounts))
+                })
+                .await
+            }
+            #[cfg(feature = "stronghold")]
+            Message::ReadBackupContent { source, password } => {
+                convert_async_panics(|| async {
+                    let backup_content = self.wallet.read_backup_content(source.to_path_buf(), password).await?;
+                    Ok(Response::BackupContent(backup_content))
+                })
+                .await
+            }
+            Message::ExportMetadata { destination, password } => {
+                convert_async_panics(|| async {
+                    self.wallet.export_metadata(destination.to_path_buf(), password).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::ImportMetadata {
+                source,
+                password,
+                accounts,
+            } => {
+                convert_async_panics(|| async {
+                    let account_handles = self
+                        .wallet
+                        .import_metadata(source.to_path_buf(), password, accounts)
+                        .await?;
+                    let mut accounts = Vec::new();
+                    for account_handle in account_handles {
+                        let account = account_handle.read().await;
+                        accounts.push(AccountDto::from(&*account));
+                    }
+                    Ok(Response::Accounts(accounts))
+                })
+                .await
+            }
             Message::GenerateMnemonic => {
                 convert_panics(|| self.wallet.generate_mnemonic().map(Response::GeneratedMnemonic))
             }
@@ -239,6 +336,18 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            Message::SetAccountClientOptions {
+                account_id,
+                client_options,
+            } => {
+                convert_async_panics(|| async {
+                    self.wallet
+                        .set_account_client_options(account_id, client_options.map(|options| *options))
+                        .await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
             #[cfg(feature = "ledger_nano")]
             Message::GetLedgerNanoStatus => {
                 convert_async_panics(|| async {
@@ -302,9 +411,12 @@ impl WalletMessageHandler {
                 .await
             }
             #[cfg(feature = "stronghold")]
-            Message::StoreMnemonic { mnemonic } => {
+            Message::StoreMnemonic { mnemonic, passphrase } => {
                 convert_async_panics(|| async {
-                    self.wallet.store_mnemonic(mnemonic).await?;
+                    match passphrase {
+                        Some(passphrase) => self.wallet.store_mnemonic_with_passphrase(mnemonic, passphrase).await?,
+                        None => self.wallet.store_mnemonic(mnemonic).await?,
+                    }
                     Ok(Response::Ok(()))
                 })
                 .await
@@ -320,6 +432,18 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            #[cfg(feature = "mqtt")]
+            Message::StartMqttSync {
+                options,
+                fallback_interval_in_milliseconds,
+            } => {
+                convert_async_panics(|| async {
+                    let duration = fallback_interval_in_milliseconds.map(Duration::from_millis);
+                    self.wallet.start_mqtt_syncing(options, duration).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
             Message::StopBackgroundSync => {
                 convert_async_panics(|| async {
                     self.wallet.stop_background_syncing().await?;
@@ -586,6 +710,80 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::TransferFoundryControl {
+                foundry_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .transfer_foundry_control(
+                            foundry_id,
+                            address,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::ChangeFoundryGovernor {
+                foundry_id,
+                governor_address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .change_foundry_governor(
+                            foundry_id,
+                            governor_address,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::ControlledFoundries => {
+                let foundries = account_handle.controlled_foundries().await?;
+                Ok(Response::ControlledFoundries(
+                    foundries.iter().map(ControlledFoundryDto::from).collect(),
+                ))
+            }
+            AccountMethod::TransitionAliasState {
+                alias_id,
+                state_transition_options,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .transition_alias_state(
+                            AliasId::try_from(&alias_id)?,
+                            AliasStateTransitionOptions::try_from(&state_transition_options)?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::TransitionAliasGovernance {
+                alias_id,
+                governance_transition_options,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .transition_alias_governance(
+                            AliasId::try_from(&alias_id)?,
+                            AliasGovernanceTransitionOptions::try_from(&governance_transition_options)?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::GenerateAddresses { amount, options } => {
                 let address = account_handle.generate_addresses(amount, options).await?;
                 Ok(Response::GeneratedAddress(address))
@@ -607,12 +805,23 @@ impl WalletMessageHandler {
                 let output = account_handle.get_foundry_output(token_id).await?;
                 Ok(Response::Output(OutputDto::from(&output)))
             }
+            AccountMethod::GetNativeTokenMetadata { token_id } => {
+                let token_id = TokenId::try_from(&token_id)?;
+                let metadata = account_handle.native_token_metadata(token_id).await?;
+                Ok(Response::NativeTokenMetadata(metadata))
+            }
             AccountMethod::GetTransaction { transaction_id } => {
                 let transaction = account_handle.get_transaction(&transaction_id).await;
                 Ok(Response::Transaction(
                     transaction.as_ref().map(TransactionDto::from).map(Box::new),
                 ))
             }
+            AccountMethod::GetTransactionClassification { transaction_id } => {
+                let transaction = account_handle.get_transaction(&transaction_id).await;
+                Ok(Response::TransactionClassification(transaction.map(|transaction| {
+                    TransactionClassificationDto::from(&transaction.classification())
+                })))
+            }
             AccountMethod::GetIncomingTransactionData { transaction_id } => {
                 let transaction = account_handle.get_incoming_transaction_data(&transaction_id).await;
 
@@ -644,6 +853,7 @@ impl WalletMessageHandler {
                 let outputs = account_handle.unspent_outputs(filter_options).await?;
                 Ok(Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect()))
             }
+            AccountMethod::Irc27Nfts => Ok(Response::Irc27Nfts(account_handle.irc_27_nfts().await?)),
             AccountMethod::IncomingTransactions => {
                 let transactions = account_handle.incoming_transactions().await?;
                 Ok(Response::IncomingTransactionsData(
@@ -757,6 +967,20 @@ impl WalletMessageHandler {
             AccountMethod::GetBalance => Ok(Response::Balance(AccountBalanceDto::from(
                 &account_handle.balance().await?,
             ))),
+            AccountMethod::GetBalanceAt { point } => Ok(Response::HistoricalBalance(HistoricalBalanceDto::from(
+                &account_handle.balance_at(point).await?,
+            ))),
+            AccountMethod::GetBalanceHistory { start, end, step } => Ok(Response::HistoricalBalances(
+                account_handle
+                    .balance_history(start, end, step)
+                    .await?
+                    .iter()
+                    .map(HistoricalBalanceDto::from)
+                    .collect(),
+            )),
+            AccountMethod::ExportTransactionHistory { format } => Ok(Response::TransactionHistoryExport(
+                account_handle.export_transaction_history(format).await?,
+            )),
             AccountMethod::PrepareOutput {
                 options,
                 transaction_options,
@@ -861,6 +1085,39 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::CreateSwapOffer {
+                swap_offer_options,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let swap_offer_transaction = account_handle
+                        .create_swap_offer(
+                            SwapOfferOptions::try_from(&swap_offer_options)?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SwapOfferTransaction(SwapOfferTransactionDto::from(
+                        &swap_offer_transaction,
+                    )))
+                })
+                .await
+            }
+            AccountMethod::VerifySwapOffer { offer } => {
+                account_handle.verify_swap_offer(&SwapOffer::try_from(&offer)?).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::AcceptSwapOffer { offer, options } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .accept_swap_offer(
+                            SwapOffer::try_from(&offer)?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::SendNativeTokens {
                 addresses_native_tokens,
                 options,
@@ -891,6 +1148,29 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SendVesting { schedules, options } => {
+                convert_async_panics(|| async {
+                    let transactions = account_handle
+                        .send_vesting(
+                            schedules
+                                .iter()
+                                .map(VestingSchedule::try_from)
+                                .collect::<Result<Vec<_>>>()?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::Transactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
+            AccountMethod::PendingVestingTranches => {
+                let tranches = account_handle.pending_vesting_tranches().await?;
+                Ok(Response::VestingTranches(
+                    tranches.iter().map(VestingTrancheDto::from).collect(),
+                ))
+            }
             AccountMethod::SetAlias { alias } => {
                 convert_async_panics(|| async {
                     account_handle.set_alias(&alias).await?;
@@ -898,6 +1178,20 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SetAddressLabel { address, label } => {
+                convert_async_panics(|| async {
+                    account_handle.set_address_label(&address, label).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            AccountMethod::SetOutputLabel { output_id, label } => {
+                convert_async_panics(|| async {
+                    account_handle.set_output_label(output_id, label).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
             AccountMethod::SendOutputs { outputs, options } => {
                 convert_async_panics(|| async {
                     let token_supply = account_handle.client.get_token_supply().await?;
@@ -914,6 +1208,57 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SendFromSubWallet {
+                address,
+                outputs,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let token_supply = account_handle.client.get_token_supply().await?;
+                    let transaction = account_handle
+                        .send_from_sub_wallet(
+                            address,
+                            outputs
+                                .iter()
+                                .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                                .collect::<crate::wallet::Result<Vec<Output>>>()?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SendAmountFromSubWallet {
+                address,
+                addresses_with_amount,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle
+                        .send_amount_from_sub_wallet(
+                            address,
+                            addresses_with_amount
+                                .iter()
+                                .map(AddressWithAmount::try_from)
+                                .collect::<Result<Vec<AddressWithAmount>>>()?,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SubWallets => {
+                let sub_wallets = account_handle.sub_wallets().await?;
+                Ok(Response::SubWallets(
+                    sub_wallets.iter().map(SubWalletBalanceDto::from).collect(),
+                ))
+            }
+            AccountMethod::SubWalletBalance { address } => {
+                let balance = account_handle.sub_wallet_balance(&address).await?;
+                Ok(Response::SubWalletBalance(SubWalletBalanceDto::from(&balance)))
+            }
             AccountMethod::SignTransactionEssence {
                 prepared_transaction_data,
             } => {
@@ -969,6 +1314,64 @@ impl WalletMessageHandler {
                 .await
             }
             #[cfg(feature = "participation")]
+            AccountMethod::VoteForEvents { votes } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle.vote_for_events(votes).await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::GetVotingPlan => {
+                convert_async_panics(|| async {
+                    let voting_plan = account_handle.get_voting_plan().await?;
+                    Ok(Response::VotingPlan(voting_plan))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::ApplyVotingPlan => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle.apply_voting_plan().await?;
+                    Ok(transaction.map_or(Response::Ok(()), |transaction| {
+                        Response::SentTransaction(TransactionDto::from(&transaction))
+                    }))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::GetParticipationEventHistory { event_id } => {
+                convert_async_panics(|| async {
+                    let history = account_handle.get_participation_event_history(event_id).await?;
+                    Ok(Response::ParticipationEventHistory(history))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::JoinStakingEvent { event_id } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle.join_staking_event(event_id).await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::LeaveStakingEvent { event_id } => {
+                convert_async_panics(|| async {
+                    let transaction = account_handle.leave_staking_event(event_id).await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
+            AccountMethod::GetStakingRewards => {
+                convert_async_panics(|| async {
+                    let rewards = account_handle.get_staking_rewards().await?;
+                    Ok(Response::StakingRewards(rewards))
+                })
+                .await
+            }
+            #[cfg(feature = "participation")]
             AccountMethod::GetVotingPower => {
                 convert_async_panics(|| async {
                     let voting_power = account_handle.get_voting_power().await?;
@@ -1066,7 +1469,12 @@ impl WalletMessageHandler {
     }
 
     /// The create account message handler.
-    async fn create_account(&self, alias: Option<String>, bech32_hrp: Option<String>) -> Result<Response> {
+    async fn create_account(
+        &self,
+        alias: Option<String>,
+        bech32_hrp: Option<String>,
+        client_options: Option<ClientOptions>,
+    ) -> Result<Response> {
         let mut builder = self.wallet.create_account();
 
         if let Some(alias) = alias {
@@ -1077,6 +1485,10 @@ impl WalletMessageHandler {
             builder = builder.with_bech32_hrp(bech32_hrp);
         }
 
+        if let Some(client_options) = client_options {
+            builder = builder.with_client_options(client_options);
+        }
+
         match builder.finish().await {
             Ok(account_handle) => {
                 let account = account_handle.read().await;
@@ -45,6 +45,7 @@ where
             #[cfg(feature = "ledger_nano")]
             SecretManagerDto::LedgerNano(is_simulator) => s.serialize_str(&format!("ledgerNano({is_simulator})")),
             SecretManagerDto::Mnemonic(_) => s.serialize_str("mnemonic(<omitted>)"),
+            SecretManagerDto::MnemonicWithPassphrase { .. } => s.serialize_str("mnemonicWithPassphrase(<omitted>)"),
             SecretManagerDto::Placeholder => s.serialize_str("placeholder"),
             #[cfg(feature = "stronghold")]
             SecretManagerDto::Stronghold(stronghold) => {
@@ -7,12 +7,20 @@ use serde::Serialize;
 #[cfg(feature = "participation")]
 use {
     crate::types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventStatus},
-    crate::wallet::account::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes},
+    crate::wallet::account::operations::participation::{
+        staking::AccountStakingRewards,
+        voting_plan::{ParticipationEventHistory, VotingPlan},
+        AccountParticipationOverview, ParticipationEventWithNodes,
+    },
     std::collections::HashMap,
 };
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::LedgerNanoStatus;
+#[cfg(feature = "stronghold")]
+use crate::wallet::BackupContent;
+#[cfg(feature = "storage")]
+use crate::wallet::Contact;
 use crate::{
     client::{
         api::{PreparedTransactionDataDto, SignedTransactionDataDto},
@@ -25,8 +33,14 @@ use crate::{
     },
     wallet::{
         account::{
-            operations::transaction::high_level::minting::mint_native_token::MintTokenTransactionDto,
-            types::{address::AccountAddress, AccountBalanceDto, TransactionDto},
+            operations::transaction::high_level::{
+                foundry_control::ControlledFoundryDto, minting::mint_native_token::MintTokenTransactionDto,
+                sub_wallet::SubWalletBalanceDto, swap::SwapOfferTransactionDto, vesting::VestingTrancheDto,
+            },
+            types::{
+                address::AccountAddress, AccountBalanceDto, HistoricalBalanceDto, Irc30Metadata, NftIrc27Metadata,
+                TransactionClassificationDto, TransactionDto,
+            },
             OutputDataDto,
         },
         message_interface::dtos::{AccountDto, AddressWithUnspentOutputsDto},
@@ -44,16 +58,33 @@ pub enum Response {
     Account(AccountDto),
     /// Response for [`GetAccountIndexes`](crate::message_interface::Message::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
-    /// Response for [`GetAccounts`](crate::message_interface::Message::GetAccounts)
+    /// Response for
+    /// [`GetAccounts`](crate::message_interface::Message::GetAccounts),
+    /// [`RestoreBackupWithOptions`](crate::message_interface::Message::RestoreBackupWithOptions),
+    /// [`ImportMetadata`](crate::message_interface::Message::ImportMetadata)
     Accounts(Vec<AccountDto>),
     /// Response for [`Addresses`](crate::message_interface::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
+    /// Response for [`GetAddressBook`](crate::message_interface::Message::GetAddressBook)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    AddressBook(Vec<Contact>),
     /// Response for
     /// [`AddressesWithUnspentOutputs`](crate::message_interface::AccountMethod::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs(Vec<AddressWithUnspentOutputsDto>),
+    /// Response for [`ReadBackupContent`](crate::message_interface::Message::ReadBackupContent)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    BackupContent(BackupContent),
     /// Response for
     /// [`RetryTransactionUntilIncluded`](crate::message_interface::AccountMethod::RetryTransactionUntilIncluded)
     BlockId(BlockId),
+    /// Response for [`RemoveContact`](crate::message_interface::Message::RemoveContact), whether a contact was removed
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    ContactRemoved(bool),
+    /// Response for [`ControlledFoundries`](crate::message_interface::AccountMethod::ControlledFoundries)
+    ControlledFoundries(Vec<ControlledFoundryDto>),
     /// Response for
     /// [`BuildAliasOutput`](crate::message_interface::AccountMethod::BuildAliasOutput)
     /// [`BuildBasicOutput`](crate::message_interface::AccountMethod::BuildBasicOutput)
@@ -62,6 +93,8 @@ pub enum Response {
     /// [`GetFoundryOutput`](crate::message_interface::AccountMethod::GetFoundryOutput)
     /// [`PrepareOutput`](crate::message_interface::AccountMethod::PrepareOutput)
     Output(OutputDto),
+    /// Response for [`GetNativeTokenMetadata`](crate::message_interface::AccountMethod::GetNativeTokenMetadata)
+    NativeTokenMetadata(Option<Irc30Metadata>),
     /// Response for
     /// [`MinimumRequiredStorageDeposit`](crate::message_interface::AccountMethod::MinimumRequiredStorageDeposit)
     MinimumRequiredStorageDeposit(String),
@@ -74,6 +107,8 @@ pub enum Response {
     /// [`Outputs`](crate::message_interface::AccountMethod::Outputs),
     /// [`UnspentOutputs`](crate::message_interface::AccountMethod::UnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
+    /// Response for [`Irc27Nfts`](crate::message_interface::AccountMethod::Irc27Nfts)
+    Irc27Nfts(Vec<NftIrc27Metadata>),
     /// Response for
     /// [`PrepareSendAmount`](crate::message_interface::AccountMethod::PrepareSendAmount),
     /// [`PrepareTransaction`](crate::message_interface::AccountMethod::PrepareTransaction)
@@ -82,9 +117,19 @@ pub enum Response {
     /// [`GetTransaction`](crate::message_interface::AccountMethod::GetTransaction),
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
+    /// [`GetTransactionClassification`](crate::message_interface::AccountMethod::GetTransactionClassification)
+    TransactionClassification(Option<TransactionClassificationDto>),
+    /// Response for
     /// [`Transactions`](crate::message_interface::AccountMethod::Transactions),
-    /// [`PendingTransactions`](crate::message_interface::AccountMethod::PendingTransactions)
+    /// [`PendingTransactions`](crate::message_interface::AccountMethod::PendingTransactions),
+    /// [`SendVesting`](crate::message_interface::AccountMethod::SendVesting)
     Transactions(Vec<TransactionDto>),
+    /// Response for [`SubWallets`](crate::message_interface::AccountMethod::SubWallets)
+    SubWallets(Vec<SubWalletBalanceDto>),
+    /// Response for [`SubWalletBalance`](crate::message_interface::AccountMethod::SubWalletBalance)
+    SubWalletBalance(SubWalletBalanceDto),
+    /// Response for [`PendingVestingTranches`](crate::message_interface::AccountMethod::PendingVestingTranches)
+    VestingTranches(Vec<VestingTrancheDto>),
     /// Response for
     /// [`SignTransactionEssence`](crate::message_interface::AccountMethod::SignTransactionEssence)
     /// [`SubmitAndStoreTransaction`](crate::message_interface::AccountMethod::SubmitAndStoreTransaction)
@@ -96,6 +141,13 @@ pub enum Response {
     /// [`GetBalance`](crate::message_interface::AccountMethod::GetBalance),
     /// [`SyncAccount`](crate::message_interface::AccountMethod::SyncAccount)
     Balance(AccountBalanceDto),
+    /// Response for [`GetBalanceAt`](crate::message_interface::AccountMethod::GetBalanceAt)
+    HistoricalBalance(HistoricalBalanceDto),
+    /// Response for [`GetBalanceHistory`](crate::message_interface::AccountMethod::GetBalanceHistory)
+    HistoricalBalances(Vec<HistoricalBalanceDto>),
+    /// Response for
+    /// [`ExportTransactionHistory`](crate::message_interface::AccountMethod::ExportTransactionHistory)
+    TransactionHistoryExport(String),
     /// Response for
     /// [`GetLedgerNanoStatus`](crate::message_interface::Message::GetLedgerNanoStatus),
     #[cfg(feature = "ledger_nano")]
@@ -120,6 +172,10 @@ pub enum Response {
     /// [`SendOutputs`](crate::message_interface::AccountMethod::SendOutputs)
     /// [`SubmitAndStoreTransaction`](crate::message_interface::AccountMethod::SubmitAndStoreTransaction)
     /// [`Vote`](crate::message_interface::AccountMethod::Vote)
+    /// [`VoteForEvents`](crate::message_interface::AccountMethod::VoteForEvents)
+    /// [`JoinStakingEvent`](crate::message_interface::AccountMethod::JoinStakingEvent)
+    /// [`LeaveStakingEvent`](crate::message_interface::AccountMethod::LeaveStakingEvent)
+    /// [`ApplyVotingPlan`](crate::message_interface::AccountMethod::ApplyVotingPlan)
     /// [`StopParticipating`](crate::message_interface::AccountMethod::StopParticipating)
     /// [`IncreaseVotingPower`](crate::message_interface::AccountMethod::IncreaseVotingPower)
     /// [`DecreaseVotingPower`](crate::message_interface::AccountMethod::DecreaseVotingPower)
@@ -127,6 +183,8 @@ pub enum Response {
     /// Response for
     /// [`MintNativeToken`](crate::message_interface::AccountMethod::MintNativeToken),
     MintTokenTransaction(MintTokenTransactionDto),
+    /// Response for [`CreateSwapOffer`](crate::message_interface::AccountMethod::CreateSwapOffer)
+    SwapOfferTransaction(SwapOfferTransactionDto),
     /// Response for
     /// [`IsStrongholdPasswordAvailable`](crate::message_interface::Message::IsStrongholdPasswordAvailable)
     StrongholdPasswordIsAvailable(bool),
@@ -169,6 +227,21 @@ pub enum Response {
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     AccountParticipationOverview(AccountParticipationOverview),
+    /// Response for
+    /// [`GetVotingPlan`](crate::message_interface::AccountMethod::GetVotingPlan)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    VotingPlan(VotingPlan),
+    /// Response for
+    /// [`GetParticipationEventHistory`](crate::message_interface::AccountMethod::GetParticipationEventHistory)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    ParticipationEventHistory(ParticipationEventHistory),
+    /// Response for
+    /// [`GetStakingRewards`](crate::message_interface::AccountMethod::GetStakingRewards)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    StakingRewards(AccountStakingRewards),
     /// Response for [`Bech32ToHex`](crate::message_interface::Message::Bech32ToHex)
     HexAddress(String),
     /// Response for [`HexToBech32`](crate::message_interface::Message::HexToBech32)
@@ -179,8 +252,14 @@ pub enum Response {
     /// Response for
     /// [`Backup`](crate::message_interface::Message::Backup),
     /// [`ClearStrongholdPassword`](crate::message_interface::Message::ClearStrongholdPassword),
+    /// [`ApplyVotingPlan`](crate::message_interface::AccountMethod::ApplyVotingPlan),
     /// [`DeregisterParticipationEvent`](crate::message_interface::AccountMethod::DeregisterParticipationEvent),
+    /// [`ExportMetadata`](crate::message_interface::Message::ExportMetadata),
     /// [`RestoreBackup`](crate::message_interface::Message::RestoreBackup),
+    /// [`SetAddressLabel`](crate::message_interface::AccountMethod::SetAddressLabel),
+    /// [`VerifySwapOffer`](crate::message_interface::AccountMethod::VerifySwapOffer),
+    /// [`SetContact`](crate::message_interface::Message::SetContact),
+    /// [`SetOutputLabel`](crate::message_interface::AccountMethod::SetOutputLabel),
     /// [`VerifyMnemonic`](crate::message_interface::Message::VerifyMnemonic),
     /// [`SetClientOptions`](crate::message_interface::Message::SetClientOptions),
     /// [`SetStrongholdPassword`](crate::message_interface::Message::SetStrongholdPassword),
@@ -200,25 +279,43 @@ impl Debug for Response {
             Self::AccountIndexes(account_indexes) => write!(f, "AccountIndexes({account_indexes:?})"),
             Self::Accounts(accounts) => write!(f, "Accounts({accounts:?})"),
             Self::Addresses(addresses) => write!(f, "Addresses({addresses:?})"),
+            #[cfg(feature = "storage")]
+            Self::AddressBook(contacts) => write!(f, "AddressBook({contacts:?})"),
             Self::AddressesWithUnspentOutputs(addresses) => {
                 write!(f, "AddressesWithUnspentOutputs({addresses:?})")
             }
+            #[cfg(feature = "stronghold")]
+            Self::BackupContent(backup_content) => write!(f, "BackupContent({backup_content:?})"),
             Self::BlockId(block_id) => write!(f, "BlockId({block_id:?})"),
+            #[cfg(feature = "storage")]
+            Self::ContactRemoved(removed) => write!(f, "ContactRemoved({removed:?})"),
+            Self::ControlledFoundries(foundries) => write!(f, "ControlledFoundries({foundries:?})"),
             Self::Output(output) => write!(f, "Output({output:?})"),
+            Self::NativeTokenMetadata(metadata) => write!(f, "NativeTokenMetadata({metadata:?})"),
             Self::MinimumRequiredStorageDeposit(amount) => write!(f, "MinimumRequiredStorageDeposit({amount:?})"),
             Self::OutputIds(output_ids) => write!(f, "OutputIds({output_ids:?})"),
             Self::OutputData(output) => write!(f, "OutputData({output:?})"),
             Self::OutputsData(outputs) => write!(f, "OutputsData{outputs:?}"),
+            Self::Irc27Nfts(nfts) => write!(f, "Irc27Nfts({nfts:?})"),
             Self::PreparedTransaction(transaction_data) => {
                 write!(f, "PreparedTransaction({transaction_data:?})")
             }
             Self::Transaction(transaction) => write!(f, "Transaction({transaction:?})"),
+            Self::TransactionClassification(classification) => {
+                write!(f, "TransactionClassification({classification:?})")
+            }
             Self::Transactions(transactions) => write!(f, "Transactions({transactions:?})"),
+            Self::SubWallets(sub_wallets) => write!(f, "SubWallets({sub_wallets:?})"),
+            Self::SubWalletBalance(balance) => write!(f, "SubWalletBalance({balance:?})"),
+            Self::VestingTranches(tranches) => write!(f, "VestingTranches({tranches:?})"),
             Self::SignedTransactionData(signed_transaction_data) => {
                 write!(f, "SignedTransactionData({signed_transaction_data:?})")
             }
             Self::GeneratedAddress(addresses) => write!(f, "GeneratedAddress({addresses:?})"),
             Self::Balance(balance) => write!(f, "Balance({balance:?})"),
+            Self::HistoricalBalance(balance) => write!(f, "HistoricalBalance({balance:?})"),
+            Self::HistoricalBalances(balances) => write!(f, "HistoricalBalances({balances:?})"),
+            Self::TransactionHistoryExport(export) => write!(f, "TransactionHistoryExport({export})"),
             Self::IncomingTransactionData(transaction_data) => {
                 write!(f, "IncomingTransactionData({transaction_data:?})")
             }
@@ -229,6 +326,9 @@ impl Debug for Response {
             Self::MintTokenTransaction(mint_transaction) => {
                 write!(f, "MintTokenTransaction({mint_transaction:?})")
             }
+            Self::SwapOfferTransaction(swap_offer_transaction) => {
+                write!(f, "SwapOfferTransaction({swap_offer_transaction:?})")
+            }
             Self::StrongholdPasswordIsAvailable(is_available) => {
                 write!(f, "StrongholdPasswordIsAvailable({is_available:?})")
             }
@@ -255,6 +355,12 @@ impl Debug for Response {
             Self::AccountParticipationOverview(overview) => {
                 write!(f, "AccountParticipationOverview({overview:?})")
             }
+            #[cfg(feature = "participation")]
+            Self::VotingPlan(voting_plan) => write!(f, "VotingPlan({voting_plan:?})"),
+            #[cfg(feature = "participation")]
+            Self::ParticipationEventHistory(history) => write!(f, "ParticipationEventHistory({history:?})"),
+            #[cfg(feature = "participation")]
+            Self::StakingRewards(rewards) => write!(f, "StakingRewards({rewards:?})"),
             Self::Faucet(response) => write!(f, "Faucet({response:?})"),
         }
     }
@@ -33,9 +33,20 @@ pub(crate) mod task;
 // Expose for high level functions
 pub use primitive_types::U256;
 
+#[cfg(feature = "participation")]
+pub use self::account::operations::participation::{
+    staking::AccountStakingRewards,
+    voting_plan::{ParticipationEventHistory, VotingPlan},
+};
+#[cfg(feature = "storage")]
+pub use self::wallet::Contact;
+#[cfg(feature = "stronghold")]
+pub use self::wallet::{BackupAccount, BackupContent, BackupManifest, RestoreBackupOptions};
 pub use self::{
     account::{
         operations::transaction::high_level::{
+            alias_transition::{AliasGovernanceTransitionOptions, AliasStateTransitionOptions},
+            foundry_control::{ControlledFoundry, ControlledFoundryDto},
             minting::{
                 increase_native_token_supply::IncreaseNativeTokenSupplyOptions, mint_native_token::NativeTokenOptions,
                 mint_nfts::NftOptions,
@@ -44,6 +55,9 @@ pub use self::{
             send_micro_transaction::AddressWithMicroAmount,
             send_native_tokens::AddressNativeTokens,
             send_nft::AddressAndNftId,
+            sub_wallet::{SubWalletBalance, SubWalletBalanceDto},
+            swap::{SwapOffer, SwapOfferDto, SwapOfferOptions, SwapOfferTransaction, SwapOfferTransactionDto},
+            vesting::{VestingSchedule, VestingTranche, VestingTrancheDto},
         },
         AccountHandle,
     },
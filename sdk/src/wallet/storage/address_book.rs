@@ -0,0 +1,19 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::wallet::{storage::constants::ADDRESS_BOOK_KEY, Contact};
+
+impl StorageManager {
+    pub(crate) async fn get_address_book(&self) -> crate::wallet::Result<Vec<Contact>> {
+        log::debug!("get_address_book");
+
+        Ok(self.storage.get(ADDRESS_BOOK_KEY).await?.unwrap_or_default())
+    }
+
+    pub(crate) async fn save_address_book(&mut self, contacts: &[Contact]) -> crate::wallet::Result<()> {
+        log::debug!("save_address_book");
+
+        self.storage.set(ADDRESS_BOOK_KEY, contacts).await
+    }
+}
@@ -29,3 +29,9 @@ pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
 #[cfg(feature = "participation")]
 pub(crate) const PARTICIPATION_CACHED_OUTPUTS: &str = "participation-cached-outputs";
+#[cfg(feature = "participation")]
+pub(crate) const PARTICIPATION_VOTING_PLAN: &str = "participation-voting-plan";
+#[cfg(all(feature = "participation", feature = "events"))]
+pub(crate) const PARTICIPATION_ENDED_STAKING_EVENTS: &str = "participation-ended-staking-events";
+
+pub(crate) const ADDRESS_BOOK_KEY: &str = "iota-wallet-address-book";
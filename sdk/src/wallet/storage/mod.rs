@@ -3,6 +3,8 @@
 
 /// Storage adapter.
 pub mod adapter;
+/// Storage functions related to the address book.
+mod address_book;
 /// Storage constants.
 pub mod constants;
 /// Storage manager.
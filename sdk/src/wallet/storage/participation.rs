@@ -2,16 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashMap;
+#[cfg(feature = "events")]
+use std::collections::HashSet;
 
 use super::manager::StorageManager;
+#[cfg(feature = "events")]
+use crate::wallet::storage::constants::PARTICIPATION_ENDED_STAKING_EVENTS;
 use crate::{
     types::{
         api::plugins::participation::{responses::OutputStatusResponse, types::ParticipationEventId},
         block::output::OutputId,
     },
     wallet::{
-        account::operations::participation::ParticipationEventWithNodes,
-        storage::constants::{PARTICIPATION_CACHED_OUTPUTS, PARTICIPATION_EVENTS},
+        account::operations::participation::{voting_plan::VotingPlan, ParticipationEventWithNodes},
+        storage::constants::{PARTICIPATION_CACHED_OUTPUTS, PARTICIPATION_EVENTS, PARTICIPATION_VOTING_PLAN},
     },
 };
 
@@ -109,4 +113,60 @@ impl StorageManager {
             .await?
             .unwrap_or_default())
     }
+
+    pub(crate) async fn set_voting_plan(
+        &mut self,
+        account_index: u32,
+        voting_plan: &VotingPlan,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("set_voting_plan");
+
+        self.storage
+            .set(&format!("{PARTICIPATION_VOTING_PLAN}{account_index}"), voting_plan)
+            .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_voting_plan(&self, account_index: u32) -> crate::wallet::Result<VotingPlan> {
+        log::debug!("get_voting_plan");
+
+        Ok(self
+            .storage
+            .get(&format!("{PARTICIPATION_VOTING_PLAN}{account_index}"))
+            .await?
+            .unwrap_or_default())
+    }
+
+    #[cfg(feature = "events")]
+    pub(crate) async fn set_ended_staking_events(
+        &mut self,
+        account_index: u32,
+        event_ids: &HashSet<ParticipationEventId>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("set_ended_staking_events");
+
+        self.storage
+            .set(
+                &format!("{PARTICIPATION_ENDED_STAKING_EVENTS}{account_index}"),
+                event_ids,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "events")]
+    pub(crate) async fn get_ended_staking_events(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<HashSet<ParticipationEventId>> {
+        log::debug!("get_ended_staking_events");
+
+        Ok(self
+            .storage
+            .get(&format!("{PARTICIPATION_ENDED_STAKING_EVENTS}{account_index}"))
+            .await?
+            .unwrap_or_default())
+    }
 }
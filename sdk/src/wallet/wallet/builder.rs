@@ -21,7 +21,7 @@ use crate::wallet::storage::adapter::memory::Memory;
 use crate::wallet::storage::{constants::default_storage_path, manager::ManagerStorage};
 use crate::{
     client::secret::SecretManager,
-    wallet::{AccountHandle, ClientOptions, Wallet},
+    wallet::{account::AccountClients, AccountHandle, ClientOptions, Wallet},
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -193,13 +193,15 @@ impl WalletBuilder {
         #[cfg(feature = "storage")]
         let accounts = storage_manager.lock().await.get_accounts().await.unwrap_or_default();
         #[cfg(not(feature = "storage"))]
-        let accounts = Vec::new();
+        let accounts: Vec<crate::wallet::account::Account> = Vec::new();
+        let mut account_clients = AccountClients::new(client.clone());
         let mut account_handles: Vec<AccountHandle> = accounts
             .into_iter()
             .map(|a| {
-                AccountHandle::new(
+                let account_client = account_clients.get(&a)?;
+                Ok(AccountHandle::new(
                     a,
-                    client.clone(),
+                    account_client,
                     self.secret_manager
                         .clone()
                         .expect("secret_manager needs to be provided"),
@@ -207,21 +209,26 @@ impl WalletBuilder {
                     event_emitter.clone(),
                     #[cfg(feature = "storage")]
                     storage_manager.clone(),
-                )
+                ))
             })
-            .collect::<_>();
+            .collect::<crate::wallet::Result<_>>()?;
 
         // If the wallet builder is not set, it means the user provided it and we need to update the addresses.
         // In the other case it was loaded from the database and addresses are up to date.
+        // Accounts bound to another network than the wallet keep their own client.
         if new_provided_client_options {
             for account in account_handles.iter_mut() {
-                account.update_account_with_new_client(client.clone()).await?;
+                if account.read().await.client_options.is_none() {
+                    account.update_account_with_new_client(client.clone()).await?;
+                }
             }
         }
 
         Ok(Wallet {
             accounts: Arc::new(RwLock::new(account_handles)),
             background_syncing_status: Arc::new(AtomicUsize::new(0)),
+            #[cfg(feature = "mqtt")]
+            mqtt_syncing_status: Arc::new(AtomicUsize::new(0)),
             client_options: Arc::new(RwLock::new(
                 self.client_options
                     .ok_or(crate::wallet::Error::MissingParameter("client_options"))?,
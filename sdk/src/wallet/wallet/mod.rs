@@ -16,6 +16,10 @@ use tokio::sync::RwLock;
 #[cfg(feature = "storage")]
 use self::builder::StorageOptions;
 pub use self::builder::WalletBuilder;
+#[cfg(feature = "storage")]
+pub use self::operations::address_book::Contact;
+#[cfg(feature = "stronghold")]
+pub use self::operations::stronghold_backup::{BackupAccount, BackupContent, BackupManifest, RestoreBackupOptions};
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
@@ -44,6 +48,9 @@ pub struct Wallet {
     pub(crate) accounts: Arc<RwLock<Vec<AccountHandle>>>,
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: Arc<AtomicUsize>,
+    // 0 = not running, 1 = running, 2 = stopping
+    #[cfg(feature = "mqtt")]
+    pub(crate) mqtt_syncing_status: Arc<AtomicUsize>,
     pub(crate) client_options: Arc<RwLock<ClientOptions>>,
     pub(crate) coin_type: Arc<AtomicU32>,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
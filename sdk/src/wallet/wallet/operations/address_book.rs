@@ -0,0 +1,125 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{types::block::address::Address, wallet::Wallet};
+
+/// A named contact of the address book.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contact {
+    /// The name of the contact, unique in the address book.
+    pub name: String,
+    /// The bech32 encoded address of the contact.
+    pub address: String,
+    /// The bech32 HRP the address must have, so an address of another network isn't used by mistake.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_hrp: Option<String>,
+}
+
+impl Contact {
+    /// Checks that the name isn't empty, that the address is a valid bech32 address and that it has the expected HRP.
+    pub(crate) fn validate(&self) -> crate::wallet::Result<()> {
+        if self.name.is_empty() {
+            return Err(crate::wallet::Error::MissingParameter("name"));
+        }
+
+        let (_, hrp) = Address::try_from_bech32_with_hrp(&self.address)?;
+        if let Some(expected_hrp) = &self.expected_hrp {
+            if &hrp != expected_hrp {
+                return Err(crate::client::Error::InvalidBech32Hrp {
+                    provided: hrp,
+                    expected: expected_hrp.clone(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Wallet {
+    /// Get the contacts of the address book, sorted by name.
+    pub async fn address_book(&self) -> crate::wallet::Result<Vec<Contact>> {
+        self.storage_manager.lock().await.get_address_book().await
+    }
+
+    /// Add a contact to the address book, a contact with the same name is replaced.
+    pub async fn set_contact(&self, contact: Contact) -> crate::wallet::Result<()> {
+        log::debug!("[set_contact] {}", contact.name);
+        contact.validate()?;
+
+        let mut storage_manager = self.storage_manager.lock().await;
+        let mut contacts = storage_manager.get_address_book().await?;
+        insert_contact(&mut contacts, contact);
+        storage_manager.save_address_book(&contacts).await
+    }
+
+    /// Remove the contact with the name from the address book, returns whether a contact was removed.
+    pub async fn remove_contact(&self, name: &str) -> crate::wallet::Result<bool> {
+        log::debug!("[remove_contact] {name}");
+
+        let mut storage_manager = self.storage_manager.lock().await;
+        let mut contacts = storage_manager.get_address_book().await?;
+        let contact_count = contacts.len();
+        contacts.retain(|contact| contact.name != name);
+        if contacts.len() == contact_count {
+            return Ok(false);
+        }
+        storage_manager.save_address_book(&contacts).await?;
+
+        Ok(true)
+    }
+}
+
+/// Inserts the contact at its position by name, replacing a contact with the same name.
+pub(crate) fn insert_contact(contacts: &mut Vec<Contact>, contact: Contact) {
+    match contacts.binary_search_by(|c| c.name.cmp(&contact.name)) {
+        Ok(position) => contacts[position] = contact,
+        Err(position) => contacts.insert(position, contact),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDRESS: &str = "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu";
+
+    fn contact(name: &str, expected_hrp: Option<&str>) -> Contact {
+        Contact {
+            name: name.to_string(),
+            address: ADDRESS.to_string(),
+            expected_hrp: expected_hrp.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert!(contact("alice", None).validate().is_ok());
+        assert!(contact("alice", Some("rms")).validate().is_ok());
+        assert!(contact("alice", Some("smr")).validate().is_err());
+        assert!(contact("", None).validate().is_err());
+        assert!(Contact {
+            address: "rms1invalid".to_string(),
+            ..contact("alice", None)
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn insert_sorted_by_name() {
+        let mut contacts = Vec::new();
+        insert_contact(&mut contacts, contact("carol", None));
+        insert_contact(&mut contacts, contact("alice", None));
+        insert_contact(&mut contacts, contact("bob", None));
+        insert_contact(&mut contacts, contact("alice", Some("rms")));
+
+        let names = contacts.iter().map(|c| c.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+        assert_eq!(contacts[0].expected_hrp.as_deref(), Some("rms"));
+    }
+}
@@ -12,6 +12,9 @@ pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_s
 
 impl Wallet {
     /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// With the `participation` feature, the voting plan of each account is applied after it synced if
+    /// `SyncOptions::apply_voting_plan` is set. With the `events` feature too, a `StakingEnded` event is emitted
+    /// for registered staking events which ended.
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
@@ -51,7 +54,19 @@ impl Wallet {
                             break 'outer;
                         }
                         match account.sync(options.clone()).await {
-                            Ok(_) => {}
+                            Ok(_) => {
+                                // Re-cast votes and stop participating in ended events according to the voting plan
+                                #[cfg(feature = "participation")]
+                                if options.as_ref().is_some_and(|options| options.apply_voting_plan) {
+                                    if let Err(err) = account.apply_voting_plan().await {
+                                        log::debug!("[background_syncing] voting plan error: {}", err);
+                                    }
+                                }
+                                #[cfg(all(feature = "participation", feature = "events"))]
+                                if let Err(err) = account.emit_ended_staking_events().await {
+                                    log::debug!("[background_syncing] staking events error: {}", err);
+                                }
+                            }
                             Err(err) => log::debug!("[background_syncing] error: {}", err),
                         };
                     }
@@ -72,9 +87,11 @@ impl Wallet {
         Ok(())
     }
 
-    /// Stop the background syncing of the accounts
+    /// Stop the background syncing of the accounts, including the MQTT syncing if it was started
     pub async fn stop_background_syncing(&self) -> crate::wallet::Result<()> {
         log::debug!("[stop_background_syncing]");
+        #[cfg(feature = "mqtt")]
+        self.stop_mqtt_syncing().await;
         // immediately return if not running
         if self.background_syncing_status.load(Ordering::Relaxed) == 0 {
             return Ok(());
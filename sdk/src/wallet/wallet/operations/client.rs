@@ -10,7 +10,7 @@ use crate::{
         node_manager::node::{Node, NodeAuth, NodeDto},
         NodeInfoWrapper, Url,
     },
-    wallet::{ClientOptions, Wallet},
+    wallet::{account::types::AccountIdentifier, ClientOptions, Wallet},
 };
 
 impl Wallet {
@@ -25,7 +25,10 @@ impl Wallet {
         let new_client = options.clone().finish()?;
 
         for account in self.accounts.write().await.iter_mut() {
-            account.update_account_with_new_client(new_client.clone()).await?;
+            // Accounts bound to another network than the wallet keep their own client
+            if account.read().await.client_options.is_none() {
+                account.update_account_with_new_client(new_client.clone()).await?;
+            }
         }
 
         #[cfg(feature = "storage")]
@@ -43,6 +46,46 @@ impl Wallet {
         Ok(())
     }
 
+    /// Sets the client options for a single account, binding it to another network than the wallet, or back to the
+    /// network of the wallet if `None` is provided. Also sets the new bech32_hrp for the addresses of the account.
+    pub async fn set_account_client_options<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        options: Option<ClientOptions>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[set_account_client_options]");
+        let account_index = *self.get_account(identifier).await?.read().await.index();
+
+        let new_client = match &options {
+            // Share the client of another account bound to the same network
+            Some(options) => {
+                let mut bound_client = None;
+                for account in self.accounts.read().await.iter() {
+                    if account.read().await.client_options.as_ref() == Some(options) {
+                        bound_client.replace(account.client.clone());
+                        break;
+                    }
+                }
+                match bound_client {
+                    Some(bound_client) => bound_client,
+                    None => options.clone().finish()?,
+                }
+            }
+            None => self.client_options.read().await.clone().finish()?,
+        };
+
+        for account in self.accounts.write().await.iter_mut() {
+            if *account.read().await.index() == account_index {
+                account.write().await.client_options = options;
+                // Also saves the account with the new client options
+                account.update_account_with_new_client(new_client).await?;
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Get the used client options.
     pub async fn get_client_options(&self) -> ClientOptions {
         self.client_options.read().await.clone()
@@ -157,7 +200,10 @@ impl Wallet {
         let new_client = new_client_options.finish()?;
 
         for account in self.accounts.write().await.iter_mut() {
-            account.update_account_with_new_client(new_client.clone()).await?;
+            // Accounts bound to another network than the wallet keep their own client
+            if account.read().await.client_options.is_none() {
+                account.update_account_with_new_client(new_client.clone()).await?;
+            }
         }
 
         Ok(())
@@ -0,0 +1,230 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::PathBuf, sync::atomic::Ordering};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use crypto::ciphers::chacha;
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "storage")]
+use crate::wallet::{wallet::operations::address_book::insert_contact, Contact, WalletBuilder};
+use crate::{
+    utils::unix_timestamp_now,
+    wallet::{
+        account::{types::AccountIdentifier, Account},
+        wallet::operations::restore_accounts::select_accounts,
+        AccountHandle, ClientOptions, Wallet,
+    },
+};
+
+/// The version of the metadata export format.
+const METADATA_EXPORT_VERSION: u8 = 1;
+const KDF_ALGORITHM: &str = "argon2id";
+const CIPHER: &str = "xchacha20-poly1305";
+/// Argon2 memory cost in KiB.
+const ARGON2_MEMORY_COST: u32 = 19 * 1024;
+const ARGON2_TIME_COST: u32 = 2;
+const ARGON2_PARALLELISM: u32 = 1;
+const SALT_LENGTH: usize = 16;
+/// The maximum key derivation parameters accepted when importing, so a crafted export can't exhaust the memory or the
+/// CPU.
+const MAX_ARGON2_MEMORY_COST: u32 = 256 * 1024;
+const MAX_ARGON2_TIME_COST: u32 = 16;
+const MAX_ARGON2_PARALLELISM: u32 = 16;
+
+/// The file written by [`Wallet::export_metadata()`], only the version and the key derivation parameters are readable
+/// without the password.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptedMetadataExport {
+    version: u8,
+    kdf: KdfParameters,
+    cipher: String,
+    /// Hex encoded nonce, tag and ciphertext.
+    data: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KdfParameters {
+    algorithm: String,
+    memory_cost: u32,
+    time_cost: u32,
+    parallelism: u32,
+    /// Hex encoded salt.
+    salt: String,
+}
+
+/// The exported wallet metadata, which doesn't contain secrets.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WalletMetadata {
+    sdk_version: String,
+    created_at: u64,
+    coin_type: u32,
+    client_options: ClientOptions,
+    accounts: Vec<Account>,
+    /// The contacts of the address book.
+    #[cfg(feature = "storage")]
+    #[serde(default)]
+    contacts: Vec<Contact>,
+}
+
+impl Wallet {
+    /// Export the wallet and account metadata to a file encrypted with the password, independently of the secret
+    /// manager. The export contains the client options, the coin type, the accounts with their addresses, aliases,
+    /// outputs and transactions including their notes and the contacts of the address book, but no secrets. Outputs are
+    /// claimed with the [`OutputsToClaim`](crate::wallet::account::OutputsToClaim) of each call, so there are no
+    /// claim policies to export.
+    pub async fn export_metadata(&self, export_path: PathBuf, mut password: String) -> crate::wallet::Result<()> {
+        log::debug!("[export_metadata] exporting wallet metadata");
+
+        let mut accounts = Vec::new();
+        for account_handle in self.accounts.read().await.iter() {
+            accounts.push(account_handle.read().await.clone());
+        }
+        let metadata = WalletMetadata {
+            sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: unix_timestamp_now().as_secs(),
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            client_options: self.client_options.read().await.clone(),
+            accounts,
+            #[cfg(feature = "storage")]
+            contacts: self.address_book().await?,
+        };
+        let plaintext = Zeroizing::new(serde_json::to_vec(&metadata)?);
+
+        let mut salt = [0u8; SALT_LENGTH];
+        crypto::utils::rand::fill(&mut salt)?;
+        let kdf = KdfParameters {
+            algorithm: KDF_ALGORITHM.to_string(),
+            memory_cost: ARGON2_MEMORY_COST,
+            time_cost: ARGON2_TIME_COST,
+            parallelism: ARGON2_PARALLELISM,
+            salt: prefix_hex::encode(salt),
+        };
+        let key = derive_key(&password, &kdf)?;
+        password.zeroize();
+
+        let export = EncryptedMetadataExport {
+            version: METADATA_EXPORT_VERSION,
+            kdf,
+            cipher: CIPHER.to_string(),
+            data: prefix_hex::encode(chacha::aead_encrypt(key.as_ref(), &plaintext)?),
+        };
+        fs::write(export_path, serde_json::to_string_pretty(&export)?)?;
+
+        Ok(())
+    }
+
+    /// Import the accounts of a file written by [`Wallet::export_metadata()`], only the selected ones if `accounts` is
+    /// provided. The accounts are added to the existing accounts, like when merging a backup: the export must have the
+    /// coin type of the wallet, its accounts must have been created with the seed of the secret manager and they can't
+    /// have the index or alias of an existing account. If the wallet has no accounts, the exported client options are
+    /// also imported. The exported contacts are merged into the address book, replacing contacts with the same name.
+    /// Returns the imported accounts.
+    pub async fn import_metadata(
+        &self,
+        import_path: PathBuf,
+        mut password: String,
+        accounts: Option<Vec<AccountIdentifier>>,
+    ) -> crate::wallet::Result<Vec<AccountHandle>> {
+        log::debug!("[import_metadata] importing wallet metadata");
+
+        let export: EncryptedMetadataExport = serde_json::from_str(&fs::read_to_string(import_path)?)?;
+        if export.version > METADATA_EXPORT_VERSION {
+            return Err(crate::wallet::Error::UnsupportedBackupVersion {
+                found: export.version,
+                supported: METADATA_EXPORT_VERSION,
+            });
+        }
+        if export.kdf.algorithm != KDF_ALGORITHM || export.cipher != CIPHER {
+            return Err(crate::wallet::Error::Backup("unsupported key derivation or cipher"));
+        }
+        if export.kdf.memory_cost > MAX_ARGON2_MEMORY_COST
+            || export.kdf.time_cost > MAX_ARGON2_TIME_COST
+            || export.kdf.parallelism > MAX_ARGON2_PARALLELISM
+        {
+            return Err(crate::wallet::Error::Backup("key derivation parameters are too large"));
+        }
+
+        let key = derive_key(&password, &export.kdf)?;
+        password.zeroize();
+        let data: Vec<u8> =
+            prefix_hex::decode(&export.data).map_err(|_| crate::wallet::Error::Backup("invalid export data"))?;
+        let plaintext = Zeroizing::new(
+            chacha::aead_decrypt(key.as_ref(), &data)
+                .map_err(|_| crate::wallet::Error::Backup("can't decrypt the export, the password may be wrong"))?,
+        );
+        let metadata: WalletMetadata = serde_json::from_slice(&plaintext)?;
+
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if metadata.coin_type != coin_type {
+            return Err(crate::wallet::Error::InvalidCoinType {
+                new_coin_type: metadata.coin_type,
+                existing_coin_type: coin_type,
+            });
+        }
+
+        // Validate the contacts before anything is imported
+        #[cfg(feature = "storage")]
+        metadata.contacts.iter().try_for_each(Contact::validate)?;
+
+        let mut wallet_accounts = self.accounts.write().await;
+        let import_client_options = wallet_accounts.is_empty();
+        let client_options = if import_client_options {
+            metadata.client_options
+        } else {
+            self.client_options.read().await.clone()
+        };
+
+        let imported_accounts = select_accounts(metadata.accounts, accounts.as_deref())?;
+        let imported_account_handles = self
+            .add_restored_accounts(&mut wallet_accounts, imported_accounts, true, &client_options)
+            .await?;
+        // Only replace the client options once the accounts were imported, so a failed import doesn't change the wallet
+        if import_client_options {
+            *self.client_options.write().await = client_options;
+        }
+        drop(wallet_accounts);
+
+        #[cfg(feature = "storage")]
+        {
+            let wallet_builder = WalletBuilder::from_wallet(self).await;
+            self.storage_manager
+                .lock()
+                .await
+                .save_wallet_data(&wallet_builder)
+                .await?;
+            for account in imported_account_handles.iter() {
+                account.save(None).await?;
+            }
+
+            let mut storage_manager = self.storage_manager.lock().await;
+            let mut address_book = storage_manager.get_address_book().await?;
+            for contact in metadata.contacts {
+                insert_contact(&mut address_book, contact);
+            }
+            storage_manager.save_address_book(&address_book).await?;
+            drop(storage_manager);
+        }
+
+        Ok(imported_account_handles)
+    }
+}
+
+/// Derives the encryption key from the password with Argon2id.
+fn derive_key(password: &str, kdf: &KdfParameters) -> crate::wallet::Result<Zeroizing<[u8; 32]>> {
+    let salt: Vec<u8> = prefix_hex::decode(&kdf.salt).map_err(|_| crate::wallet::Error::Backup("invalid salt"))?;
+    let params = Params::new(kdf.memory_cost, kdf.time_cost, kdf.parallelism, Some(32))
+        .map_err(|_| crate::wallet::Error::Backup("invalid key derivation parameters"))?;
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), &salt, key.as_mut())
+        .map_err(|_| crate::wallet::Error::Backup("key derivation failed"))?;
+
+    Ok(key)
+}
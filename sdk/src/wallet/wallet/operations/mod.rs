@@ -2,12 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+#[cfg(feature = "storage")]
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+pub(crate) mod metadata_export;
+#[cfg(feature = "mqtt")]
+pub(crate) mod mqtt_syncing;
+pub(crate) mod restore_accounts;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
@@ -0,0 +1,109 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use futures::{stream::select_all, StreamExt};
+use tokio::time::{sleep, timeout, Instant};
+
+use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
+
+/// The default interval for the full syncs that are done as fallback next to the MQTT syncing
+pub(crate) const DEFAULT_MQTT_FALLBACK_SYNCING_INTERVAL: Duration = Duration::from_secs(60);
+
+impl Wallet {
+    /// Start syncing the accounts incrementally from MQTT events about their addresses and pending transactions.
+    /// A full background syncing is still done as fallback, default fallback interval is 60 seconds. The subscriptions
+    /// are renewed with the same interval so new addresses and transactions are included.
+    /// Stopped with [`Wallet::stop_background_syncing`].
+    pub async fn start_mqtt_syncing(
+        &self,
+        options: Option<SyncOptions>,
+        fallback_interval: Option<Duration>,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[start_mqtt_syncing]");
+        let fallback_interval = fallback_interval.unwrap_or(DEFAULT_MQTT_FALLBACK_SYNCING_INTERVAL);
+        // stop existing process if running
+        self.stop_mqtt_syncing().await;
+        self.start_background_syncing(options.clone(), Some(fallback_interval))
+            .await?;
+
+        let mqtt_syncing_status = self.mqtt_syncing_status.clone();
+        mqtt_syncing_status.store(1, Ordering::Relaxed);
+        let accounts = self.accounts.clone();
+        let options = options.unwrap_or_default();
+        let _mqtt_syncing = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let mut events;
+                'outer: loop {
+                    log::debug!("[mqtt_syncing]: subscribing to account topics");
+                    let mut streams = Vec::new();
+                    for account in accounts.read().await.iter() {
+                        let topics = match account.mqtt_syncing_topics().await {
+                            Ok(topics) if !topics.is_empty() => topics,
+                            Ok(_) => continue,
+                            Err(err) => {
+                                log::debug!("[mqtt_syncing] error: {}", err);
+                                continue;
+                            }
+                        };
+                        match account.client().subscribe_stream(topics).await {
+                            Ok(stream) => {
+                                let account = account.clone();
+                                streams.push(stream.map(move |event| (account.clone(), event)));
+                            }
+                            Err(err) => log::debug!("[mqtt_syncing] error: {}", err),
+                        }
+                    }
+                    // Replace the streams only after subscribing the new ones, dropping the old ones unsubscribes
+                    // topics which are no longer used. Events dropped while a stream was full are picked up by the
+                    // fallback syncing.
+                    events = select_all(streams);
+
+                    let renew_subscriptions = Instant::now() + fallback_interval;
+                    while Instant::now() < renew_subscriptions {
+                        // Wait at most a second for events so stopping the process doesn't have to wait long
+                        match timeout(Duration::from_secs(1), events.next()).await {
+                            Ok(Some((account, event))) => {
+                                if let Err(err) = account.sync_mqtt_event(&event, &options).await {
+                                    log::debug!("[mqtt_syncing] error: {}", err);
+                                }
+                            }
+                            // No subscriptions
+                            Ok(None) => sleep(Duration::from_secs(1)).await,
+                            Err(_) => {}
+                        }
+                        if mqtt_syncing_status.load(Ordering::Relaxed) == 2 {
+                            log::debug!("[mqtt_syncing]: stopping");
+                            break 'outer;
+                        }
+                    }
+                }
+                // Unsubscribe before reporting the process as stopped
+                drop(events);
+                mqtt_syncing_status.store(0, Ordering::Relaxed);
+                log::debug!("[mqtt_syncing]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop the MQTT syncing process if it's running and wait until it stopped.
+    pub(crate) async fn stop_mqtt_syncing(&self) {
+        // immediately return if not running
+        if self.mqtt_syncing_status.load(Ordering::Relaxed) == 0 {
+            return;
+        }
+        log::debug!("[stop_mqtt_syncing]");
+        // send stop request
+        self.mqtt_syncing_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.mqtt_syncing_status.load(Ordering::Relaxed) != 0 {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::wallet::{
+    account::{builder::get_first_public_address, types::AccountIdentifier, Account, AccountClients},
+    AccountHandle, ClientOptions, Wallet,
+};
+
+impl Wallet {
+    /// Adds accounts restored from a backup or an export to the accounts of the wallet, keeping them ordered by index.
+    /// If `verify_seed` is set, the first address of the first restored account is generated and compared to the
+    /// stored one, to prevent adding accounts created with another seed. The clients of the accounts on the network of
+    /// the wallet are built from `client_options`. The accounts of the wallet are only changed if nothing failed.
+    /// Returns the restored accounts.
+    pub(crate) async fn add_restored_accounts(
+        &self,
+        accounts: &mut Vec<AccountHandle>,
+        restored_accounts: Vec<Account>,
+        verify_seed: bool,
+        client_options: &ClientOptions,
+    ) -> crate::wallet::Result<Vec<AccountHandle>> {
+        check_restored_accounts(accounts, &restored_accounts).await?;
+
+        if verify_seed {
+            if let Some(account) = restored_accounts.first() {
+                if let Some(address) = account.public_addresses.first() {
+                    let first_public_address =
+                        get_first_public_address(&self.secret_manager, *account.coin_type(), *account.index()).await?;
+                    if first_public_address != address.address.inner {
+                        return Err(crate::wallet::Error::InvalidMnemonic(
+                            "restored accounts used another seed".to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut account_clients = AccountClients::new(client_options.clone().finish()?);
+
+        let mut restored_account_handles = Vec::new();
+        for account in restored_accounts {
+            let account_client = account_clients.get(&account)?;
+            restored_account_handles.push(AccountHandle::new(
+                account,
+                account_client,
+                self.secret_manager.clone(),
+                #[cfg(feature = "events")]
+                self.event_emitter.clone(),
+                #[cfg(feature = "storage")]
+                self.storage_manager.clone(),
+            ))
+        }
+
+        // Keep the accounts ordered by index
+        let mut indexed_accounts = Vec::new();
+        for account_handle in accounts.drain(..).chain(restored_account_handles.iter().cloned()) {
+            let index = *account_handle.read().await.index();
+            indexed_accounts.push((index, account_handle));
+        }
+        indexed_accounts.sort_by_key(|(index, _)| *index);
+        *accounts = indexed_accounts
+            .into_iter()
+            .map(|(_, account_handle)| account_handle)
+            .collect();
+
+        Ok(restored_account_handles)
+    }
+}
+
+/// Keeps the accounts matching one of the identifiers, all accounts if there are no identifiers.
+pub(crate) fn select_accounts(
+    accounts: Vec<Account>,
+    identifiers: Option<&[AccountIdentifier]>,
+) -> crate::wallet::Result<Vec<Account>> {
+    let identifiers = match identifiers {
+        Some(identifiers) => identifiers,
+        None => return Ok(accounts),
+    };
+
+    let matches = |account: &Account, identifier: &AccountIdentifier| match identifier {
+        AccountIdentifier::Index(index) => account.index() == index,
+        AccountIdentifier::Alias(alias) => account.alias() == alias,
+    };
+
+    for identifier in identifiers {
+        if !accounts.iter().any(|account| matches(account, identifier)) {
+            return Err(crate::wallet::Error::AccountNotFound(serde_json::to_string(
+                identifier,
+            )?));
+        }
+    }
+
+    Ok(accounts
+        .into_iter()
+        .filter(|account| identifiers.iter().any(|identifier| matches(account, identifier)))
+        .collect())
+}
+
+/// Checks that the restored accounts don't have the index or alias of an existing account and that the account indexes
+/// stay without gaps, since the index of a new account is the number of accounts. A selective restore must therefore
+/// include the accounts with lower indexes which don't exist yet, otherwise an error names the missing indexes.
+async fn check_restored_accounts(
+    existing_accounts: &[AccountHandle],
+    restored_accounts: &[Account],
+) -> crate::wallet::Result<()> {
+    let mut indexes = Vec::new();
+    let mut aliases = HashSet::new();
+    for account_handle in existing_accounts {
+        let account = account_handle.read().await;
+        indexes.push(*account.index());
+        aliases.insert(account.alias().to_lowercase());
+    }
+
+    for account in restored_accounts {
+        if indexes.contains(account.index()) || !aliases.insert(account.alias().to_lowercase()) {
+            return Err(crate::wallet::Error::BackupAccountConflict {
+                index: *account.index(),
+                alias: account.alias().clone(),
+            });
+        }
+        indexes.push(*account.index());
+    }
+
+    let missing_indexes = (0..indexes.iter().copied().max().unwrap_or_default())
+        .filter(|index| !indexes.contains(index))
+        .collect::<Vec<_>>();
+    if !missing_indexes.is_empty() {
+        return Err(crate::wallet::Error::BackupAccountIndexGap { missing_indexes });
+    }
+
+    Ok(())
+}
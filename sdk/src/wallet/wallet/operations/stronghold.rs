@@ -43,6 +43,26 @@ impl Wallet {
         Ok(())
     }
 
+    /// Stores a mnemonic and its BIP-39 passphrase into the Stronghold vault
+    pub async fn store_mnemonic_with_passphrase(
+        &self,
+        mnemonic: String,
+        passphrase: String,
+    ) -> crate::wallet::Result<()> {
+        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
+            stronghold.store_mnemonic_with_passphrase(mnemonic, passphrase).await?;
+        }
+        Ok(())
+    }
+
+    /// Stores the master secret recovered from a threshold of SLIP-39 mnemonic shares into the Stronghold vault
+    pub async fn store_slip39_shares(&self, shares: Vec<String>, passphrase: String) -> crate::wallet::Result<()> {
+        if let SecretManager::Stronghold(stronghold) = &mut *self.secret_manager.write().await {
+            stronghold.store_slip39_shares(shares, passphrase).await?;
+        }
+        Ok(())
+    }
+
     /// Clears the Stronghold password from memory.
     pub async fn clear_stronghold_password(&self) -> crate::wallet::Result<()> {
         log::debug!("[clear_stronghold_password]");
@@ -1,18 +1,35 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod options;
 mod stronghold_snapshot;
 
-use std::{fs, path::PathBuf, sync::atomic::Ordering};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+    sync::atomic::Ordering,
+};
 
 use zeroize::Zeroize;
 
-use self::stronghold_snapshot::{read_data_from_stronghold_snapshot, store_data_to_stronghold};
 #[cfg(feature = "storage")]
-use crate::wallet::WalletBuilder;
+use self::stronghold_snapshot::read_address_book_from_stronghold_snapshot;
+use self::stronghold_snapshot::{
+    read_data_from_stronghold_snapshot, read_manifest_from_stronghold_snapshot, store_data_to_stronghold,
+};
+pub use self::{
+    options::RestoreBackupOptions,
+    stronghold_snapshot::{BackupAccount, BackupContent, BackupManifest},
+};
+#[cfg(feature = "storage")]
+use crate::wallet::{wallet::operations::address_book::insert_contact, Contact, WalletBuilder};
 use crate::{
-    client::secret::{stronghold::StrongholdSecretManager, SecretManager, SecretManagerDto},
-    wallet::{AccountHandle, Wallet},
+    client::{
+        secret::{stronghold::StrongholdSecretManager, SecretManager, SecretManagerDto},
+        stronghold::verify_snapshot_header,
+    },
+    wallet::{wallet::operations::restore_accounts::select_accounts, AccountHandle, Wallet},
 };
 
 impl Wallet {
@@ -53,41 +70,138 @@ impl Wallet {
         Ok(())
     }
 
+    /// Read the manifest of a backup, without restoring it. Returns `None` for backups created before manifests were
+    /// added.
+    pub async fn read_backup_manifest(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+    ) -> crate::wallet::Result<Option<BackupManifest>> {
+        log::debug!("[read_backup_manifest] loading stronghold backup");
+
+        if !backup_path.is_file() {
+            return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
+        }
+        verify_backup_snapshot_header(&backup_path)?;
+
+        let mut backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path)?;
+        stronghold_password.zeroize();
+
+        read_manifest_from_stronghold_snapshot(&mut backup_stronghold).await
+    }
+
+    /// Read the content of a backup, without restoring it. Accounts with the index or alias of an existing account are
+    /// marked as conflicting.
+    pub async fn read_backup_content(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+    ) -> crate::wallet::Result<BackupContent> {
+        log::debug!("[read_backup_content] loading stronghold backup");
+
+        if !backup_path.is_file() {
+            return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
+        }
+        verify_backup_snapshot_header(&backup_path)?;
+
+        let mut backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path)?;
+        stronghold_password.zeroize();
+
+        let manifest = read_manifest_from_stronghold_snapshot(&mut backup_stronghold).await?;
+        let (read_client_options, read_coin_type, read_secret_manager, read_accounts) =
+            read_data_from_stronghold_snapshot(&mut backup_stronghold).await?;
+
+        let mut existing_indexes = HashSet::new();
+        let mut existing_aliases = HashSet::new();
+        for account_handle in self.accounts.read().await.iter() {
+            let account = account_handle.read().await;
+            existing_indexes.insert(*account.index());
+            existing_aliases.insert(account.alias().to_lowercase());
+        }
+
+        let accounts = read_accounts
+            .unwrap_or_default()
+            .iter()
+            .map(|account| BackupAccount {
+                index: *account.index(),
+                alias: account.alias().clone(),
+                coin_type: *account.coin_type(),
+                address_count: account.public_addresses.len() + account.internal_addresses.len(),
+                transaction_count: account.transactions().len() + account.incoming_transactions().len(),
+                conflict: existing_indexes.contains(account.index())
+                    || existing_aliases.contains(&account.alias().to_lowercase()),
+            })
+            .collect();
+
+        Ok(BackupContent {
+            manifest,
+            coin_type: read_coin_type,
+            client_options: read_client_options.is_some(),
+            secret_manager: read_secret_manager.is_some(),
+            accounts,
+        })
+    }
+
     /// Restore a backup from a Stronghold file
-    /// Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already
-    /// created If Stronghold is used as secret_manager, the existing Stronghold file will be overwritten. If a
-    /// mnemonic was stored, it will be gone.
+    /// Replaces client_options, coin_type, secret_manager, accounts and the address book. Returns an error if accounts
+    /// were already created If Stronghold is used as secret_manager, the existing Stronghold file will be
+    /// overwritten. If a mnemonic was stored, it will be gone.
     /// if ignore_if_coin_type_mismatch.is_some(), client options will not be restored
     /// if ignore_if_coin_type_mismatch == Some(true), client options coin type and accounts will not be restored if the
     /// cointype doesn't match
+    /// Backups created with an older SDK version are upgraded, backups created with a newer SDK version are refused
+    /// with [`Error::UnsupportedBackupVersion`](crate::wallet::Error::UnsupportedBackupVersion).
     pub async fn restore_backup(
         &self,
         backup_path: PathBuf,
-        mut stronghold_password: String,
+        stronghold_password: String,
         ignore_if_coin_type_mismatch: Option<bool>,
     ) -> crate::wallet::Result<()> {
+        self.restore_backup_with_options(
+            backup_path,
+            stronghold_password,
+            RestoreBackupOptions {
+                ignore_if_coin_type_mismatch,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Restore a backup from a Stronghold file, like [`Wallet::restore_backup()`], but only the selected accounts and
+    /// optionally merged into the existing accounts. Returns the restored accounts.
+    /// When merging, the client options, coin type and secret manager of the wallet are kept, the backup must have the
+    /// same coin type and its accounts must have been created with the same secret. The restored accounts can't have
+    /// the index or alias of an existing account and the account indexes must stay without gaps. The contacts of the
+    /// backup are added to the address book, replacing contacts with the same name. Without merging, the address book
+    /// is replaced, backups created before the address book was added restore an empty one.
+    pub async fn restore_backup_with_options(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+        options: RestoreBackupOptions,
+    ) -> crate::wallet::Result<Vec<AccountHandle>> {
         log::debug!("[restore_backup] loading stronghold backup");
 
         if !backup_path.is_file() {
             return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
         }
+        verify_backup_snapshot_header(&backup_path)?;
 
         let mut accounts = self.accounts.write().await;
         // We don't want to overwrite possible existing accounts
-        if !accounts.is_empty() {
+        if !options.merge && !accounts.is_empty() {
             return Err(crate::wallet::Error::Backup(
                 "can't restore backup when there are already accounts",
             ));
         }
 
-        let mut secret_manager = self.secret_manager.as_ref().write().await;
-        // Get the current snapshot path if set
-        let new_snapshot_path = if let SecretManager::Stronghold(stronghold) = &mut *secret_manager {
-            stronghold.snapshot_path.clone()
-        } else {
-            PathBuf::from("wallet.stronghold")
-        };
-
         // We'll create a new stronghold to load the backup
         let mut new_stronghold = StrongholdSecretManager::builder()
             .password(&stronghold_password)
@@ -95,73 +209,94 @@ impl Wallet {
 
         let (read_client_options, read_coin_type, read_secret_manager, read_accounts) =
             read_data_from_stronghold_snapshot(&mut new_stronghold).await?;
+        let read_accounts = select_accounts(read_accounts.unwrap_or_default(), options.accounts.as_deref())?;
+        #[cfg(feature = "storage")]
+        let read_address_book = read_address_book_from_stronghold_snapshot(&mut new_stronghold).await?;
+        #[cfg(feature = "storage")]
+        read_address_book.iter().flatten().try_for_each(Contact::validate)?;
 
-        // If the coin type is not matching the current one, then the addresses in the accounts will also not be
-        // correct, so we will not restore them
-        let ignore_backup_values = ignore_if_coin_type_mismatch.map_or(false, |ignore| {
-            if ignore {
-                read_coin_type.map_or(true, |read_coin_type| {
-                    self.coin_type.load(Ordering::Relaxed) != read_coin_type
-                })
-            } else {
-                false
-            }
-        });
+        let read_accounts = if options.merge {
+            stronghold_password.zeroize();
 
-        // Update Wallet with read data
-        if ignore_if_coin_type_mismatch.is_none() {
-            if let Some(read_client_options) = read_client_options {
-                // If the nodes are from the same network as the current client options, then extend it
-                *self.client_options.write().await = read_client_options;
+            let coin_type = self.coin_type.load(Ordering::Relaxed);
+            if let Some(read_coin_type) = read_coin_type {
+                if read_coin_type != coin_type {
+                    return Err(crate::wallet::Error::InvalidCoinType {
+                        new_coin_type: read_coin_type,
+                        existing_coin_type: coin_type,
+                    });
+                }
             }
-        }
 
-        if !ignore_backup_values {
-            if let Some(read_coin_type) = read_coin_type {
-                self.coin_type.store(read_coin_type, Ordering::Relaxed);
+            read_accounts
+        } else {
+            let mut secret_manager = self.secret_manager.as_ref().write().await;
+            // Get the current snapshot path if set
+            let new_snapshot_path = if let SecretManager::Stronghold(stronghold) = &mut *secret_manager {
+                stronghold.snapshot_path.clone()
+            } else {
+                PathBuf::from("wallet.stronghold")
+            };
+
+            // If the coin type is not matching the current one, then the addresses in the accounts will also not be
+            // correct, so we will not restore them
+            let ignore_backup_values = options.ignore_if_coin_type_mismatch.map_or(false, |ignore| {
+                if ignore {
+                    read_coin_type.map_or(true, |read_coin_type| {
+                        self.coin_type.load(Ordering::Relaxed) != read_coin_type
+                    })
+                } else {
+                    false
+                }
+            });
+
+            // Update Wallet with read data
+            if options.ignore_if_coin_type_mismatch.is_none() {
+                if let Some(read_client_options) = read_client_options {
+                    // If the nodes are from the same network as the current client options, then extend it
+                    *self.client_options.write().await = read_client_options;
+                }
             }
-        }
 
-        if let Some(mut read_secret_manager) = read_secret_manager {
-            // We have to replace the snapshot path with the current one, when building stronghold
-            if let SecretManagerDto::Stronghold(stronghold_dto) = &mut read_secret_manager {
-                stronghold_dto.snapshot_path = new_snapshot_path.clone().into_os_string().to_string_lossy().into();
+            if !ignore_backup_values {
+                if let Some(read_coin_type) = read_coin_type {
+                    self.coin_type.store(read_coin_type, Ordering::Relaxed);
+                }
             }
 
-            let mut restored_secret_manager = SecretManager::try_from(&read_secret_manager)
-                .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?;
+            if let Some(mut read_secret_manager) = read_secret_manager {
+                // We have to replace the snapshot path with the current one, when building stronghold
+                if let SecretManagerDto::Stronghold(stronghold_dto) = &mut read_secret_manager {
+                    stronghold_dto.snapshot_path = new_snapshot_path.clone().into_os_string().to_string_lossy().into();
+                }
 
-            if let SecretManager::Stronghold(stronghold) = &mut restored_secret_manager {
-                // Copy Stronghold file so the seed is available in the new location
-                fs::copy(backup_path, new_snapshot_path)?;
+                let mut restored_secret_manager = SecretManager::try_from(&read_secret_manager)
+                    .map_err(|_| crate::wallet::Error::Backup("invalid secret_manager"))?;
 
-                // Set password to restored secret manager
-                stronghold.set_password(&stronghold_password).await?;
+                if let SecretManager::Stronghold(stronghold) = &mut restored_secret_manager {
+                    // Copy Stronghold file so the seed is available in the new location
+                    fs::copy(backup_path, new_snapshot_path)?;
+
+                    // Set password to restored secret manager
+                    stronghold.set_password(&stronghold_password).await?;
+                }
+                *secret_manager = restored_secret_manager;
             }
-            *secret_manager = restored_secret_manager;
-        }
 
-        stronghold_password.zeroize();
+            stronghold_password.zeroize();
 
-        if !ignore_backup_values {
-            if let Some(read_accounts) = read_accounts {
-                let client = self.client_options.read().await.clone().finish()?;
-
-                let mut restored_account_handles = Vec::new();
-                for account in read_accounts {
-                    restored_account_handles.push(AccountHandle::new(
-                        account,
-                        client.clone(),
-                        self.secret_manager.clone(),
-                        #[cfg(feature = "events")]
-                        self.event_emitter.clone(),
-                        #[cfg(feature = "storage")]
-                        self.storage_manager.clone(),
-                    ))
-                }
-                *accounts = restored_account_handles;
+            if ignore_backup_values {
+                Vec::new()
+            } else {
+                read_accounts
             }
-        }
+        };
+
+        let client_options = self.client_options.read().await.clone();
+        let restored_account_handles = self
+            .add_restored_accounts(&mut accounts, read_accounts, options.merge, &client_options)
+            .await?;
+        drop(accounts);
 
         // store new data
         #[cfg(feature = "storage")]
@@ -179,19 +314,42 @@ impl Wallet {
                 )
                 .with_client_options(self.client_options.read().await.clone())
                 .with_coin_type(self.coin_type.load(Ordering::Relaxed));
-            // drop secret manager, otherwise we get a deadlock in save_wallet_data
-            drop(secret_manager);
             self.storage_manager
                 .lock()
                 .await
                 .save_wallet_data(&wallet_builder)
                 .await?;
             // also save account to db
-            for account in accounts.iter() {
+            for account in restored_account_handles.iter() {
                 account.save(None).await?;
             }
+            // When merging, contacts of the backup are added and replace the ones with the same name, otherwise the
+            // address book of the backup replaces the existing one
+            if !options.merge || read_address_book.is_some() {
+                let mut address_book = if options.merge {
+                    self.storage_manager.lock().await.get_address_book().await?
+                } else {
+                    Vec::new()
+                };
+                for contact in read_address_book.unwrap_or_default() {
+                    insert_contact(&mut address_book, contact);
+                }
+                self.storage_manager
+                    .lock()
+                    .await
+                    .save_address_book(&address_book)
+                    .await?;
+            }
         }
 
-        Ok(())
+        Ok(restored_account_handles)
     }
 }
+
+/// Checks that the backup is a Stronghold snapshot with a supported format version, so a snapshot written by a newer
+/// Stronghold version isn't reported as a wrong password.
+fn verify_backup_snapshot_header(backup_path: &Path) -> crate::wallet::Result<()> {
+    verify_snapshot_header(backup_path).map_or(Ok(()), |status| {
+        Err(crate::client::Error::StrongholdSnapshotUnreadable(Box::new(status)).into())
+    })
+}
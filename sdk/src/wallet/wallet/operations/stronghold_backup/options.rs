@@ -0,0 +1,22 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::account::types::AccountIdentifier;
+
+/// Options for restoring a backup with [`Wallet::restore_backup_with_options()`](crate::wallet::Wallet).
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreBackupOptions {
+    /// Only restore these accounts of the backup. All accounts are restored if `None`.
+    #[serde(default)]
+    pub accounts: Option<Vec<AccountIdentifier>>,
+    /// Add the restored accounts to the existing accounts of the wallet, keeping its client options, coin type and
+    /// secret manager. Accounts with the index or alias of an existing account are refused.
+    #[serde(default)]
+    pub merge: bool,
+    /// Same as for [`Wallet::restore_backup()`](crate::wallet::Wallet), ignored when merging.
+    #[serde(default)]
+    pub ignore_if_coin_type_mismatch: Option<bool>,
+}
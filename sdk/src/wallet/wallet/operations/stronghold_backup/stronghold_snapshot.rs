@@ -3,8 +3,17 @@
 
 use std::sync::atomic::Ordering;
 
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "storage")]
+use crate::wallet::Contact;
 use crate::{
-    client::{secret::SecretManagerDto, storage::StorageProvider, stronghold::StrongholdAdapter},
+    client::{
+        secret::SecretManagerDto,
+        storage::StorageProvider,
+        stronghold::{StrongholdAdapter, SNAPSHOT_VERSION},
+    },
+    utils::unix_timestamp_now,
     wallet::{account::Account, ClientOptions, Wallet},
 };
 
@@ -12,8 +21,68 @@ pub(crate) const CLIENT_OPTIONS_KEY: &str = "client_options";
 pub(crate) const COIN_TYPE_KEY: &str = "coin_type";
 pub(crate) const SECRET_MANAGER_KEY: &str = "secret_manager";
 pub(crate) const ACCOUNTS_KEY: &str = "accounts";
+pub(crate) const MANIFEST_KEY: &str = "manifest";
+#[cfg(feature = "storage")]
+pub(crate) const ADDRESS_BOOK_KEY: &str = "address_book";
 pub(crate) const BACKUP_SCHEMA_VERSION_KEY: &str = "backup_schema_version";
-pub(crate) const BACKUP_SCHEMA_VERSION: u8 = 1;
+// Version 2 added the manifest
+pub(crate) const BACKUP_SCHEMA_VERSION: u8 = 2;
+
+/// Describes the content of a backup, stored along the wallet data.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    /// Version of the SDK which created the backup.
+    pub sdk_version: String,
+    /// Version of the layout of the wallet data in the backup.
+    pub backup_schema_version: u8,
+    /// Version of the Stronghold snapshot format of the backup.
+    pub snapshot_version: [u8; 2],
+    /// Name of the network of the wallet, accounts bound to another network aren't on it. `None` if the wallet client
+    /// didn't sync the network info with a node yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+    /// Coin type of the wallet.
+    pub coin_type: u32,
+    /// Number of accounts in the backup.
+    pub account_count: usize,
+    /// Unix timestamp in seconds of the backup creation.
+    pub created_at: u64,
+}
+
+/// The content of a backup, listed without restoring it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupContent {
+    /// The manifest of the backup, `None` for backups created before manifests were added.
+    pub manifest: Option<BackupManifest>,
+    /// The coin type of the backed up wallet.
+    pub coin_type: Option<u32>,
+    /// Whether the backup contains client options.
+    pub client_options: bool,
+    /// Whether the backup contains a secret manager, a mnemonic secret manager isn't backed up.
+    pub secret_manager: bool,
+    /// The accounts of the backup.
+    pub accounts: Vec<BackupAccount>,
+}
+
+/// An account of a backup.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupAccount {
+    /// The account index.
+    pub index: u32,
+    /// The account alias.
+    pub alias: String,
+    /// The coin type of the account.
+    pub coin_type: u32,
+    /// The number of public and internal addresses.
+    pub address_count: usize,
+    /// The number of sent and incoming transactions.
+    pub transaction_count: usize,
+    /// Whether an account of the wallet has the same index or alias, so the account can't be merged.
+    pub conflict: bool,
+}
 
 pub(crate) async fn store_data_to_stronghold(
     wallet: &Wallet,
@@ -49,10 +118,25 @@ pub(crate) async fn store_data_to_stronghold(
         }
     }
 
+    let accounts = wallet.accounts.read().await;
     let mut serialized_accounts = Vec::new();
-    for account in wallet.accounts.read().await.iter() {
+    for account in accounts.iter() {
         serialized_accounts.push(serde_json::to_string(&*account.read().await)?);
     }
+    // Only the wallet client, shared by the accounts on the network of the wallet, can know the network from a node
+    // sync, the client options only have the default protocol parameters. Accounts bound to another network have their
+    // own client options.
+    let mut network_name = None;
+    for account in accounts.iter() {
+        if account.read().await.client_options.is_none() {
+            network_name = account
+                .client()
+                .synced_protocol_parameters()?
+                .map(|protocol_parameters| protocol_parameters.network_name().to_string());
+            break;
+        }
+    }
+    drop(accounts);
 
     stronghold
         .insert(
@@ -61,9 +145,88 @@ pub(crate) async fn store_data_to_stronghold(
         )
         .await?;
 
+    #[cfg(feature = "storage")]
+    {
+        let address_book = wallet.address_book().await?;
+        stronghold
+            .insert(
+                ADDRESS_BOOK_KEY.as_bytes(),
+                serde_json::to_string(&address_book)?.as_bytes(),
+            )
+            .await?;
+    }
+
+    let manifest = BackupManifest {
+        sdk_version: env!("CARGO_PKG_VERSION").to_string(),
+        backup_schema_version: BACKUP_SCHEMA_VERSION,
+        snapshot_version: SNAPSHOT_VERSION,
+        network_name,
+        coin_type,
+        account_count: serialized_accounts.len(),
+        created_at: unix_timestamp_now().as_secs(),
+    };
+    stronghold
+        .insert(MANIFEST_KEY.as_bytes(), serde_json::to_string(&manifest)?.as_bytes())
+        .await?;
+
     Ok(())
 }
 
+/// Reads the backup schema version and checks that the backup can be read by this SDK version.
+async fn read_backup_schema_version(stronghold: &mut StrongholdAdapter) -> crate::wallet::Result<u8> {
+    let version = match stronghold.get(BACKUP_SCHEMA_VERSION_KEY.as_bytes()).await? {
+        Some(version) => match version.as_slice() {
+            [version] if *version > 0 => *version,
+            _ => return Err(crate::wallet::Error::Backup("invalid backup_schema_version")),
+        },
+        // Backups created before the schema was versioned have the layout of the first version
+        None => 1,
+    };
+
+    if version > BACKUP_SCHEMA_VERSION {
+        return Err(crate::wallet::Error::UnsupportedBackupVersion {
+            found: version,
+            supported: BACKUP_SCHEMA_VERSION,
+        });
+    }
+
+    Ok(version)
+}
+
+/// Reads the manifest of a backup, backups created before the manifest was added don't have one.
+pub(crate) async fn read_manifest_from_stronghold_snapshot(
+    stronghold: &mut StrongholdAdapter,
+) -> crate::wallet::Result<Option<BackupManifest>> {
+    if read_backup_schema_version(stronghold).await? < 2 {
+        return Ok(None);
+    }
+
+    let manifest_bytes = stronghold
+        .get(MANIFEST_KEY.as_bytes())
+        .await?
+        .ok_or(crate::wallet::Error::Backup("missing manifest"))?;
+    let manifest_string =
+        String::from_utf8(manifest_bytes).map_err(|_| crate::wallet::Error::Backup("invalid manifest"))?;
+
+    Ok(Some(serde_json::from_str(&manifest_string)?))
+}
+
+/// Reads the address book of a backup, backups created before the address book was added don't have one.
+#[cfg(feature = "storage")]
+pub(crate) async fn read_address_book_from_stronghold_snapshot(
+    stronghold: &mut StrongholdAdapter,
+) -> crate::wallet::Result<Option<Vec<Contact>>> {
+    match stronghold.get(ADDRESS_BOOK_KEY.as_bytes()).await? {
+        Some(address_book_bytes) => {
+            let address_book_string = String::from_utf8(address_book_bytes)
+                .map_err(|_| crate::wallet::Error::Backup("invalid address_book"))?;
+
+            Ok(Some(serde_json::from_str(&address_book_string)?))
+        }
+        None => Ok(None),
+    }
+}
+
 pub(crate) async fn read_data_from_stronghold_snapshot(
     stronghold: &mut StrongholdAdapter,
 ) -> crate::wallet::Result<(
@@ -72,13 +235,9 @@ pub(crate) async fn read_data_from_stronghold_snapshot(
     Option<SecretManagerDto>,
     Option<Vec<Account>>,
 )> {
-    // Get version
-    let version = stronghold.get(BACKUP_SCHEMA_VERSION_KEY.as_bytes()).await?;
-    if let Some(version) = version {
-        if version[0] != BACKUP_SCHEMA_VERSION {
-            return Err(crate::wallet::Error::Backup("invalid backup_schema_version"));
-        }
-    }
+    // Get the manifest, this also checks the version. Backups of version 1 have the same layout without manifest, so
+    // they don't need to be upgraded.
+    let manifest = read_manifest_from_stronghold_snapshot(stronghold).await?;
 
     // Get client_options
     let client_options_bytes = stronghold.get(CLIENT_OPTIONS_KEY.as_bytes()).await?;
@@ -142,5 +301,12 @@ pub(crate) async fn read_data_from_stronghold_snapshot(
         None
     };
 
+    // Make sure no account is silently dropped
+    if let Some(manifest) = manifest {
+        if manifest.account_count != restored_accounts.as_ref().map_or(0, Vec::len) {
+            return Err(crate::wallet::Error::Backup("accounts don't match the manifest"));
+        }
+    }
+
     Ok((client_options, coin_type, restored_secret_manager, restored_accounts))
 }
@@ -266,6 +266,7 @@ async fn address_generation() {
             let message = Message::StoreMnemonic {
                 secret_manager: SecretManagerDto::Stronghold(secret_manager_dto.clone()),
                 mnemonic: address.mnemonic,
+                passphrase: None,
             };
             let _response = message_handler.send_message(message).await;
 
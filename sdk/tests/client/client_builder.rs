@@ -132,3 +132,9 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[tokio::test]
+async fn offline_client_has_no_synced_protocol_parameters() {
+    let client = Client::builder().finish().unwrap();
+    assert_eq!(client.synced_protocol_parameters().unwrap(), None);
+}
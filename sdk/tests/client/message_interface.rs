@@ -185,6 +185,7 @@ async fn stronghold() {
     let message = Message::StoreMnemonic {
         secret_manager: serde_json::from_str(secret_manager_dto).unwrap(),
         mnemonic,
+        passphrase: None,
     };
     let _response = message_handler.send_message(message).await;
 
@@ -1,7 +1,12 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, Result};
+use iota_sdk::client::{
+    constants::IOTA_COIN_TYPE,
+    secret::{mnemonic::MnemonicSecretManager, SecretManage},
+    slip39::Slip39Group,
+    Client, Result,
+};
 
 #[tokio::test]
 async fn mnemonic() -> Result<()> {
@@ -19,3 +24,116 @@ async fn mnemonic() -> Result<()> {
     );
     Ok(())
 }
+
+#[tokio::test]
+async fn mnemonic_with_passphrase() -> Result<()> {
+    // Test vector from https://github.com/trezor/python-mnemonic/blob/master/vectors.json
+    let mnemonic = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+    assert_eq!(
+        Client::mnemonic_to_hex_seed_with_passphrase(mnemonic, "TREZOR")?,
+        "0xc55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04"
+    );
+    // An empty passphrase is the same as no passphrase
+    assert_eq!(
+        Client::mnemonic_to_hex_seed_with_passphrase(mnemonic, "")?,
+        Client::mnemonic_to_hex_seed(mnemonic)?
+    );
+
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic_with_passphrase(mnemonic, "TREZOR")?;
+    let address = secret_manager
+        .generate_addresses(IOTA_COIN_TYPE, 0, 0..1, false, None)
+        .await?;
+    let secret_manager =
+        MnemonicSecretManager::try_from_hex_seed(&Client::mnemonic_to_hex_seed_with_passphrase(mnemonic, "TREZOR")?)?;
+    assert_eq!(
+        secret_manager
+            .generate_addresses(IOTA_COIN_TYPE, 0, 0..1, false, None)
+            .await?,
+        address
+    );
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic)?;
+    assert_ne!(
+        secret_manager
+            .generate_addresses(IOTA_COIN_TYPE, 0, 0..1, false, None)
+            .await?,
+        address
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn slip39_test_vectors() -> Result<()> {
+    // Test vectors from https://github.com/trezor/python-shamir-mnemonic/blob/master/vectors.json
+    let share = "duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision keyboard";
+    assert_eq!(
+        Client::slip39_shares_to_hex_seed(&[share], "TREZOR")?,
+        "0xbb54aac4b89dc868ba37d9cc21b2cece"
+    );
+
+    let shares = [
+        "shadow pistol academic always adequate wildlife fancy gross oasis cylinder mustang wrist rescue view short owner flip making coding armed",
+        "shadow pistol academic acid actress prayer class unknown daughter sweater depict flip twice unkind craft early superior advocate guest smoking",
+    ];
+    assert_eq!(
+        Client::slip39_shares_to_hex_seed(&shares, "TREZOR")?,
+        "0xb43ceb7e57a0ea8766221624d01b0864"
+    );
+    // Only one of the two required shares
+    assert!(Client::slip39_shares_to_hex_seed(&shares[..1], "TREZOR").is_err());
+    // Invalid checksum
+    assert!(Client::slip39_shares_to_hex_seed(&["duckling enlarge academic academic agency result length solution fridge kidney coal piece deal husband erode duke ajar critical decision kidney"], "TREZOR").is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn slip39_generate_and_recover() -> Result<()> {
+    let groups = [Slip39Group::new(1, 1), Slip39Group::new(2, 3), Slip39Group::new(3, 5)];
+    let shares = Client::generate_slip39_shares(2, &groups, "passphrase")?;
+    assert_eq!(shares.iter().map(Vec::len).collect::<Vec<_>>(), vec![1, 3, 5]);
+
+    let seed = Client::slip39_shares_to_hex_seed(
+        &[&shares[0][0], &shares[2][4], &shares[2][0], &shares[2][2]],
+        "passphrase",
+    )?;
+    assert_eq!(
+        Client::slip39_shares_to_hex_seed(&[&shares[1][1], &shares[1][2], &shares[0][0]], "passphrase")?,
+        seed
+    );
+    assert_eq!(
+        Client::slip39_shares_to_hex_seed(
+            &[
+                &shares[1][0],
+                &shares[1][2],
+                &shares[2][1],
+                &shares[2][3],
+                &shares[2][4]
+            ],
+            "passphrase"
+        )?,
+        seed
+    );
+    // A different passphrase recovers a different master secret
+    assert_ne!(
+        Client::slip39_shares_to_hex_seed(&[&shares[1][1], &shares[1][2], &shares[0][0]], "")?,
+        seed
+    );
+    // Not enough complete groups
+    assert!(Client::slip39_shares_to_hex_seed(&[&shares[0][0], &shares[2][0], &shares[2][1]], "passphrase").is_err());
+
+    let secret_manager =
+        MnemonicSecretManager::try_from_slip39_shares(&[&shares[0][0], &shares[1][0], &shares[1][1]], "passphrase")?;
+    let address = secret_manager
+        .generate_addresses(IOTA_COIN_TYPE, 0, 0..1, false, None)
+        .await?;
+    let secret_manager = MnemonicSecretManager::try_from_hex_seed(&seed)?;
+    assert_eq!(
+        secret_manager
+            .generate_addresses(IOTA_COIN_TYPE, 0, 0..1, false, None)
+            .await?,
+        address
+    );
+
+    Ok(())
+}
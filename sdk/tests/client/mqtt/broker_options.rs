@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::client::mqtt::BrokerOptions;
+
+#[test]
+fn replay_missed_milestones_by_default() {
+    let options: BrokerOptions = serde_json::from_str("{}").unwrap();
+    assert_eq!(options, BrokerOptions::new());
+
+    let json = serde_json::to_value(BrokerOptions::new()).unwrap();
+    assert_eq!(json["replayMissedMilestones"], true);
+
+    let json = serde_json::to_value(BrokerOptions::new().replay_missed_milestones(false)).unwrap();
+    assert_eq!(json["replayMissedMilestones"], false);
+}
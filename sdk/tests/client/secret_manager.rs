@@ -68,6 +68,96 @@ async fn stronghold_secret_manager_dto() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn mnemonic_with_passphrase_secret_manager() -> Result<()> {
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all("mnemonic_with_passphrase_secret_manager").unwrap_or(());
+
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let dto = format!(r#"{{"mnemonicWithPassphrase": {{"mnemonic": "{mnemonic}", "passphrase": "passphrase"}}}}"#);
+    let mnemonic_secret_manager: SecretManager = dto.parse()?;
+
+    let mut stronghold_secret_manager = iota_sdk::client::secret::stronghold::StrongholdSecretManager::builder()
+        .password("some_hopefully_secure_password")
+        .build("mnemonic_with_passphrase_secret_manager/test.stronghold")?;
+    stronghold_secret_manager
+        .store_mnemonic_with_passphrase(mnemonic.to_string(), "passphrase".to_string())
+        .await?;
+    let stronghold_secret_manager = SecretManager::Stronghold(stronghold_secret_manager);
+
+    let addresses = GetAddressesBuilder::new(&mnemonic_secret_manager)
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_range(0..1)
+        .finish()
+        .await?;
+    assert_eq!(
+        addresses,
+        GetAddressesBuilder::new(&stronghold_secret_manager)
+            .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+            .with_range(0..1)
+            .finish()
+            .await?
+    );
+    // The address of the mnemonic without passphrase
+    assert_ne!(
+        addresses[0],
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a".to_string()
+    );
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all("mnemonic_with_passphrase_secret_manager").unwrap_or(());
+    Ok(())
+}
+
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn stronghold_slip39_shares() -> Result<()> {
+    // Cleanup of a possibly failed run
+    std::fs::remove_dir_all("stronghold_slip39_shares").unwrap_or(());
+
+    let shares = iota_sdk::client::Client::generate_slip39_shares(
+        1,
+        &[iota_sdk::client::slip39::Slip39Group::new(2, 3)],
+        "passphrase",
+    )?
+    .remove(0);
+    let mnemonic_secret_manager = SecretManager::Mnemonic(
+        iota_sdk::client::secret::mnemonic::MnemonicSecretManager::try_from_slip39_shares(&shares[1..], "passphrase")?,
+    );
+
+    let mut stronghold_secret_manager = iota_sdk::client::secret::stronghold::StrongholdSecretManager::builder()
+        .password("some_hopefully_secure_password")
+        .build("stronghold_slip39_shares/test.stronghold")?;
+    stronghold_secret_manager
+        .store_slip39_shares(shares[..2].to_vec(), "passphrase".to_string())
+        .await?;
+    // The vault already contains a seed
+    assert!(stronghold_secret_manager
+        .store_slip39_shares(shares[..2].to_vec(), "passphrase".to_string())
+        .await
+        .is_err());
+    let stronghold_secret_manager = SecretManager::Stronghold(stronghold_secret_manager);
+
+    let addresses = GetAddressesBuilder::new(&stronghold_secret_manager)
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_range(0..2)
+        .finish()
+        .await?;
+    assert_eq!(
+        addresses,
+        GetAddressesBuilder::new(&mnemonic_secret_manager)
+            .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+            .with_range(0..2)
+            .finish()
+            .await?
+    );
+
+    // Remove garbage after test, but don't care about the result
+    std::fs::remove_dir_all("stronghold_slip39_shares").unwrap_or(());
+    Ok(())
+}
+
 #[cfg(feature = "stronghold")]
 #[tokio::test]
 async fn stronghold_mnemonic_missing() -> Result<()> {
@@ -95,3 +185,75 @@ async fn stronghold_mnemonic_missing() -> Result<()> {
     std::fs::remove_dir_all("stronghold_mnemonic_missing").unwrap_or(());
     Ok(())
 }
+
+#[cfg(feature = "stronghold")]
+#[tokio::test]
+async fn stronghold_snapshot_verification() -> Result<()> {
+    use iota_sdk::client::{
+        storage::StorageProvider,
+        stronghold::{SnapshotStatus, StrongholdAdapter},
+        Client,
+    };
+
+    let snapshot_dir = "snapshot_test_dir/verification";
+    let snapshot_path = format!("{snapshot_dir}/test.stronghold");
+    let repaired_snapshot_path = format!("{snapshot_dir}/repaired.stronghold");
+    let password = "some_hopefully_secure_password";
+    std::fs::remove_dir_all(snapshot_dir).ok();
+
+    assert_eq!(
+        StrongholdAdapter::verify_snapshot(&snapshot_path, password)?,
+        SnapshotStatus::Missing
+    );
+
+    let mut stronghold = StrongholdAdapter::builder().password(password).build(&snapshot_path)?;
+    stronghold.store_mnemonic(Client::generate_mnemonic()?).await?;
+    stronghold.insert(b"accounts", b"[]").await?;
+    stronghold.write_stronghold_snapshot(None).await?;
+
+    match StrongholdAdapter::verify_snapshot(&snapshot_path, password)? {
+        SnapshotStatus::Valid(content) => {
+            assert!(content.has_seed());
+            assert_eq!(content.store_keys, vec!["accounts".to_string()]);
+            assert!(content.corrupted_store_keys.is_empty());
+        }
+        status => panic!("unexpected snapshot status: {status}"),
+    }
+    assert_eq!(
+        StrongholdAdapter::verify_snapshot(&snapshot_path, "wrong_password")?,
+        SnapshotStatus::InvalidPassword
+    );
+
+    // The repaired snapshot keeps the seed and the readable data
+    let content = StrongholdAdapter::repair_snapshot(&snapshot_path, password, &repaired_snapshot_path)?;
+    assert!(content.has_seed() && content.has_wallet_data());
+    assert_eq!(
+        StrongholdAdapter::verify_snapshot(&repaired_snapshot_path, password)?,
+        SnapshotStatus::Valid(content)
+    );
+
+    // Snapshot of another format version
+    let mut snapshot = std::fs::read(&snapshot_path).unwrap();
+    snapshot[5..7].copy_from_slice(&[3, 0]);
+    std::fs::write(&snapshot_path, &snapshot).unwrap();
+    assert_eq!(
+        StrongholdAdapter::verify_snapshot(&snapshot_path, password)?,
+        SnapshotStatus::UnsupportedVersion {
+            expected: [2, 0],
+            found: [3, 0]
+        }
+    );
+
+    std::fs::write(
+        &snapshot_path,
+        b"not a snapshot, but long enough to have the size of a snapshot header",
+    )
+    .unwrap();
+    assert!(matches!(
+        StrongholdAdapter::verify_snapshot(&snapshot_path, password)?,
+        SnapshotStatus::Corrupted(_)
+    ));
+
+    std::fs::remove_dir_all(snapshot_dir).unwrap();
+    Ok(())
+}
@@ -7,18 +7,19 @@ use iota_sdk::{
         api::{transaction::validate_transaction_payload_length, verify_semantic, PreparedTransactionData},
         constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{SecretManage, SecretManageExt, SecretManager},
-        Client, Result,
+        Client, Error, Result,
     },
     types::block::{
         input::{Input, UtxoInput},
-        output::InputsCommitment,
+        output::{InputsCommitment, RentStructure},
         payload::{
             transaction::{RegularTransactionEssence, TransactionEssence},
             TransactionPayload,
         },
-        protocol::protocol_parameters,
+        protocol::{protocol_parameters, ProtocolParameters},
         semantic::ConflictReason,
         unlock::{SignatureUnlock, Unlock},
+        Error as BlockError,
     },
 };
 
@@ -356,3 +357,91 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn verify_protocol_parameters() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, false, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    prepared_transaction_data.verify_protocol_parameters(&protocol_parameters)?;
+
+    // The byte cost increased so the output doesn't cover its storage deposit anymore
+    let increased_byte_cost = ProtocolParameters::new(
+        protocol_parameters.protocol_version(),
+        protocol_parameters.network_name().to_string(),
+        protocol_parameters.bech32_hrp().to_string(),
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        RentStructure::new(5000, 10, 1),
+        protocol_parameters.token_supply(),
+    )?;
+    assert!(matches!(
+        prepared_transaction_data.verify_protocol_parameters(&increased_byte_cost),
+        Err(Error::Block(BlockError::InsufficientStorageDepositAmount { .. }))
+    ));
+
+    // Another network
+    let other_network = ProtocolParameters::new(
+        protocol_parameters.protocol_version(),
+        "other".to_string(),
+        protocol_parameters.bech32_hrp().to_string(),
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        protocol_parameters.rent_structure().clone(),
+        protocol_parameters.token_supply(),
+    )?;
+    assert!(matches!(
+        prepared_transaction_data.verify_protocol_parameters(&other_network),
+        Err(Error::Block(BlockError::NetworkIdMismatch { .. }))
+    ));
+
+    Ok(())
+}
@@ -213,3 +213,25 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+async fn account_client_options() -> Result<()> {
+    let storage_path = "test-storage/account_client_options";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let client_options = iota_sdk::wallet::ClientOptions::new().with_node("http://127.0.0.1:14266")?;
+    let account = wallet
+        .create_account()
+        .with_bech32_hrp("tst".to_string())
+        .with_client_options(client_options.clone())
+        .finish()
+        .await?;
+    assert_eq!(account.read().await.client_options(), &Some(client_options));
+
+    // Bind the account to the network of the wallet again
+    wallet.set_account_client_options(0, None).await?;
+    assert!(account.read().await.client_options().is_none());
+
+    tear_down(storage_path)
+}
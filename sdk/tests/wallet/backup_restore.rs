@@ -13,7 +13,7 @@ use iota_sdk::client::{
 #[cfg(all(feature = "stronghold", feature = "storage"))]
 use iota_sdk::wallet::{ClientOptions, Result, Wallet};
 
-use crate::wallet::common::{setup, tear_down, NODE_LOCAL, NODE_OTHER};
+use crate::wallet::common::{make_wallet, setup, tear_down, NODE_LOCAL, NODE_OTHER};
 
 #[tokio::test]
 #[cfg(all(feature = "stronghold", feature = "storage"))]
@@ -443,3 +443,406 @@ async fn backup_and_restore_different_coin_type_dont_ignore() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Backups have a manifest, older backups are upgraded and newer ones are refused
+async fn backup_manifest_and_schema_version() -> Result<()> {
+    use iota_sdk::client::storage::StorageProvider;
+
+    let storage_path = "test-storage/backup_manifest_and_schema_version";
+    setup(storage_path)?;
+
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(
+        "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak",
+    )?;
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/backup_manifest_and_schema_version/1")
+        .finish()
+        .await?;
+
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+    let backup_path = PathBuf::from("test-storage/backup_manifest_and_schema_version/backup.stronghold");
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).unwrap_or(());
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+
+    let manifest = wallet
+        .read_backup_manifest(backup_path.clone(), stronghold_password.to_string())
+        .await?
+        .unwrap();
+    assert_eq!(manifest.sdk_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(manifest.backup_schema_version, 2);
+    assert_eq!(manifest.coin_type, SHIMMER_COIN_TYPE);
+    assert_eq!(manifest.account_count, 1);
+
+    let restore_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak",
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/backup_manifest_and_schema_version/2")
+        .finish()
+        .await?;
+
+    // A backup from a newer SDK is refused
+    let mut backup_stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build(backup_path.clone())?;
+    backup_stronghold.insert(b"backup_schema_version", &[3]).await?;
+    backup_stronghold.write_stronghold_snapshot(None).await?;
+    assert!(matches!(
+        restore_wallet
+            .restore_backup(backup_path.clone(), stronghold_password.to_string(), None)
+            .await,
+        Err(iota_sdk::wallet::Error::UnsupportedBackupVersion { found: 3, supported: 2 })
+    ));
+
+    // A backup from before the manifest was added is upgraded
+    backup_stronghold.insert(b"backup_schema_version", &[1]).await?;
+    backup_stronghold.delete(b"manifest").await?;
+    backup_stronghold.write_stronghold_snapshot(None).await?;
+    assert!(
+        restore_wallet
+            .read_backup_manifest(backup_path.clone(), stronghold_password.to_string())
+            .await?
+            .is_none()
+    );
+    restore_wallet
+        .restore_backup(backup_path, stronghold_password.to_string(), None)
+        .await?;
+    restore_wallet.get_account("Alice").await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// List the content of a backup and restore selected accounts into a wallet with existing accounts
+async fn backup_and_merge_restore() -> Result<()> {
+    use iota_sdk::wallet::{account::types::AccountIdentifier, Error, RestoreBackupOptions};
+
+    let storage_path = "test-storage/backup_and_merge_restore";
+    setup(storage_path)?;
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let wallet = make_wallet("test-storage/backup_and_merge_restore/1", Some(mnemonic), None).await?;
+    for alias in ["Alice", "Bob", "Carol"] {
+        wallet.create_account().with_alias(alias.to_string()).finish().await?;
+    }
+
+    let stronghold_password = "some_hopefully_secure_password";
+    let backup_path = PathBuf::from("test-storage/backup_and_merge_restore/backup.stronghold");
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).unwrap_or(());
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+
+    let restore_wallet = make_wallet("test-storage/backup_and_merge_restore/2", Some(mnemonic), None).await?;
+    restore_wallet
+        .create_account()
+        .with_alias("Alice".to_string())
+        .finish()
+        .await?;
+
+    // Dry run
+    let backup_content = restore_wallet
+        .read_backup_content(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+    assert_eq!(backup_content.manifest.unwrap().account_count, 3);
+    assert_eq!(backup_content.coin_type, Some(SHIMMER_COIN_TYPE));
+    assert_eq!(
+        backup_content
+            .accounts
+            .iter()
+            .map(|account| (account.alias.as_str(), account.conflict))
+            .collect::<Vec<_>>(),
+        [("Alice", true), ("Bob", false), ("Carol", false)]
+    );
+
+    let merge_options = |accounts: Option<Vec<AccountIdentifier>>| RestoreBackupOptions {
+        accounts,
+        merge: true,
+        ..Default::default()
+    };
+
+    // Without merging, accounts can't be restored into a wallet with accounts
+    restore_wallet
+        .restore_backup_with_options(
+            backup_path.clone(),
+            stronghold_password.to_string(),
+            RestoreBackupOptions::default(),
+        )
+        .await
+        .unwrap_err();
+
+    // Conflicting account
+    assert!(matches!(
+        restore_wallet
+            .restore_backup_with_options(
+                backup_path.clone(),
+                stronghold_password.to_string(),
+                merge_options(None),
+            )
+            .await,
+        Err(Error::BackupAccountConflict { index: 0, .. })
+    ));
+
+    // Account index gap
+    assert!(matches!(
+        restore_wallet
+            .restore_backup_with_options(
+                backup_path.clone(),
+                stronghold_password.to_string(),
+                merge_options(Some(vec![AccountIdentifier::Index(2)])),
+            )
+            .await,
+        Err(Error::BackupAccountIndexGap { missing_indexes }) if missing_indexes == [1]
+    ));
+
+    // Unknown account
+    assert!(matches!(
+        restore_wallet
+            .restore_backup_with_options(
+                backup_path.clone(),
+                stronghold_password.to_string(),
+                merge_options(Some(vec![AccountIdentifier::Alias("Dave".to_string())])),
+            )
+            .await,
+        Err(Error::AccountNotFound(_))
+    ));
+
+    let restored_accounts = restore_wallet
+        .restore_backup_with_options(
+            backup_path.clone(),
+            stronghold_password.to_string(),
+            merge_options(Some(vec![
+                AccountIdentifier::Alias("Bob".to_string()),
+                AccountIdentifier::Index(2),
+            ])),
+        )
+        .await?;
+    assert_eq!(restored_accounts.len(), 2);
+    let mut account_indexes = Vec::new();
+    for account in restore_wallet.get_accounts().await? {
+        account_indexes.push(*account.read().await.index());
+    }
+    assert_eq!(account_indexes, [0, 1, 2]);
+    assert_eq!(
+        restore_wallet.get_account("Carol").await?.addresses().await?,
+        wallet.get_account("Carol").await?.addresses().await?
+    );
+
+    // Accounts created with another seed can't be merged
+    let other_wallet = make_wallet("test-storage/backup_and_merge_restore/3", None, None).await?;
+    assert!(matches!(
+        other_wallet
+            .restore_backup_with_options(backup_path, stronghold_password.to_string(), merge_options(None))
+            .await,
+        Err(Error::InvalidMnemonic(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Restore the address book of a backup, replacing or merging it into the existing one
+async fn backup_and_restore_address_book() -> Result<()> {
+    use iota_sdk::wallet::{Contact, RestoreBackupOptions};
+
+    let storage_path = "test-storage/backup_and_restore_address_book";
+    setup(storage_path)?;
+
+    let contact = |name: &str| Contact {
+        name: name.to_string(),
+        address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+        expected_hrp: Some("rms".to_string()),
+    };
+    let contact_names = |contacts: Vec<Contact>| contacts.into_iter().map(|c| c.name).collect::<Vec<_>>();
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let wallet = make_wallet("test-storage/backup_and_restore_address_book/1", Some(mnemonic), None).await?;
+    wallet.set_contact(contact("Alice")).await?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+    let backup_path = PathBuf::from("test-storage/backup_and_restore_address_book/backup.stronghold");
+    std::fs::create_dir_all(storage_path).unwrap_or(());
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+
+    // Without merging, the address book is replaced
+    let restore_wallet = make_wallet("test-storage/backup_and_restore_address_book/2", Some(mnemonic), None).await?;
+    restore_wallet.set_contact(contact("Bob")).await?;
+    restore_wallet
+        .restore_backup_with_options(
+            backup_path.clone(),
+            stronghold_password.to_string(),
+            RestoreBackupOptions::default(),
+        )
+        .await?;
+    assert_eq!(contact_names(restore_wallet.address_book().await?), ["Alice"]);
+
+    // When merging, the contacts of the backup are added
+    let merge_wallet = make_wallet("test-storage/backup_and_restore_address_book/3", Some(mnemonic), None).await?;
+    merge_wallet.set_contact(contact("Carol")).await?;
+    merge_wallet
+        .restore_backup_with_options(
+            backup_path,
+            stronghold_password.to_string(),
+            RestoreBackupOptions {
+                merge: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(contact_names(merge_wallet.address_book().await?), ["Alice", "Carol"]);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+// Export the metadata encrypted with a password and import it into another wallet, without Stronghold
+async fn metadata_export_and_import() -> iota_sdk::wallet::Result<()> {
+    use iota_sdk::wallet::{account::types::AccountIdentifier, Error};
+
+    let storage_path = "test-storage/metadata_export_and_import";
+    setup(storage_path)?;
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let wallet = make_wallet("test-storage/metadata_export_and_import/1", Some(mnemonic), None).await?;
+    for alias in ["Alice", "Bob"] {
+        wallet.create_account().with_alias(alias.to_string()).finish().await?;
+    }
+
+    let password = "some_hopefully_secure_password";
+    let export_path = std::path::PathBuf::from("test-storage/metadata_export_and_import/export.json");
+    std::fs::create_dir_all(storage_path).unwrap_or(());
+    wallet
+        .export_metadata(export_path.clone(), password.to_string())
+        .await?;
+
+    let import_wallet = make_wallet("test-storage/metadata_export_and_import/2", Some(mnemonic), None).await?;
+
+    // Wrong password fails
+    assert!(matches!(
+        import_wallet
+            .import_metadata(export_path.clone(), "wrong password".to_string(), None)
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    let imported_accounts = import_wallet
+        .import_metadata(
+            export_path.clone(),
+            password.to_string(),
+            Some(vec![AccountIdentifier::Index(0)]),
+        )
+        .await?;
+    assert_eq!(imported_accounts.len(), 1);
+    import_wallet
+        .import_metadata(export_path.clone(), password.to_string(), None)
+        .await
+        .unwrap_err();
+    import_wallet
+        .import_metadata(
+            export_path.clone(),
+            password.to_string(),
+            Some(vec![AccountIdentifier::Alias("Bob".to_string())]),
+        )
+        .await?;
+    assert_eq!(
+        import_wallet.get_account("Bob").await?.addresses().await?,
+        wallet.get_account("Bob").await?.addresses().await?
+    );
+
+    // Key derivation parameters that would exhaust the memory are refused
+    let mut export: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&export_path)?)?;
+    export["kdf"]["memoryCost"] = u32::MAX.into();
+    let crafted_export_path = std::path::PathBuf::from("test-storage/metadata_export_and_import/crafted_export.json");
+    std::fs::write(&crafted_export_path, export.to_string())?;
+    assert!(matches!(
+        import_wallet
+            .import_metadata(crafted_export_path, password.to_string(), None)
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    // Accounts created with another seed can't be imported, and the failed import doesn't change the client options
+    let other_wallet = make_wallet(
+        "test-storage/metadata_export_and_import/3",
+        None,
+        Some("http://127.0.0.1:14266"),
+    )
+    .await?;
+    let client_options = other_wallet.get_client_options().await;
+    assert!(matches!(
+        other_wallet
+            .import_metadata(export_path, password.to_string(), None)
+            .await,
+        Err(Error::InvalidMnemonic(_))
+    ));
+    assert_eq!(other_wallet.get_client_options().await, client_options);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(feature = "storage")]
+// The contacts of the address book are exported with the metadata and merged into the address book on import
+async fn metadata_export_and_import_address_book() -> iota_sdk::wallet::Result<()> {
+    use iota_sdk::wallet::Contact;
+
+    let storage_path = "test-storage/metadata_export_and_import_address_book";
+    setup(storage_path)?;
+
+    let contact = |name: &str| Contact {
+        name: name.to_string(),
+        address: "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+        expected_hrp: Some("rms".to_string()),
+    };
+    let contact_names = |contacts: Vec<Contact>| contacts.into_iter().map(|c| c.name).collect::<Vec<_>>();
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let wallet = make_wallet(
+        "test-storage/metadata_export_and_import_address_book/1",
+        Some(mnemonic),
+        None,
+    )
+    .await?;
+    wallet.set_contact(contact("Alice")).await?;
+
+    let password = "some_hopefully_secure_password";
+    let export_path = std::path::PathBuf::from("test-storage/metadata_export_and_import_address_book/export.json");
+    std::fs::create_dir_all(storage_path).unwrap_or(());
+    wallet
+        .export_metadata(export_path.clone(), password.to_string())
+        .await?;
+
+    let import_wallet = make_wallet(
+        "test-storage/metadata_export_and_import_address_book/2",
+        Some(mnemonic),
+        None,
+    )
+    .await?;
+    import_wallet.set_contact(contact("Bob")).await?;
+    import_wallet
+        .import_metadata(export_path, password.to_string(), None)
+        .await?;
+    assert_eq!(contact_names(import_wallet.address_book().await?), ["Alice", "Bob"]);
+
+    tear_down(storage_path)
+}
@@ -105,6 +105,7 @@ async fn message_interface_create_account() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            client_options: None,
         })
         .await;
 
@@ -161,6 +162,7 @@ async fn message_interface_events() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: Some("alias".to_string()),
             bech32_hrp: None,
+            client_options: None,
         })
         .await;
 
@@ -309,7 +311,12 @@ async fn message_interface_stronghold() -> Result<()> {
         })
         .await;
     let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast".to_string();
-    let _ = wallet_handle.send_message(Message::StoreMnemonic { mnemonic }).await;
+    let _ = wallet_handle
+        .send_message(Message::StoreMnemonic {
+            mnemonic,
+            passphrase: None,
+        })
+        .await;
 
     // create an account, if password or storing mnemonic failed, it would fail here, because it couldn't generate
     // an address
@@ -317,6 +324,7 @@ async fn message_interface_stronghold() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            client_options: None,
         })
         .await;
 